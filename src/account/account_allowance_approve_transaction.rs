@@ -201,6 +201,7 @@ impl AccountAllowanceApproveTransaction {
     }
 }
 
+/// An allowance of hbar an owner has approved for a spender.
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub struct HbarAllowance {
@@ -214,6 +215,7 @@ pub struct HbarAllowance {
     pub amount: Hbar,
 }
 
+/// An allowance of a fungible token an owner has approved for a spender.
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub struct TokenAllowance {
@@ -230,6 +232,7 @@ pub struct TokenAllowance {
     pub amount: u64,
 }
 
+/// An allowance of NFTs an owner has approved for a spender.
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub struct NftAllowance {
@@ -0,0 +1,416 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use serde_json::Value;
+
+use super::{
+    HbarAllowance,
+    NftAllowance,
+    TokenAllowance,
+};
+use crate::mirror_rest::{
+    self,
+    DEFAULT_MAX_ATTEMPTS,
+    DEFAULT_MAX_BACKOFF,
+};
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    Hbar,
+    TokenId,
+};
+
+/// The most allowances the mirror node returns in a single page.
+const PAGE_SIZE: usize = 100;
+
+/// Lists the allowances an account has approved, or been approved for, using the mirror node.
+///
+/// Hbar and fungible token allowances can only be listed by [`owner_account_id`](Self::owner_account_id),
+/// optionally narrowed down to a [`spender_account_id`](Self::spender_account_id).
+/// NFT allowances can be listed by either one.
+///
+/// The amounts of the returned [`HbarAllowance`]s and [`TokenAllowance`]s are what's left of each allowance,
+/// and the returned [`NftAllowance`]s are the allowances approved for all of an owner's NFTs of a token.
+///
+/// # Examples
+/// ```no_run
+/// # async fn example() -> hiero_sdk::Result<()> {
+/// use hiero_sdk::{AccountAllowanceQuery, AccountId, Client};
+///
+/// let client = Client::for_testnet();
+///
+/// let allowances = AccountAllowanceQuery::new()
+///     .owner_account_id(AccountId::new(0, 0, 1234))
+///     .execute_hbar(&client)
+///     .await?;
+///
+/// for allowance in allowances {
+///     println!("{} may spend {}", allowance.spender_account_id, allowance.amount);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AccountAllowanceQuery {
+    owner_account_id: Option<AccountId>,
+    spender_account_id: Option<AccountId>,
+    token_id: Option<TokenId>,
+    limit: Option<usize>,
+    max_attempts: usize,
+    max_backoff: Duration,
+}
+
+impl Default for AccountAllowanceQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AccountAllowanceQuery {
+    /// Creates a new `AccountAllowanceQuery`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            owner_account_id: None,
+            spender_account_id: None,
+            token_id: None,
+            limit: None,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+
+    /// Returns the account that approved the allowances.
+    #[must_use]
+    pub fn get_owner_account_id(&self) -> Option<AccountId> {
+        self.owner_account_id
+    }
+
+    /// Sets the account that approved the allowances.
+    pub fn owner_account_id(&mut self, owner_account_id: AccountId) -> &mut Self {
+        self.owner_account_id = Some(owner_account_id);
+        self
+    }
+
+    /// Returns the account the allowances were approved for.
+    #[must_use]
+    pub fn get_spender_account_id(&self) -> Option<AccountId> {
+        self.spender_account_id
+    }
+
+    /// Sets the account the allowances were approved for.
+    pub fn spender_account_id(&mut self, spender_account_id: AccountId) -> &mut Self {
+        self.spender_account_id = Some(spender_account_id);
+        self
+    }
+
+    /// Returns the token to list allowances of.
+    #[must_use]
+    pub fn get_token_id(&self) -> Option<TokenId> {
+        self.token_id
+    }
+
+    /// Sets the token to list allowances of, by default allowances of every token are listed.
+    ///
+    /// Ignored when listing hbar allowances.
+    pub fn token_id(&mut self, token_id: TokenId) -> &mut Self {
+        self.token_id = Some(token_id);
+        self
+    }
+
+    /// Returns the most allowances to list.
+    #[must_use]
+    pub fn get_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Sets the most allowances to list, by default every allowance is listed.
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the maximum number of retry attempts.
+    #[must_use]
+    pub fn get_max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    /// Sets the maximum number of retry attempts.
+    pub fn max_attempts(&mut self, max_attempts: usize) -> &mut Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Returns the maximum backoff duration between retries.
+    #[must_use]
+    pub fn get_max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// Sets the maximum backoff duration between retries.
+    pub fn max_backoff(&mut self, max_backoff: Duration) -> &mut Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Lists the hbar allowances.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the owner isn't set.
+    /// - [`Error::BasicParse`] if the mirror node returns an error, or a response that can't be parsed.
+    pub async fn execute_hbar(&self, client: &Client) -> crate::Result<Vec<HbarAllowance>> {
+        let items = self.get_all(client, &self.hbar_path()?).await?;
+
+        items.iter().map(parse_hbar_allowance).collect()
+    }
+
+    /// Lists the fungible token allowances.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the owner isn't set.
+    /// - [`Error::BasicParse`] if the mirror node returns an error, or a response that can't be parsed.
+    pub async fn execute_token(&self, client: &Client) -> crate::Result<Vec<TokenAllowance>> {
+        let items = self.get_all(client, &self.token_path()?).await?;
+
+        items.iter().map(parse_token_allowance).collect()
+    }
+
+    /// Lists the NFT allowances approved for all of an owner's NFTs of a token.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if neither the owner nor the spender is set.
+    /// - [`Error::BasicParse`] if the mirror node returns an error, or a response that can't be parsed.
+    pub async fn execute_nft(&self, client: &Client) -> crate::Result<Vec<NftAllowance>> {
+        let items = self.get_all(client, &self.nft_path()?).await?;
+
+        items.iter().map(parse_nft_allowance).collect()
+    }
+
+    async fn get_all(&self, client: &Client, path: &str) -> crate::Result<Vec<Value>> {
+        mirror_rest::get_all(
+            &mirror_rest::base_url(client),
+            path,
+            "allowances",
+            self.limit,
+            self.max_attempts,
+            self.max_backoff,
+        )
+        .await
+    }
+
+    fn page_size(&self) -> usize {
+        self.limit.map_or(PAGE_SIZE, |it| it.clamp(1, PAGE_SIZE))
+    }
+
+    fn owner(&self, kind: &str) -> crate::Result<AccountId> {
+        self.owner_account_id.ok_or_else(|| {
+            Error::basic_parse(format!(
+                "owner must be set on AccountAllowanceQuery to list {kind} allowances"
+            ))
+        })
+    }
+
+    fn hbar_path(&self) -> crate::Result<String> {
+        let mut path = format!(
+            "/accounts/{}/allowances/crypto?limit={}",
+            self.owner("hbar")?,
+            self.page_size()
+        );
+
+        if let Some(spender_id) = self.spender_account_id {
+            path.push_str(&format!("&spender.id={spender_id}"));
+        }
+
+        Ok(path)
+    }
+
+    fn token_path(&self) -> crate::Result<String> {
+        let mut path = format!(
+            "/accounts/{}/allowances/tokens?limit={}",
+            self.owner("token")?,
+            self.page_size()
+        );
+
+        if let Some(spender_id) = self.spender_account_id {
+            path.push_str(&format!("&spender.id={spender_id}"));
+        }
+
+        if let Some(token_id) = self.token_id {
+            path.push_str(&format!("&token.id={token_id}"));
+        }
+
+        Ok(path)
+    }
+
+    fn nft_path(&self) -> crate::Result<String> {
+        // `account.id` filters by the other side of the allowance.
+        let mut path =
+            match (self.owner_account_id, self.spender_account_id) {
+                (Some(owner_id), spender_id) => {
+                    let mut path = format!(
+                        "/accounts/{owner_id}/allowances/nfts?limit={}&owner=true",
+                        self.page_size()
+                    );
+
+                    if let Some(spender_id) = spender_id {
+                        path.push_str(&format!("&account.id={spender_id}"));
+                    }
+
+                    path
+                }
+
+                (None, Some(spender_id)) => format!(
+                    "/accounts/{spender_id}/allowances/nfts?limit={}&owner=false",
+                    self.page_size()
+                ),
+
+                (None, None) => return Err(Error::basic_parse(
+                    "owner or spender must be set on AccountAllowanceQuery to list NFT allowances",
+                )),
+            };
+
+        if let Some(token_id) = self.token_id {
+            path.push_str(&format!("&token.id={token_id}"));
+        }
+
+        Ok(path)
+    }
+}
+
+fn field<'a>(value: &'a Value, key: &str) -> crate::Result<&'a str> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| Error::basic_parse(format!("mirror node allowance is missing `{key}`")))
+}
+
+fn amount(value: &Value) -> crate::Result<i64> {
+    value
+        .get("amount")
+        .and_then(Value::as_i64)
+        .ok_or_else(|| Error::basic_parse("mirror node allowance is missing `amount`"))
+}
+
+// `{"amount": 75, "amount_granted": 100, "owner": "0.0.2", "spender": "0.0.3", ...}`
+fn parse_hbar_allowance(value: &Value) -> crate::Result<HbarAllowance> {
+    Ok(HbarAllowance {
+        owner_account_id: field(value, "owner")?.parse()?,
+        spender_account_id: field(value, "spender")?.parse()?,
+        amount: Hbar::from_tinybars(amount(value)?),
+    })
+}
+
+// `{"amount": 75, "amount_granted": 100, "owner": "0.0.2", "spender": "0.0.3", "token_id": "0.0.4", ...}`
+fn parse_token_allowance(value: &Value) -> crate::Result<TokenAllowance> {
+    Ok(TokenAllowance {
+        token_id: field(value, "token_id")?.parse()?,
+        owner_account_id: field(value, "owner")?.parse()?,
+        spender_account_id: field(value, "spender")?.parse()?,
+        amount: u64::try_from(amount(value)?)
+            .map_err(|_| Error::basic_parse("mirror node allowance has a negative `amount`"))?,
+    })
+}
+
+// `{"approved_for_all": true, "owner": "0.0.2", "spender": "0.0.3", "token_id": "0.0.4", ...}`
+fn parse_nft_allowance(value: &Value) -> crate::Result<NftAllowance> {
+    Ok(NftAllowance {
+        token_id: field(value, "token_id")?.parse()?,
+        owner_account_id: field(value, "owner")?.parse()?,
+        spender_account_id: field(value, "spender")?.parse()?,
+        serials: Vec::new(),
+        approved_for_all: value.get("approved_for_all").and_then(Value::as_bool),
+        delegating_spender_account_id: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{
+        parse_hbar_allowance,
+        parse_nft_allowance,
+        parse_token_allowance,
+    };
+    use crate::{
+        AccountAllowanceQuery,
+        AccountId,
+        Hbar,
+        TokenId,
+    };
+
+    #[test]
+    fn paths() {
+        let mut query = AccountAllowanceQuery::new();
+
+        assert!(query.hbar_path().is_err());
+        assert!(query.nft_path().is_err());
+
+        query.spender_account_id(AccountId::new(0, 0, 3));
+
+        assert!(query.token_path().is_err());
+        assert_eq!(
+            query.nft_path().unwrap(),
+            "/accounts/0.0.3/allowances/nfts?limit=100&owner=false"
+        );
+
+        query.owner_account_id(AccountId::new(0, 0, 2)).token_id(TokenId::new(0, 0, 4)).limit(5);
+
+        assert_eq!(
+            query.hbar_path().unwrap(),
+            "/accounts/0.0.2/allowances/crypto?limit=5&spender.id=0.0.3"
+        );
+        assert_eq!(
+            query.token_path().unwrap(),
+            "/accounts/0.0.2/allowances/tokens?limit=5&spender.id=0.0.3&token.id=0.0.4"
+        );
+        assert_eq!(
+            query.nft_path().unwrap(),
+            "/accounts/0.0.2/allowances/nfts?limit=5&owner=true&account.id=0.0.3&token.id=0.0.4"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let hbar = parse_hbar_allowance(&json!({
+            "amount": 75,
+            "amount_granted": 100,
+            "owner": "0.0.2",
+            "spender": "0.0.3",
+            "timestamp": { "from": "1651560386.661997287", "to": null }
+        }))
+        .unwrap();
+
+        assert_eq!(hbar.owner_account_id, AccountId::new(0, 0, 2));
+        assert_eq!(hbar.spender_account_id, AccountId::new(0, 0, 3));
+        assert_eq!(hbar.amount, Hbar::from_tinybars(75));
+
+        let token = parse_token_allowance(&json!({
+            "amount": 75,
+            "amount_granted": 100,
+            "owner": "0.0.2",
+            "spender": "0.0.3",
+            "token_id": "0.0.4"
+        }))
+        .unwrap();
+
+        assert_eq!(token.token_id, TokenId::new(0, 0, 4));
+        assert_eq!(token.amount, 75);
+
+        let nft = parse_nft_allowance(&json!({
+            "approved_for_all": true,
+            "owner": "0.0.2",
+            "spender": "0.0.3",
+            "token_id": "0.0.5"
+        }))
+        .unwrap();
+
+        assert_eq!(nft.token_id, TokenId::new(0, 0, 5));
+        assert_eq!(nft.approved_for_all, Some(true));
+
+        assert!(parse_token_allowance(&json!({ "amount": 1, "owner": "0.0.2" })).is_err());
+    }
+}
@@ -40,6 +40,10 @@ pub async fn verify_signature(
 }
 
 /// Returns `Ok(())` if the given account's public key has signed the given transaction.
+///
+/// Every signature by the account's key, in every node's copy of the transaction, is checked.
+/// To check the signatures of every signer instead, see [`Transaction::verify_signatures`].
+///
 /// # Errors
 /// - [`Error::SignatureVerify`] if the private key associated with the account's public key did _not_ sign this transaction,
 ///   or the signature associated was invalid.
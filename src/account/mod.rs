@@ -2,6 +2,8 @@
 
 mod account_allowance_approve_transaction;
 mod account_allowance_delete_transaction;
+#[cfg(feature = "serde")]
+mod account_allowance_query;
 mod account_balance;
 mod account_balance_query;
 mod account_create_transaction;
@@ -16,10 +18,17 @@ mod account_records_query;
 mod account_update_transaction;
 mod proxy_staker;
 
-pub use account_allowance_approve_transaction::AccountAllowanceApproveTransaction;
 pub(crate) use account_allowance_approve_transaction::AccountAllowanceApproveTransactionData;
+pub use account_allowance_approve_transaction::{
+    AccountAllowanceApproveTransaction,
+    HbarAllowance,
+    NftAllowance,
+    TokenAllowance,
+};
 pub use account_allowance_delete_transaction::AccountAllowanceDeleteTransaction;
 pub(crate) use account_allowance_delete_transaction::AccountAllowanceDeleteTransactionData;
+#[cfg(feature = "serde")]
+pub use account_allowance_query::AccountAllowanceQuery;
 pub use account_balance::AccountBalance;
 pub use account_balance_query::AccountBalanceQuery;
 pub(crate) use account_balance_query::AccountBalanceQueryData;
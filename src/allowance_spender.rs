@@ -0,0 +1,474 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+use crate::signer::AnySigner;
+#[cfg(feature = "serde")]
+use crate::AccountAllowanceQuery;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    Hbar,
+    NftId,
+    PrivateKey,
+    PublicKey,
+    TokenId,
+    TransactionId,
+    TransactionReceipt,
+    TransferTransaction,
+};
+
+/// A transfer queued by an [`AllowanceSpender`].
+#[derive(Debug, Clone)]
+enum ApprovedTransfer {
+    Hbar { owner: AccountId, receiver: AccountId, amount: Hbar },
+    Token { token_id: TokenId, owner: AccountId, receiver: AccountId, amount: i64 },
+    Nft { nft_id: NftId, owner: AccountId, receiver: AccountId },
+}
+
+/// Spends the allowances other accounts have approved for a spender.
+///
+/// The spender keeps track of what's left of each allowance, and checks every transfer against it
+/// when it's added, so a transfer that exceeds an allowance fails locally instead of on the network.
+///
+/// The transfers are executed as a single [`TransferTransaction`] paid for by the spender,
+/// with [`approved_hbar_transfer`](TransferTransaction::approved_hbar_transfer) and friends.
+///
+/// # Examples
+///
+/// ```no_run
+/// use hiero_sdk::{AccountId, AllowanceSpender, Client, Hbar, PrivateKey};
+///
+/// # async fn example(client: &Client, spender_key: PrivateKey) -> hiero_sdk::Result<()> {
+/// let owner = AccountId::new(0, 0, 1234);
+///
+/// let mut spender = AllowanceSpender::new(AccountId::new(0, 0, 5678));
+///
+/// spender
+///     .fetch(client, owner)
+///     .await?
+///     .hbar_transfer(owner, AccountId::new(0, 0, 9), Hbar::new(5))?
+///     .sign(spender_key);
+///
+/// let receipt = spender.execute(client).await?;
+///
+/// println!("{} left to spend", spender.get_hbar_allowance(owner));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AllowanceSpender {
+    spender_account_id: AccountId,
+    hbar_allowances: HashMap<AccountId, Hbar>,
+    token_allowances: HashMap<(TokenId, AccountId), u64>,
+    nft_allowances: HashSet<(TokenId, AccountId)>,
+    nft_serial_allowances: HashSet<(NftId, AccountId)>,
+    transfers: Vec<ApprovedTransfer>,
+    signers: Vec<AnySigner>,
+}
+
+impl AllowanceSpender {
+    /// Create a new `AllowanceSpender` for `spender_account_id`, with no known allowances.
+    #[must_use]
+    pub fn new(spender_account_id: AccountId) -> Self {
+        Self {
+            spender_account_id,
+            hbar_allowances: HashMap::new(),
+            token_allowances: HashMap::new(),
+            nft_allowances: HashSet::new(),
+            nft_serial_allowances: HashSet::new(),
+            transfers: Vec::new(),
+            signers: Vec::new(),
+        }
+    }
+
+    /// Returns the account spending the allowances.
+    #[must_use]
+    pub fn get_spender_account_id(&self) -> AccountId {
+        self.spender_account_id
+    }
+
+    /// Replaces the known allowances `owner_account_id` approved for the spender with the ones on the mirror node,
+    /// less the queued transfers.
+    ///
+    /// NFT allowances of single serials aren't listed by the mirror node, and are kept as they are.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the mirror node returns an error, or a response that can't be parsed.
+    #[cfg(feature = "serde")]
+    pub async fn fetch(
+        &mut self,
+        client: &Client,
+        owner_account_id: AccountId,
+    ) -> crate::Result<&mut Self> {
+        let mut query = AccountAllowanceQuery::new();
+
+        query.owner_account_id(owner_account_id).spender_account_id(self.spender_account_id);
+
+        let hbar_allowances = query.execute_hbar(client).await?;
+        let token_allowances = query.execute_token(client).await?;
+        let nft_allowances = query.execute_nft(client).await?;
+
+        self.hbar_allowances.remove(&owner_account_id);
+        self.token_allowances.retain(|(_, owner), _| *owner != owner_account_id);
+        self.nft_allowances.retain(|(_, owner)| *owner != owner_account_id);
+
+        for allowance in hbar_allowances {
+            self.hbar_allowance(allowance.owner_account_id, allowance.amount);
+        }
+
+        for allowance in token_allowances {
+            self.token_allowance(allowance.token_id, allowance.owner_account_id, allowance.amount);
+        }
+
+        for allowance in nft_allowances {
+            if allowance.approved_for_all == Some(true) {
+                self.nft_allowance(allowance.token_id, allowance.owner_account_id);
+            }
+        }
+
+        // the mirror node doesn't know about the queued transfers yet.
+        for transfer in &self.transfers {
+            match *transfer {
+                ApprovedTransfer::Hbar { owner, amount, .. } if owner == owner_account_id => {
+                    let remaining = self.get_hbar_allowance(owner);
+                    self.hbar_allowances.insert(owner, (remaining - amount).max(Hbar::ZERO));
+                }
+
+                ApprovedTransfer::Token { token_id, owner, amount, .. }
+                    if owner == owner_account_id =>
+                {
+                    let remaining = self.get_token_allowance(token_id, owner);
+                    self.token_allowances
+                        .insert((token_id, owner), remaining.saturating_sub(amount.unsigned_abs()));
+                }
+
+                _ => {}
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Sets what's left of the hbar allowance `owner_account_id` approved for the spender.
+    pub fn hbar_allowance(&mut self, owner_account_id: AccountId, amount: Hbar) -> &mut Self {
+        self.hbar_allowances.insert(owner_account_id, amount);
+
+        self
+    }
+
+    /// Returns what's left of the hbar allowance `owner_account_id` approved for the spender, after the queued transfers.
+    #[must_use]
+    pub fn get_hbar_allowance(&self, owner_account_id: AccountId) -> Hbar {
+        self.hbar_allowances.get(&owner_account_id).copied().unwrap_or(Hbar::ZERO)
+    }
+
+    /// Sets what's left of the allowance of `token_id` `owner_account_id` approved for the spender.
+    ///
+    /// `amount` is in the lowest denomination for the token.
+    pub fn token_allowance(
+        &mut self,
+        token_id: TokenId,
+        owner_account_id: AccountId,
+        amount: u64,
+    ) -> &mut Self {
+        self.token_allowances.insert((token_id, owner_account_id), amount);
+
+        self
+    }
+
+    /// Returns what's left of the allowance of `token_id` `owner_account_id` approved for the spender, after the queued transfers.
+    #[must_use]
+    pub fn get_token_allowance(&self, token_id: TokenId, owner_account_id: AccountId) -> u64 {
+        self.token_allowances.get(&(token_id, owner_account_id)).copied().unwrap_or(0)
+    }
+
+    /// Sets that `owner_account_id` approved the spender for all of its NFTs of `token_id`.
+    pub fn nft_allowance(&mut self, token_id: TokenId, owner_account_id: AccountId) -> &mut Self {
+        self.nft_allowances.insert((token_id, owner_account_id));
+
+        self
+    }
+
+    /// Sets that `owner_account_id` approved the spender for the NFT `nft_id`.
+    pub fn nft_serial_allowance(
+        &mut self,
+        nft_id: impl Into<NftId>,
+        owner_account_id: AccountId,
+    ) -> &mut Self {
+        self.nft_serial_allowances.insert((nft_id.into(), owner_account_id));
+
+        self
+    }
+
+    /// Returns `true` if the spender may transfer `nft_id` of `owner_account_id`, after the queued transfers.
+    #[must_use]
+    pub fn is_nft_approved(&self, nft_id: NftId, owner_account_id: AccountId) -> bool {
+        self.nft_allowances.contains(&(nft_id.token_id, owner_account_id))
+            || self.nft_serial_allowances.contains(&(nft_id, owner_account_id))
+    }
+
+    /// Queues a transfer of `amount` hbar from `owner_account_id` to `receiver_account_id`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `amount` isn't positive, or exceeds what's left of the allowance.
+    pub fn hbar_transfer(
+        &mut self,
+        owner_account_id: AccountId,
+        receiver_account_id: AccountId,
+        amount: Hbar,
+    ) -> crate::Result<&mut Self> {
+        if amount <= Hbar::ZERO {
+            return Err(Error::basic_parse("approved hbar transfer amount must be positive"));
+        }
+
+        let remaining = self.get_hbar_allowance(owner_account_id);
+
+        if amount > remaining {
+            return Err(Error::basic_parse(format!(
+                "approved transfer of {amount} from {owner_account_id} exceeds the remaining allowance of {remaining}"
+            )));
+        }
+
+        self.hbar_allowances.insert(owner_account_id, remaining - amount);
+        self.transfers.push(ApprovedTransfer::Hbar {
+            owner: owner_account_id,
+            receiver: receiver_account_id,
+            amount,
+        });
+
+        Ok(self)
+    }
+
+    /// Queues a transfer of `amount` of `token_id` from `owner_account_id` to `receiver_account_id`.
+    ///
+    /// `amount` is in the lowest denomination for the token.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `amount` is 0, or exceeds what's left of the allowance.
+    pub fn token_transfer(
+        &mut self,
+        token_id: TokenId,
+        owner_account_id: AccountId,
+        receiver_account_id: AccountId,
+        amount: u64,
+    ) -> crate::Result<&mut Self> {
+        let signed_amount = match i64::try_from(amount) {
+            Ok(it) if it > 0 => it,
+            _ => return Err(Error::basic_parse("approved token transfer amount must be positive")),
+        };
+
+        let remaining = self.get_token_allowance(token_id, owner_account_id);
+
+        if amount > remaining {
+            return Err(Error::basic_parse(format!(
+                "approved transfer of {amount} of {token_id} from {owner_account_id} exceeds the remaining allowance of {remaining}"
+            )));
+        }
+
+        self.token_allowances.insert((token_id, owner_account_id), remaining - amount);
+        self.transfers.push(ApprovedTransfer::Token {
+            token_id,
+            owner: owner_account_id,
+            receiver: receiver_account_id,
+            amount: signed_amount,
+        });
+
+        Ok(self)
+    }
+
+    /// Queues a transfer of `nft_id` from `owner_account_id` to `receiver_account_id`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the spender isn't approved for the NFT, or it's already queued.
+    pub fn nft_transfer(
+        &mut self,
+        nft_id: impl Into<NftId>,
+        owner_account_id: AccountId,
+        receiver_account_id: AccountId,
+    ) -> crate::Result<&mut Self> {
+        let nft_id = nft_id.into();
+
+        let is_queued = self.transfers.iter().any(
+            |it| matches!(it, ApprovedTransfer::Nft { nft_id: queued, .. } if *queued == nft_id),
+        );
+
+        if is_queued || !self.is_nft_approved(nft_id, owner_account_id) {
+            return Err(Error::basic_parse(format!(
+                "spender has no allowance for NFT {nft_id} of {owner_account_id}"
+            )));
+        }
+
+        // an allowance for a single serial is used up by transferring it.
+        self.nft_serial_allowances.remove(&(nft_id, owner_account_id));
+        self.transfers.push(ApprovedTransfer::Nft {
+            nft_id,
+            owner: owner_account_id,
+            receiver: receiver_account_id,
+        });
+
+        Ok(self)
+    }
+
+    /// Drops the queued transfers, giving back the allowances they would have spent.
+    pub fn clear_transfers(&mut self) -> &mut Self {
+        for transfer in std::mem::take(&mut self.transfers) {
+            match transfer {
+                ApprovedTransfer::Hbar { owner, amount, .. } => {
+                    *self.hbar_allowances.entry(owner).or_insert(Hbar::ZERO) += amount;
+                }
+
+                ApprovedTransfer::Token { token_id, owner, amount, .. } => {
+                    *self.token_allowances.entry((token_id, owner)).or_insert(0) +=
+                        amount.unsigned_abs();
+                }
+
+                ApprovedTransfer::Nft { nft_id, owner, .. } => {
+                    if !self.nft_allowances.contains(&(nft_id.token_id, owner)) {
+                        self.nft_serial_allowances.insert((nft_id, owner));
+                    }
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Adds a key to sign the transaction with, such as the spender's key.
+    pub fn sign(&mut self, key: PrivateKey) -> &mut Self {
+        self.signers.push(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Adds a signer to sign the transaction with, such as the signer of the spender's key.
+    pub fn sign_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.signers.push(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Builds a [`TransferTransaction`] of the queued transfers, paid for by the spender.
+    #[must_use]
+    pub fn build(&self) -> TransferTransaction {
+        let mut tx = TransferTransaction::new();
+
+        tx.transaction_id(TransactionId::generate(self.spender_account_id));
+
+        for transfer in &self.transfers {
+            match *transfer {
+                ApprovedTransfer::Hbar { owner, receiver, amount } => {
+                    tx.approved_hbar_transfer(owner, -amount).hbar_transfer(receiver, amount);
+                }
+
+                ApprovedTransfer::Token { token_id, owner, receiver, amount } => {
+                    tx.approved_token_transfer(token_id, owner, -amount)
+                        .token_transfer(token_id, receiver, amount);
+                }
+
+                ApprovedTransfer::Nft { nft_id, owner, receiver } => {
+                    tx.approved_nft_transfer(nft_id, owner, receiver);
+                }
+            }
+        }
+
+        tx
+    }
+
+    /// Executes the queued transfers in a single transaction, and waits for its receipt.
+    ///
+    /// The queued transfers are cleared once the transaction succeeds,
+    /// and kept (along with the allowances they spend) if it fails, to retry or [`clear`](Self::clear_transfers).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if there are no queued transfers.
+    /// - If the transaction fails, see [`TransferTransaction::execute`] and [`TransactionResponse::get_receipt`](crate::TransactionResponse::get_receipt).
+    pub async fn execute(&mut self, client: &Client) -> crate::Result<TransactionReceipt> {
+        if self.transfers.is_empty() {
+            return Err(Error::basic_parse("`AllowanceSpender` has no transfers to execute"));
+        }
+
+        let mut tx = self.build();
+
+        tx.freeze_with(client)?;
+
+        for signer in &self.signers {
+            tx.sign_signer(signer.clone());
+        }
+
+        let receipt = tx.execute(client).await?.get_receipt(client).await?;
+
+        self.transfers.clear();
+
+        Ok(receipt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AccountId,
+        AllowanceSpender,
+        Hbar,
+        NftId,
+        TokenId,
+    };
+
+    #[test]
+    fn tracks_remaining_allowances() {
+        let owner = AccountId::new(0, 0, 2);
+        let receiver = AccountId::new(0, 0, 4);
+        let token_id = TokenId::new(0, 0, 5);
+
+        let mut spender = AllowanceSpender::new(AccountId::new(0, 0, 3));
+
+        spender.hbar_allowance(owner, Hbar::new(10)).token_allowance(token_id, owner, 100);
+
+        spender.hbar_transfer(owner, receiver, Hbar::new(6)).unwrap();
+
+        assert!(spender.hbar_transfer(owner, receiver, Hbar::new(5)).is_err());
+        assert_eq!(spender.get_hbar_allowance(owner), Hbar::new(4));
+
+        spender.token_transfer(token_id, owner, receiver, 100).unwrap();
+
+        assert!(spender.token_transfer(token_id, owner, receiver, 1).is_err());
+        assert!(spender.token_transfer(TokenId::new(0, 0, 6), owner, receiver, 1).is_err());
+
+        spender.clear_transfers();
+
+        assert_eq!(spender.get_hbar_allowance(owner), Hbar::new(10));
+        assert_eq!(spender.get_token_allowance(token_id, owner), 100);
+    }
+
+    #[test]
+    fn nft_allowances() {
+        let owner = AccountId::new(0, 0, 2);
+        let receiver = AccountId::new(0, 0, 4);
+        let nft = NftId { token_id: TokenId::new(0, 0, 5), serial: 1 };
+
+        let mut spender = AllowanceSpender::new(AccountId::new(0, 0, 3));
+
+        assert!(spender.nft_transfer(nft, owner, receiver).is_err());
+
+        spender.nft_serial_allowance(nft, owner).nft_transfer(nft, owner, receiver).unwrap();
+
+        assert!(!spender.is_nft_approved(nft, owner));
+
+        spender.nft_allowance(nft.token_id, owner);
+
+        assert!(spender.nft_transfer(nft, owner, receiver).is_err());
+        spender.nft_transfer(NftId { serial: 2, ..nft }, owner, receiver).unwrap();
+
+        spender.clear_transfers();
+
+        assert!(spender.is_nft_approved(NftId { serial: 3, ..nft }, owner));
+    }
+}
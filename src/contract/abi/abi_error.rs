@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{
+    codec,
+    AbiFunction,
+    AbiParam,
+    AbiStateMutability,
+    DecodedOutputs,
+};
+use crate::Error;
+
+/// A custom Solidity error (`error InsufficientBalance(uint256 available, uint256 required)`)
+/// in a [`ContractAbi`](crate::ContractAbi), used to decode revert data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiError {
+    /// The name of the error.
+    pub name: String,
+
+    /// The error's parameters.
+    pub inputs: Vec<AbiParam>,
+}
+
+impl AbiError {
+    /// Parses an error from its signature, for example `InsufficientBalance(uint256,uint256)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `signature` isn't a valid error signature.
+    pub fn from_signature(signature: &str) -> crate::Result<Self> {
+        // errors have the same signature (and selector) format as functions.
+        let AbiFunction { name, inputs, .. } = AbiFunction::from_signature(signature)?;
+
+        Ok(Self { name, inputs })
+    }
+
+    /// Returns the canonical signature of this error, for example `InsufficientBalance(uint256,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        self.as_function().signature()
+    }
+
+    /// Returns the 4 byte selector that revert data for this error starts with.
+    #[must_use]
+    pub fn selector(&self) -> [u8; 4] {
+        self.as_function().selector()
+    }
+
+    /// Decodes the parameters of this error from revert `data` (including the selector).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `data` doesn't start with this error's selector, or isn't a valid encoding of its parameters.
+    pub fn decode(&self, data: &[u8]) -> crate::Result<DecodedOutputs> {
+        let params = data.strip_prefix(&self.selector()).ok_or_else(|| {
+            Error::basic_parse(format!("data is not a `{}` error", self.signature()))
+        })?;
+
+        let types: Vec<_> = self.inputs.iter().map(|it| it.param_type.clone()).collect();
+        let values = codec::decode(&types, params)?;
+
+        Ok(DecodedOutputs::new(self.inputs.iter().map(|it| it.name.clone()).collect(), values))
+    }
+
+    fn as_function(&self) -> AbiFunction {
+        AbiFunction {
+            name: self.name.clone(),
+            inputs: self.inputs.clone(),
+            outputs: Vec::new(),
+            state_mutability: AbiStateMutability::NonPayable,
+        }
+    }
+}
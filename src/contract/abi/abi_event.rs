@@ -0,0 +1,369 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+use super::abi_type::split_types;
+use super::{
+    codec,
+    AbiType,
+    AbiValue,
+    DecodedOutputs,
+};
+use crate::{
+    ContractId,
+    ContractLogInfo,
+    Error,
+};
+
+/// An event in a [`ContractAbi`](crate::ContractAbi), used to decode [`ContractLogInfo`]s.
+///
+/// # Examples
+/// ```
+/// # fn main() -> hiero_sdk::Result<()> {
+/// use hiero_sdk::AbiEvent;
+///
+/// let transfer =
+///     AbiEvent::from_signature("Transfer(address indexed from, address indexed to, uint256 value)")?;
+///
+/// assert_eq!(transfer.signature(), "Transfer(address,address,uint256)");
+/// assert_eq!(
+///     hex::encode(transfer.topic()),
+///     "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiEvent {
+    /// The name of the event.
+    pub name: String,
+
+    /// The event's parameters.
+    pub inputs: Vec<AbiEventParam>,
+
+    /// If `true`, the event's signature isn't included as the first topic of its logs.
+    pub anonymous: bool,
+}
+
+impl AbiEvent {
+    /// Parses an event from a human readable signature,
+    /// for example `Transfer(address indexed from, address indexed to, uint256 value)`.
+    ///
+    /// Parameter names are optional.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `signature` isn't a valid event signature.
+    pub fn from_signature(signature: &str) -> crate::Result<Self> {
+        let invalid = || Error::basic_parse(format!("invalid event signature `{signature}`"));
+
+        let (name, params) = signature.trim().split_once('(').ok_or_else(invalid)?;
+        let params = params.strip_suffix(')').ok_or_else(invalid)?;
+
+        if name.is_empty() {
+            return Err(invalid());
+        }
+
+        let inputs =
+            split_types(params).into_iter().map(parse_param).collect::<crate::Result<_>>()?;
+
+        Ok(Self { name: name.to_owned(), inputs, anonymous: false })
+    }
+
+    /// Returns the canonical signature of this event, for example `Transfer(address,address,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        let params: Vec<_> = self.inputs.iter().map(|it| it.param_type.to_string()).collect();
+
+        format!("{}({})", self.name, params.join(","))
+    }
+
+    /// Returns the keccak-256 hash of this event's signature, which is the first topic of its (non-anonymous) logs.
+    #[must_use]
+    pub fn topic(&self) -> [u8; 32] {
+        Keccak256::digest(self.signature().as_bytes()).into()
+    }
+
+    /// Returns `true` if `log` looks like it was emitted for this event.
+    ///
+    /// Checks the first topic and the number of indexed parameters,
+    /// which tells apart events that share a signature, like ERC-20 and ERC-721 `Transfer`.
+    #[must_use]
+    pub fn matches(&self, log: &ContractLogInfo) -> bool {
+        let indexed = self.inputs.iter().filter(|it| it.indexed).count();
+
+        if self.anonymous {
+            return log.topics.len() == indexed;
+        }
+
+        log.topics.len() == indexed + 1 && log.topics[0] == self.topic()
+    }
+
+    /// Decodes `log` as this event.
+    ///
+    /// Indexed parameters of dynamic types (`string`, `bytes`, arrays and tuples)
+    /// are only stored as the keccak-256 hash of their value, so they decode to a 32 byte [`AbiValue::FixedBytes`].
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `log` wasn't emitted for this event, or its data isn't a valid encoding of the event's parameters.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn decode_log(&self, log: &ContractLogInfo) -> crate::Result<DecodedEvent> {
+        if !self.matches(log) {
+            return Err(Error::basic_parse(format!("log is not a `{}` event", self.signature())));
+        }
+
+        let mut topics = log.topics.iter().skip(usize::from(!self.anonymous));
+
+        let data_types: Vec<_> =
+            self.inputs.iter().filter(|it| !it.indexed).map(|it| it.param_type.clone()).collect();
+
+        let mut data = codec::decode(&data_types, &log.data)?.into_iter();
+
+        let values = self
+            .inputs
+            .iter()
+            .map(|param| {
+                if !param.indexed {
+                    // `decode` returns exactly one value per type.
+                    return Ok(data.next().unwrap());
+                }
+
+                // `matches` checked that there's a topic for every indexed parameter.
+                let topic = topics.next().unwrap();
+
+                if is_hashed(&param.param_type) {
+                    if topic.len() != 32 {
+                        return Err(Error::basic_parse("log topic is not 32 bytes"));
+                    }
+
+                    Ok(AbiValue::FixedBytes(topic.clone()))
+                } else {
+                    codec::decode_value(&param.param_type, topic)
+                }
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(DecodedEvent {
+            contract_id: log.contract_id,
+            name: self.name.clone(),
+            params: DecodedOutputs::new(
+                self.inputs.iter().map(|it| it.name.clone()).collect(),
+                values,
+            ),
+        })
+    }
+}
+
+/// A parameter of an [`AbiEvent`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiEventParam {
+    /// The name of the parameter, empty if it's unnamed.
+    pub name: String,
+
+    /// The type of the parameter.
+    pub param_type: AbiType,
+
+    /// If `true`, the parameter is stored in the log's topics instead of its data.
+    pub indexed: bool,
+}
+
+impl AbiEventParam {
+    /// Creates a new `AbiEventParam`.
+    #[must_use]
+    pub fn new(name: impl Into<String>, param_type: AbiType, indexed: bool) -> Self {
+        Self { name: name.into(), param_type, indexed }
+    }
+}
+
+/// A [`ContractLogInfo`] decoded against an [`AbiEvent`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedEvent {
+    /// The contract that emitted the event.
+    pub contract_id: ContractId,
+
+    /// The name of the event.
+    pub name: String,
+
+    /// The event's parameters, in the order they're declared.
+    pub params: DecodedOutputs,
+}
+
+// indexed values that aren't a single word are replaced by their hash.
+pub(super) fn is_hashed(ty: &AbiType) -> bool {
+    matches!(
+        ty,
+        AbiType::Bytes
+            | AbiType::String
+            | AbiType::Array(_)
+            | AbiType::FixedArray(..)
+            | AbiType::Tuple(_)
+    )
+}
+
+// `<type> [indexed] [name]`
+fn parse_param(param: &str) -> crate::Result<AbiEventParam> {
+    let is_identifier = |it: &str| {
+        it.starts_with(|it: char| it.is_ascii_alphabetic() || it == '_')
+            && it.chars().all(|it| it.is_ascii_alphanumeric() || it == '_')
+    };
+
+    let mut rest = param.trim();
+    let mut name = "";
+
+    if let Some((head, last)) = rest.rsplit_once(char::is_whitespace) {
+        if last != "indexed" && is_identifier(last) {
+            name = last;
+            rest = head.trim_end();
+        }
+    }
+
+    let mut indexed = false;
+
+    if let Some(head) = rest.strip_suffix("indexed") {
+        if head.ends_with(char::is_whitespace) {
+            indexed = true;
+            rest = head.trim_end();
+        }
+    }
+
+    Ok(AbiEventParam::new(name, rest.parse()?, indexed))
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+    use sha3::{
+        Digest,
+        Keccak256,
+    };
+
+    use super::AbiEvent;
+    use crate::{
+        AbiType,
+        AbiValue,
+        ContractId,
+        ContractLogInfo,
+        EvmAddress,
+    };
+
+    fn log(topics: &[[u8; 32]], data: &[u8]) -> ContractLogInfo {
+        ContractLogInfo {
+            contract_id: ContractId::new(0, 0, 1001),
+            bloom: Vec::new(),
+            topics: topics.iter().map(|it| it.to_vec()).collect(),
+            data: data.to_vec(),
+        }
+    }
+
+    const TRANSFER: [u8; 32] =
+        hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+    const FROM: [u8; 32] = hex!("0000000000000000000000000000000000000000000000000000000000000401");
+    const TO: [u8; 32] = hex!("0000000000000000000000000000000000000000000000000000000000000402");
+    const AMOUNT: [u8; 32] =
+        hex!("00000000000000000000000000000000000000000000000000000000000003e8");
+
+    #[test]
+    fn from_signature() {
+        let event = AbiEvent::from_signature(
+            "Transfer(address indexed from, address indexed, uint256 value)",
+        )
+        .unwrap();
+
+        assert_eq!(event.signature(), "Transfer(address,address,uint256)");
+        assert_eq!(event.topic(), TRANSFER);
+        assert_eq!(event.inputs[0].name, "from");
+        assert!(event.inputs[0].indexed);
+        assert_eq!(event.inputs[1].name, "");
+        assert!(event.inputs[1].indexed);
+        assert_eq!(event.inputs[2].name, "value");
+        assert!(!event.inputs[2].indexed);
+
+        let event = AbiEvent::from_signature("Orders((uint256, address)[] orders)").unwrap();
+        assert_eq!(event.signature(), "Orders((uint256,address)[])");
+        assert_eq!(event.inputs[0].name, "orders");
+
+        assert!(AbiEvent::from_signature("Transfer").is_err());
+        assert!(AbiEvent::from_signature("Transfer(foo bar)").is_err());
+    }
+
+    #[test]
+    fn decode_erc20_transfer() {
+        let event = AbiEvent::from_signature(
+            "Transfer(address indexed from, address indexed to, uint256 value)",
+        )
+        .unwrap();
+
+        let decoded = event.decode_log(&log(&[TRANSFER, FROM, TO], &AMOUNT)).unwrap();
+
+        assert_eq!(decoded.name, "Transfer");
+        assert_eq!(decoded.contract_id, ContractId::new(0, 0, 1001));
+        assert_eq!(
+            decoded.params.get("from").and_then(AbiValue::as_address),
+            Some(EvmAddress(hex!("0000000000000000000000000000000000000401")))
+        );
+        assert_eq!(
+            decoded.params.get("to").and_then(AbiValue::as_address),
+            Some(EvmAddress(hex!("0000000000000000000000000000000000000402")))
+        );
+        assert_eq!(decoded.params.get("value"), Some(&AbiValue::from(1000_u32)));
+    }
+
+    #[test]
+    fn erc721_transfer_does_not_match_erc20() {
+        let erc20 = AbiEvent::from_signature(
+            "Transfer(address indexed from, address indexed to, uint256 value)",
+        )
+        .unwrap();
+
+        let erc721 = AbiEvent::from_signature(
+            "Transfer(address indexed from, address indexed to, uint256 indexed tokenId)",
+        )
+        .unwrap();
+
+        let log = log(&[TRANSFER, FROM, TO, AMOUNT], &[]);
+
+        assert!(!erc20.matches(&log));
+        assert!(erc20.decode_log(&log).is_err());
+
+        assert!(erc721.matches(&log));
+        assert_eq!(
+            erc721.decode_log(&log).unwrap().params.get("tokenId"),
+            Some(&AbiValue::from(1000_u32))
+        );
+    }
+
+    #[test]
+    fn indexed_dynamic_values_are_hashes() {
+        let event = AbiEvent::from_signature("Named(string indexed name, string note)").unwrap();
+
+        let name_hash: [u8; 32] = Keccak256::digest(b"alice").into();
+
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+            "6869000000000000000000000000000000000000000000000000000000000000"
+        );
+
+        let decoded = event.decode_log(&log(&[event.topic(), name_hash], &data)).unwrap();
+
+        assert_eq!(decoded.params.get("name"), Some(&AbiValue::FixedBytes(name_hash.to_vec())));
+        assert_eq!(decoded.params.get("note").and_then(AbiValue::as_str), Some("hi"));
+    }
+
+    #[test]
+    fn anonymous() {
+        let mut event = AbiEvent::from_signature("Ping(uint8 indexed id)").unwrap();
+        event.anonymous = true;
+
+        let id = hex!("0000000000000000000000000000000000000000000000000000000000000007");
+
+        assert_eq!(
+            event.decode_log(&log(&[id], &[])).unwrap().params.get_index(0),
+            Some(&AbiValue::from(7_u8))
+        );
+
+        assert_eq!(event.inputs[0].param_type, AbiType::Uint(8));
+    }
+}
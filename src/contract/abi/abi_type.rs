@@ -0,0 +1,341 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Error;
+
+/// A Solidity ABI type.
+///
+/// Parsed from (and displayed as) the canonical type name used in function signatures,
+/// for example `uint256`, `bytes32[2]`, or `(uint256,address)[]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AbiType {
+    /// `uint<N>`, where `N` is the number of bits (a multiple of 8, from 8 to 256).
+    Uint(usize),
+
+    /// `int<N>`, where `N` is the number of bits (a multiple of 8, from 8 to 256).
+    Int(usize),
+
+    /// `address`.
+    Address,
+
+    /// `bool`.
+    Bool,
+
+    /// `bytes<N>`, where `N` is the number of bytes (from 1 to 32).
+    FixedBytes(usize),
+
+    /// `bytes`.
+    Bytes,
+
+    /// `string`.
+    String,
+
+    /// `T[]`.
+    Array(Box<AbiType>),
+
+    /// `T[N]`.
+    FixedArray(Box<AbiType>, usize),
+
+    /// `(T1,T2,...)`, a Solidity struct.
+    Tuple(Vec<AbiType>),
+}
+
+impl AbiType {
+    /// Creates a `uint<bits>` type.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `bits` isn't a multiple of 8 from 8 to 256.
+    pub fn uint(bits: usize) -> crate::Result<Self> {
+        Self::Uint(bits).checked()
+    }
+
+    /// Creates an `int<bits>` type.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `bits` isn't a multiple of 8 from 8 to 256.
+    pub fn int(bits: usize) -> crate::Result<Self> {
+        Self::Int(bits).checked()
+    }
+
+    /// Creates a `bytes<len>` type.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `len` isn't from 1 to 32.
+    pub fn fixed_bytes(len: usize) -> crate::Result<Self> {
+        Self::FixedBytes(len).checked()
+    }
+
+    /// Creates an `inner[len]` type.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `len` is `0`.
+    pub fn fixed_array(inner: Self, len: usize) -> crate::Result<Self> {
+        Self::FixedArray(Box::new(inner), len).checked()
+    }
+
+    /// Returns an error if the size of this type is invalid, like `uint7`, `bytes33` or `uint256[0]`.
+    ///
+    /// Only this type is checked, not the types nested in it.
+    pub(crate) fn check_size(&self) -> crate::Result<()> {
+        let valid = match self {
+            Self::Uint(bits) | Self::Int(bits) => valid_bits(*bits),
+            Self::FixedBytes(len) => valid_fixed_bytes(*len),
+            Self::FixedArray(_, len) => *len > 0,
+            Self::Tuple(types) => !types.is_empty(),
+            Self::Address | Self::Bool | Self::Bytes | Self::String | Self::Array(_) => true,
+        };
+
+        if !valid {
+            return Err(Error::basic_parse(format!("invalid ABI type `{self}`")));
+        }
+
+        Ok(())
+    }
+
+    fn checked(self) -> crate::Result<Self> {
+        self.check_size().map(|()| self)
+    }
+
+    /// Returns `true` if values of this type are encoded out of line (in the "tail" of the encoding).
+    #[must_use]
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            Self::Uint(_) | Self::Int(_) | Self::Address | Self::Bool | Self::FixedBytes(_) => {
+                false
+            }
+            Self::Bytes | Self::String | Self::Array(_) => true,
+            Self::FixedArray(inner, _) => inner.is_dynamic(),
+            Self::Tuple(types) => types.iter().any(Self::is_dynamic),
+        }
+    }
+
+    /// The number of bytes a value of this type takes up in the "head" of the encoding.
+    pub(crate) fn head_size(&self) -> usize {
+        match self {
+            _ if self.is_dynamic() => 32,
+            Self::FixedArray(inner, len) => inner.head_size().saturating_mul(*len),
+            Self::Tuple(types) => {
+                types.iter().fold(0, |acc, it| acc.saturating_add(it.head_size()))
+            }
+            _ => 32,
+        }
+    }
+}
+
+impl fmt::Display for AbiType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Uint(bits) => write!(f, "uint{bits}"),
+            Self::Int(bits) => write!(f, "int{bits}"),
+            Self::Address => f.write_str("address"),
+            Self::Bool => f.write_str("bool"),
+            Self::FixedBytes(len) => write!(f, "bytes{len}"),
+            Self::Bytes => f.write_str("bytes"),
+            Self::String => f.write_str("string"),
+            Self::Array(inner) => write!(f, "{inner}[]"),
+            Self::FixedArray(inner, len) => write!(f, "{inner}[{len}]"),
+            Self::Tuple(types) => {
+                f.write_str("(")?;
+
+                for (index, ty) in types.iter().enumerate() {
+                    if index != 0 {
+                        f.write_str(",")?;
+                    }
+
+                    write!(f, "{ty}")?;
+                }
+
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl FromStr for AbiType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let s = s.trim();
+
+        if let Some(inner) = s.strip_suffix(']') {
+            let (inner, len) = inner
+                .rsplit_once('[')
+                .ok_or_else(|| Error::basic_parse(format!("invalid ABI type `{s}`")))?;
+
+            let inner = Box::new(inner.parse()?);
+
+            if len.is_empty() {
+                return Ok(Self::Array(inner));
+            }
+
+            return Some(len)
+                .filter(|it| it.bytes().all(|it| it.is_ascii_digit()))
+                .and_then(|it| it.parse().ok())
+                .filter(|&it| it > 0)
+                .map(|len| Self::FixedArray(inner, len))
+                .ok_or_else(|| Error::basic_parse(format!("invalid ABI type `{s}`")));
+        }
+
+        if let Some(types) = s.strip_prefix('(').and_then(|it| it.strip_suffix(')')) {
+            // Solidity doesn't allow empty structs.
+            if types.trim().is_empty() {
+                return Err(Error::basic_parse(format!("invalid ABI type `{s}`")));
+            }
+
+            return split_types(types)
+                .into_iter()
+                .map(str::parse)
+                .collect::<crate::Result<_>>()
+                .map(Self::Tuple);
+        }
+
+        let parse_size = |digits: &str, valid: fn(usize) -> bool| {
+            Some(digits)
+                .filter(|it| it.bytes().all(|it| it.is_ascii_digit()))
+                .and_then(|it| it.parse().ok())
+                .filter(|&it| valid(it))
+                .ok_or_else(|| Error::basic_parse(format!("invalid ABI type `{s}`")))
+        };
+
+        match s {
+            "address" => Ok(Self::Address),
+            "bool" => Ok(Self::Bool),
+            "bytes" => Ok(Self::Bytes),
+            "string" => Ok(Self::String),
+            // `uint` and `int` are aliases for their 256 bit versions.
+            "uint" => Ok(Self::Uint(256)),
+            "int" => Ok(Self::Int(256)),
+            _ => {
+                if let Some(bits) = s.strip_prefix("uint") {
+                    parse_size(bits, valid_bits).map(Self::Uint)
+                } else if let Some(bits) = s.strip_prefix("int") {
+                    parse_size(bits, valid_bits).map(Self::Int)
+                } else if let Some(len) = s.strip_prefix("bytes") {
+                    parse_size(len, valid_fixed_bytes).map(Self::FixedBytes)
+                } else {
+                    Err(Error::basic_parse(format!("unsupported ABI type `{s}`")))
+                }
+            }
+        }
+    }
+}
+
+fn valid_bits(bits: usize) -> bool {
+    bits.is_multiple_of(8) && (8..=256).contains(&bits)
+}
+
+fn valid_fixed_bytes(len: usize) -> bool {
+    (1..=32).contains(&len)
+}
+
+/// Splits a comma separated list of types, ignoring commas nested in parentheses.
+pub(super) fn split_types(list: &str) -> Vec<&str> {
+    if list.trim().is_empty() {
+        return Vec::new();
+    }
+
+    let mut depth = 0_usize;
+    let mut start = 0;
+    let mut types = Vec::new();
+
+    for (index, ch) in list.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                types.push(list[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    types.push(list[start..].trim());
+
+    types
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AbiType;
+
+    #[test]
+    fn parse_display_round_trip() {
+        for s in [
+            "uint256",
+            "int8",
+            "address",
+            "bool",
+            "bytes32",
+            "bytes",
+            "string",
+            "uint64[]",
+            "string[][]",
+            "uint256[3]",
+            "uint8[2][]",
+            "(uint256,address)",
+            "(uint256,address)[]",
+            "(bool,(string,bytes32[2]))[3]",
+        ] {
+            assert_eq!(s.parse::<AbiType>().unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn aliases() {
+        assert_eq!("uint".parse::<AbiType>().unwrap(), AbiType::Uint(256));
+        assert_eq!("int[]".parse::<AbiType>().unwrap().to_string(), "int256[]");
+        assert_eq!("( uint, int )".parse::<AbiType>().unwrap().to_string(), "(uint256,int256)");
+    }
+
+    #[test]
+    fn dynamic() {
+        let ty = |s: &str| s.parse::<AbiType>().unwrap();
+
+        assert!(!ty("uint256[3]").is_dynamic());
+        assert!(ty("string[3]").is_dynamic());
+        assert!(!ty("(uint256,address)").is_dynamic());
+        assert!(ty("(uint256,bytes)").is_dynamic());
+
+        assert_eq!(ty("(uint256,address)").head_size(), 64);
+        assert_eq!(ty("(uint256,bytes32[2])[3]").head_size(), 288);
+        assert_eq!(ty("(uint256,bytes)[3]").head_size(), 32);
+    }
+
+    #[test]
+    fn invalid() {
+        for s in [
+            "uint7",
+            "uint264",
+            "int0",
+            "bytes0",
+            "bytes33",
+            "foo",
+            "uint256[0]",
+            "uint256[x]",
+            "(uint256",
+            "()",
+            "tuple",
+        ] {
+            assert!(s.parse::<AbiType>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn constructors() {
+        assert_eq!(AbiType::uint(64).unwrap(), AbiType::Uint(64));
+        assert_eq!(AbiType::int(8).unwrap(), AbiType::Int(8));
+        assert_eq!(AbiType::fixed_bytes(32).unwrap(), AbiType::FixedBytes(32));
+        assert_eq!(AbiType::fixed_array(AbiType::Bool, 2).unwrap().to_string(), "bool[2]");
+
+        assert!(AbiType::uint(0).is_err());
+        assert!(AbiType::uint(7).is_err());
+        assert!(AbiType::int(264).is_err());
+        assert!(AbiType::fixed_bytes(0).is_err());
+        assert!(AbiType::fixed_bytes(40).is_err());
+        assert!(AbiType::fixed_array(AbiType::Bool, 0).is_err());
+    }
+}
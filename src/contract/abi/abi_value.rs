@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use num_bigint::{
+    BigInt,
+    BigUint,
+};
+
+use crate::EvmAddress;
+
+/// A dynamically typed Solidity ABI value.
+///
+/// Values don't carry their exact ABI type (`uint8` and `uint256` are both [`AbiValue::Uint`]),
+/// the type comes from the [`AbiType`](crate::AbiType) they're encoded as or decoded from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AbiValue {
+    /// An unsigned integer (`uint<N>`).
+    Uint(BigUint),
+
+    /// A signed integer (`int<N>`).
+    Int(BigInt),
+
+    /// An `address`.
+    Address(EvmAddress),
+
+    /// A `bool`.
+    Bool(bool),
+
+    /// A fixed size byte array (`bytes<N>`).
+    FixedBytes(Vec<u8>),
+
+    /// A dynamically sized byte array (`bytes`).
+    Bytes(Vec<u8>),
+
+    /// A `string`.
+    String(String),
+
+    /// A dynamically sized array (`T[]`).
+    Array(Vec<AbiValue>),
+
+    /// A fixed size array (`T[N]`).
+    FixedArray(Vec<AbiValue>),
+
+    /// A tuple (`(T1,T2,...)`), which is how Solidity structs are encoded.
+    Tuple(Vec<AbiValue>),
+}
+
+impl AbiValue {
+    /// Returns the value of a `bool`.
+    #[must_use]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(it) => Some(*it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a `uint<N>`.
+    #[must_use]
+    pub fn as_uint(&self) -> Option<&BigUint> {
+        match self {
+            Self::Uint(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of an `int<N>`.
+    #[must_use]
+    pub fn as_int(&self) -> Option<&BigInt> {
+        match self {
+            Self::Int(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of an `address`.
+    #[must_use]
+    pub fn as_address(&self) -> Option<EvmAddress> {
+        match self {
+            Self::Address(it) => Some(*it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a `bytes` or `bytes<N>`.
+    #[must_use]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(it) | Self::FixedBytes(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a `string`.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of a `T[]` or `T[N]`.
+    #[must_use]
+    pub fn as_array(&self) -> Option<&[AbiValue]> {
+        match self {
+            Self::Array(it) | Self::FixedArray(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the fields of a tuple (struct).
+    #[must_use]
+    pub fn as_tuple(&self) -> Option<&[AbiValue]> {
+        match self {
+            Self::Tuple(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_from_uint {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for AbiValue {
+                fn from(value: $ty) -> Self {
+                    Self::Uint(value.into())
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for AbiValue {
+                fn from(value: $ty) -> Self {
+                    Self::Int(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_uint!(u8, u16, u32, u64, u128, BigUint);
+impl_from_int!(i8, i16, i32, i64, i128, BigInt);
+
+impl From<bool> for AbiValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<EvmAddress> for AbiValue {
+    fn from(value: EvmAddress) -> Self {
+        Self::Address(value)
+    }
+}
+
+impl From<[u8; 32]> for AbiValue {
+    fn from(value: [u8; 32]) -> Self {
+        Self::FixedBytes(value.to_vec())
+    }
+}
+
+impl From<Vec<u8>> for AbiValue {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl From<&str> for AbiValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for AbiValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<Vec<AbiValue>> for AbiValue {
+    fn from(value: Vec<AbiValue>) -> Self {
+        Self::Array(value)
+    }
+}
@@ -0,0 +1,700 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use super::abi_event::is_hashed;
+use super::{
+    AbiEvent,
+    AbiFunction,
+    AbiParam,
+    AbiType,
+    ContractAbi,
+};
+
+/// Generates typed Rust bindings for a contract from its ABI, meant to be run from a build script.
+///
+/// The generated code contains:
+/// - A struct named after the contract, wrapping its [`ContractId`](crate::ContractId).
+/// - A method per function, returning a ready [`ContractExecuteTransaction`](crate::ContractExecuteTransaction)
+///   (or, for `view` and `pure` functions, a [`ContractCallQuery`](crate::ContractCallQuery)).
+/// - A `decode_*` function per `view` and `pure` function, decoding its [`ContractFunctionResult`](crate::ContractFunctionResult).
+/// - A struct per event (`<Name>Event`) that can decode [`ContractLogInfo`](crate::ContractLogInfo)s.
+///
+/// Solidity integers wider than 128 bits are `num_bigint::BigUint`/`BigInt`, so crates using bindings for them
+/// need to depend on `num-bigint`.
+///
+/// Overloaded functions, and parameters that would clash with the generated code (like `abi` or `contract_id`),
+/// get a numeric suffix: `transfer_1`, `abi_1`.
+///
+/// # Examples
+/// In `build.rs`:
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use hiero_sdk::ContractBindings;
+///
+/// let json = std::fs::read_to_string("abi/Erc20.json")?;
+/// let out_dir = std::env::var("OUT_DIR")?;
+///
+/// ContractBindings::from_json("Erc20", &json)?.write_to_file(format!("{out_dir}/erc20.rs"))?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// And then in the crate:
+/// ```ignore
+/// mod erc20 {
+///     include!(concat!(env!("OUT_DIR"), "/erc20.rs"));
+/// }
+///
+/// let token = erc20::Erc20::new(contract_id);
+///
+/// token.transfer(to, 100)?.gas(100_000).execute(&client).await?;
+///
+/// let result = token.balance_of(owner)?.gas(30_000).execute(&client).await?;
+/// let balance = erc20::Erc20::decode_balance_of(&result)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContractBindings {
+    name: String,
+    abi: ContractAbi,
+}
+
+impl ContractBindings {
+    /// Creates a generator for the contract `name` (which is used as the name of the generated struct).
+    pub fn new(name: impl Into<String>, abi: ContractAbi) -> Self {
+        Self { name: name.into(), abi }
+    }
+
+    /// Creates a generator for the contract `name` from its ABI JSON.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if `json` isn't valid ABI JSON.
+    #[cfg(feature = "serde")]
+    pub fn from_json(name: impl Into<String>, json: &str) -> crate::Result<Self> {
+        Ok(Self::new(name, ContractAbi::from_json(json)?))
+    }
+
+    /// Writes the generated bindings to `path`.
+    ///
+    /// # Errors
+    /// If writing to `path` fails.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.generate())
+    }
+
+    /// Generates the bindings as Rust source code.
+    #[must_use]
+    pub fn generate(&self) -> String {
+        let name = &self.name;
+        let mut out = String::new();
+
+        let _ = writeln!(out, "// Generated by `hiero_sdk::ContractBindings`, do not edit.");
+        let _ = writeln!(out);
+        let _ = writeln!(out, "/// Typed bindings for the `{name}` contract.");
+        let _ = writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]");
+        let _ = writeln!(out, "pub struct {name} {{");
+        let _ = writeln!(out, "    /// The contract instance to call.");
+        let _ = writeln!(out, "    pub contract_id: ::hiero_sdk::ContractId,");
+        let _ = writeln!(out, "}}");
+        let _ = writeln!(out);
+        let _ = writeln!(out, "#[allow(clippy::too_many_arguments, clippy::type_complexity)]");
+        let _ = writeln!(out, "impl {name} {{");
+        let _ = writeln!(
+            out,
+            "    /// Creates bindings for the `{name}` contract instance `contract_id`."
+        );
+        let _ = writeln!(out, "    #[must_use]");
+        let _ = writeln!(out, "    pub fn new(contract_id: ::hiero_sdk::ContractId) -> Self {{");
+        let _ = writeln!(out, "        Self {{ contract_id }}");
+        let _ = writeln!(out, "    }}");
+
+        self.write_constructor(&mut out);
+
+        let mut names = reserved(&["new", "constructor_parameters"]);
+
+        for function in self.abi.functions() {
+            let method = unique(&mut names, snake_case(&function.name));
+            write_function(&mut out, &method, function);
+        }
+
+        let _ = writeln!(out, "}}");
+
+        let mut names = reserved(&[name.as_str()]);
+
+        for event in self.abi.events() {
+            let struct_name = unique(&mut names, format!("{}Event", pascal_case(&event.name)));
+            write_event(&mut out, &struct_name, event);
+        }
+
+        out
+    }
+
+    fn write_constructor(&self, out: &mut String) {
+        let inputs = self.abi.constructor_inputs();
+
+        let _ = writeln!(out);
+        let _ = writeln!(
+            out,
+            "    /// Encodes the constructor parameters for deploying a new `{}`.",
+            self.name
+        );
+        let _ = writeln!(out, "    ///");
+        let _ = writeln!(out, "    /// # Errors");
+        let _ = writeln!(out, "    /// If an argument is out of range for its Solidity type.");
+        let _ = writeln!(
+            out,
+            "    pub fn constructor_parameters({}) -> ::hiero_sdk::Result<Vec<u8>> {{",
+            rust_params(inputs)
+        );
+        let _ = writeln!(out, "        let mut abi = ::hiero_sdk::ContractAbi::new();");
+        let _ = writeln!(out, "        abi.constructor(vec![{}]);", abi_params(inputs));
+        let _ = writeln!(out, "        abi.encode_constructor(&[{}])", abi_values(inputs));
+        let _ = writeln!(out, "    }}");
+    }
+}
+
+fn write_function(out: &mut String, method: &str, function: &AbiFunction) {
+    let signature = function.signature();
+    let params = rust_params(&function.inputs);
+    let self_params =
+        if params.is_empty() { "&self".to_owned() } else { format!("&self, {params}") };
+
+    let (kind, doc) = if function.is_read_only() {
+        ("ContractCallQuery", "Queries")
+    } else {
+        ("ContractExecuteTransaction", "Calls")
+    };
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    /// {doc} `{signature}`.");
+    let _ = writeln!(out, "    ///");
+    let _ = writeln!(out, "    /// # Errors");
+    let _ = writeln!(out, "    /// If an argument is out of range for its Solidity type.");
+    let _ = writeln!(
+        out,
+        "    pub fn {method}({self_params}) -> ::hiero_sdk::Result<::hiero_sdk::{kind}> {{"
+    );
+    let _ = writeln!(
+        out,
+        "        let data = ::hiero_sdk::AbiFunction::from_signature({signature:?})?.encode_call(&[{}])?;",
+        abi_values(&function.inputs)
+    );
+    let _ = writeln!(out, "        let mut request = ::hiero_sdk::{kind}::new();");
+    let _ =
+        writeln!(out, "        request.contract_id(self.contract_id).function_parameters(data);");
+    let _ = writeln!(out, "        Ok(request)");
+    let _ = writeln!(out, "    }}");
+
+    if !function.is_read_only() || function.outputs.is_empty() {
+        return;
+    }
+
+    let types: Vec<_> = function.outputs.iter().map(|it| &it.param_type).collect();
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    /// Decodes the result of `{signature}`.");
+    let _ = writeln!(out, "    ///");
+    let _ = writeln!(out, "    /// # Errors");
+    let _ = writeln!(
+        out,
+        "    /// If the result isn't a valid encoding of the function's return values."
+    );
+    let _ = writeln!(
+        out,
+        "    pub fn decode_{}(result: &::hiero_sdk::ContractFunctionResult) -> ::hiero_sdk::Result<{}> {{",
+        method.trim_start_matches("r#"),
+        rust_tuple(&types)
+    );
+    write_decode_fn(out, &types, false);
+
+    let parsed: Vec<_> = types.iter().map(|it| format!("{:?}.parse()?", it.to_string())).collect();
+
+    let _ = writeln!(out, "        let values = result.decode_values(&[{}])?;", parsed.join(", "));
+    let _ = writeln!(out, "        decode(&values).ok_or_else(|| {})", decode_error(&signature));
+    let _ = writeln!(out, "    }}");
+}
+
+fn write_event(out: &mut String, struct_name: &str, event: &AbiEvent) {
+    let signature = event.signature();
+
+    let mut names = reserved(&["contract_id"]);
+    let fields: Vec<_> = event
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(index, param)| {
+            let field = unique(&mut names, param_name(&param.name, index));
+
+            // indexed dynamic values are only stored as their hash.
+            let field_type = if param.indexed && is_hashed(&param.param_type) {
+                AbiType::FixedBytes(32)
+            } else {
+                param.param_type.clone()
+            };
+
+            (field, field_type, param)
+        })
+        .collect();
+
+    let human_readable: Vec<_> = event
+        .inputs
+        .iter()
+        .map(|it| {
+            let mut param = it.param_type.to_string();
+            if it.indexed {
+                param.push_str(" indexed");
+            }
+            if !it.name.is_empty() {
+                param.push(' ');
+                param.push_str(&it.name);
+            }
+            param
+        })
+        .collect();
+
+    let human_readable = format!("{}({})", event.name, human_readable.join(", "));
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "/// The `{signature}` event.");
+    let _ = writeln!(out, "#[derive(Debug, Clone, PartialEq, Eq)]");
+    let _ = writeln!(out, "#[allow(clippy::type_complexity)]");
+    let _ = writeln!(out, "pub struct {struct_name} {{");
+    let _ = writeln!(out, "    /// The contract that emitted the event.");
+    let _ = writeln!(out, "    pub contract_id: ::hiero_sdk::ContractId,");
+
+    for (field, field_type, param) in &fields {
+        let _ = writeln!(out);
+        let _ = writeln!(
+            out,
+            "    /// `{}{}`.",
+            param.param_type,
+            if param.indexed { " indexed" } else { "" }
+        );
+        let _ = writeln!(out, "    pub {field}: {},", rust_type(field_type));
+    }
+
+    let _ = writeln!(out, "}}");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "#[allow(clippy::type_complexity)]");
+    let _ = writeln!(out, "impl {struct_name} {{");
+    let _ = writeln!(out, "    /// Returns the ABI of this event.");
+    let _ = writeln!(out, "    #[must_use]");
+    let _ = writeln!(out, "    pub fn abi() -> ::hiero_sdk::AbiEvent {{");
+    let from_signature = format!(
+        "::hiero_sdk::AbiEvent::from_signature({human_readable:?}).expect(\"generated from a valid ABI\")"
+    );
+
+    if event.anonymous {
+        let _ = writeln!(out, "        let mut event = {from_signature};");
+        let _ = writeln!(out, "        event.anonymous = true;");
+        let _ = writeln!(out, "        event");
+    } else {
+        let _ = writeln!(out, "        {from_signature}");
+    }
+    let _ = writeln!(out, "    }}");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    /// Decodes `log` as this event.");
+    let _ = writeln!(out, "    ///");
+    let _ = writeln!(out, "    /// # Errors");
+    let _ = writeln!(out, "    /// If `log` wasn't emitted for this event.");
+    let _ = writeln!(
+        out,
+        "    pub fn decode(log: &::hiero_sdk::ContractLogInfo) -> ::hiero_sdk::Result<Self> {{"
+    );
+    let _ = writeln!(out, "        Self::from_decoded(Self::abi().decode_log(log)?)");
+    let _ = writeln!(out, "    }}");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    /// Decodes every one of these events emitted in `record`.");
+    let _ = writeln!(out, "    ///");
+    let _ = writeln!(out, "    /// # Errors");
+    let _ = writeln!(out, "    /// If a log emitted for this event can't be decoded.");
+    let _ = writeln!(
+        out,
+        "    pub fn from_record(record: &::hiero_sdk::TransactionRecord) -> ::hiero_sdk::Result<Vec<Self>> {{"
+    );
+    let _ = writeln!(
+        out,
+        "        record.decode_events(&Self::abi())?.into_iter().map(Self::from_decoded).collect()"
+    );
+    let _ = writeln!(out, "    }}");
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "    fn from_decoded(event: ::hiero_sdk::DecodedEvent) -> ::hiero_sdk::Result<Self> {{"
+    );
+
+    let field_names: Vec<_> = fields.iter().map(|(field, ..)| field.as_str()).collect();
+
+    if !fields.is_empty() {
+        let types: Vec<_> = fields.iter().map(|(_, ty, _)| ty).collect();
+
+        write_decode_fn(out, &types, true);
+        let _ = writeln!(out, "        let values = event.params.into_values();");
+        let _ = writeln!(
+            out,
+            "        let ({},) = decode(&values).ok_or_else(|| {})?;",
+            field_names.join(", "),
+            decode_error(&signature)
+        );
+    }
+
+    let _ = writeln!(
+        out,
+        "        Ok(Self {{ contract_id: event.contract_id{} }})",
+        field_names.iter().map(|it| format!(", {it}")).collect::<String>()
+    );
+    let _ = writeln!(out, "    }}");
+    let _ = writeln!(out, "}}");
+}
+
+// `fn decode(values: &[AbiValue]) -> Option<..>`, converting decoded values into their Rust types.
+fn write_decode_fn(out: &mut String, types: &[&AbiType], always_tuple: bool) {
+    let (return_type, body) = match types {
+        // `Some(x?)` would be needless.
+        [ty] if !always_tuple => (rust_type(ty), from_value(ty, "values.first()?")),
+        _ => {
+            let conversions: Vec<_> = types
+                .iter()
+                .enumerate()
+                .map(|(index, ty)| format!("{}?", from_value(ty, &element("values", index))))
+                .collect();
+
+            let types: Vec<_> = types.iter().map(|it| rust_type(it)).collect();
+
+            (format!("({},)", types.join(", ")), format!("Some(({},))", conversions.join(", ")))
+        }
+    };
+
+    let _ = writeln!(
+        out,
+        "        fn decode(values: &[::hiero_sdk::AbiValue]) -> Option<{return_type}> {{"
+    );
+    let _ = writeln!(out, "            {body}");
+    let _ = writeln!(out, "        }}");
+    let _ = writeln!(out);
+}
+
+fn decode_error(signature: &str) -> String {
+    format!(
+        "::hiero_sdk::Error::BasicParse({:?}.into())",
+        format!("unexpected values for `{signature}`")
+    )
+}
+
+fn rust_params(params: &[AbiParam]) -> String {
+    params
+        .iter()
+        .zip(param_names(params))
+        .map(|(it, name)| format!("{name}: {}", rust_type(&it.param_type)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn abi_params(params: &[AbiParam]) -> String {
+    params
+        .iter()
+        .map(|it| {
+            format!(
+                "::hiero_sdk::AbiParam::new({:?}, {:?}.parse()?)",
+                it.name,
+                it.param_type.to_string()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn abi_values(params: &[AbiParam]) -> String {
+    params
+        .iter()
+        .zip(param_names(params))
+        .map(|(it, name)| to_value(&it.param_type, &name, 0))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// the Rust names of function (or constructor) parameters, avoiding the locals of the generated code.
+fn param_names(params: &[AbiParam]) -> Vec<String> {
+    let mut names = reserved(&["abi", "data", "request"]);
+
+    params
+        .iter()
+        .enumerate()
+        .map(|(index, it)| unique(&mut names, param_name(&it.name, index)))
+        .collect()
+}
+
+fn rust_tuple(types: &[&AbiType]) -> String {
+    match types {
+        [ty] => rust_type(ty),
+        _ => format!("({})", types.iter().map(|it| rust_type(it)).collect::<Vec<_>>().join(", ")),
+    }
+}
+
+fn rust_type(ty: &AbiType) -> String {
+    match ty {
+        AbiType::Uint(bits) => match bits {
+            ..=128 => format!("u{}", bits.next_power_of_two().max(8)),
+            _ => "::num_bigint::BigUint".to_owned(),
+        },
+        AbiType::Int(bits) => match bits {
+            ..=128 => format!("i{}", bits.next_power_of_two().max(8)),
+            _ => "::num_bigint::BigInt".to_owned(),
+        },
+        AbiType::Address => "::hiero_sdk::EvmAddress".to_owned(),
+        AbiType::Bool => "bool".to_owned(),
+        AbiType::FixedBytes(len) => format!("[u8; {len}]"),
+        AbiType::Bytes => "Vec<u8>".to_owned(),
+        AbiType::String => "String".to_owned(),
+        AbiType::Array(inner) => format!("Vec<{}>", rust_type(inner)),
+        AbiType::FixedArray(inner, len) => format!("[{}; {len}]", rust_type(inner)),
+        AbiType::Tuple(types) => {
+            let trailing = if types.len() == 1 { "," } else { "" };
+            format!("({}{trailing})", types.iter().map(rust_type).collect::<Vec<_>>().join(", "))
+        }
+    }
+}
+
+// converts the owned value `expr` into an `AbiValue`.
+fn to_value(ty: &AbiType, expr: &str, depth: usize) -> String {
+    match ty {
+        AbiType::Uint(_) | AbiType::Int(_) | AbiType::Address | AbiType::Bool | AbiType::String => {
+            format!("::hiero_sdk::AbiValue::from({expr})")
+        }
+        AbiType::FixedBytes(_) => format!("::hiero_sdk::AbiValue::FixedBytes({expr}.to_vec())"),
+        AbiType::Bytes => format!("::hiero_sdk::AbiValue::Bytes({expr})"),
+        AbiType::Array(inner) | AbiType::FixedArray(inner, _) => {
+            let variant = if matches!(ty, AbiType::Array(_)) { "Array" } else { "FixedArray" };
+            let it = format!("it{depth}");
+
+            format!(
+                "::hiero_sdk::AbiValue::{variant}({expr}.into_iter().map(|{it}| {}).collect())",
+                to_value(inner, &it, depth + 1)
+            )
+        }
+        AbiType::Tuple(types) => {
+            let names: Vec<_> = (0..types.len()).map(|index| format!("v{depth}_{index}")).collect();
+            let values: Vec<_> =
+                types.iter().zip(&names).map(|(ty, name)| to_value(ty, name, depth + 1)).collect();
+
+            format!(
+                "{{ let ({},) = {expr}; ::hiero_sdk::AbiValue::Tuple(vec![{}]) }}",
+                names.join(", "),
+                values.join(", ")
+            )
+        }
+    }
+}
+
+// converts `expr: &AbiValue` into an `Option` of the Rust type for `ty`.
+fn from_value(ty: &AbiType, expr: &str) -> String {
+    match ty {
+        AbiType::Uint(bits) | AbiType::Int(bits) => {
+            let accessor = if matches!(ty, AbiType::Uint(_)) { "as_uint" } else { "as_int" };
+
+            if *bits > 128 {
+                format!("{expr}.{accessor}().cloned()")
+            } else {
+                format!("{expr}.{accessor}().and_then(|it| {}::try_from(it).ok())", rust_type(ty))
+            }
+        }
+        AbiType::Address => format!("{expr}.as_address()"),
+        AbiType::Bool => format!("{expr}.as_bool()"),
+        AbiType::FixedBytes(len) => {
+            format!("{expr}.as_bytes().and_then(|it| <[u8; {len}]>::try_from(it).ok())")
+        }
+        AbiType::Bytes => format!("{expr}.as_bytes().map(<[u8]>::to_vec)"),
+        AbiType::String => format!("{expr}.as_str().map(str::to_owned)"),
+        AbiType::Array(inner) => format!(
+            "{expr}.as_array().and_then(|it| it.iter().map(|it| {}).collect::<Option<Vec<_>>>())",
+            from_value(inner, "it")
+        ),
+        AbiType::FixedArray(inner, _) => format!(
+            "{expr}.as_array().and_then(|it| it.iter().map(|it| {}).collect::<Option<Vec<_>>>()).and_then(|it| it.try_into().ok())",
+            from_value(inner, "it")
+        ),
+        AbiType::Tuple(types) => {
+            let trailing = if types.len() == 1 { "," } else { "" };
+            let fields: Vec<_> = types
+                .iter()
+                .enumerate()
+                .map(|(index, ty)| format!("{}?", from_value(ty, &element("it", index))))
+                .collect();
+
+            format!("{expr}.as_tuple().and_then(|it| Some(({}{trailing})))", fields.join(", "))
+        }
+    }
+}
+
+// `<slice>.get(<index>)?`, using `first` for the first element.
+fn element(slice: &str, index: usize) -> String {
+    match index {
+        0 => format!("{slice}.first()?"),
+        _ => format!("{slice}.get({index})?"),
+    }
+}
+
+fn param_name(name: &str, index: usize) -> String {
+    match snake_case(name) {
+        it if it.is_empty() => format!("arg{index}"),
+        it => it,
+    }
+}
+
+// names already taken by the generated code, for `unique`.
+fn reserved(names: &[&str]) -> HashMap<String, usize> {
+    names.iter().map(|&it| (it.to_owned(), 1)).collect()
+}
+
+// appends a number to `name` if it's already been used.
+fn unique(seen: &mut HashMap<String, usize>, name: String) -> String {
+    let count = seen.entry(name.clone()).or_default();
+    *count += 1;
+
+    match *count {
+        1 => name,
+        count => format!("{}_{}", name.trim_start_matches("r#"), count - 1),
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let chars: Vec<_> = name.trim_start_matches('_').chars().collect();
+    let mut out = String::with_capacity(chars.len());
+
+    for (index, &ch) in chars.iter().enumerate() {
+        if ch.is_ascii_uppercase() {
+            let prev = index.checked_sub(1).map(|it| chars[it]);
+            let next = chars.get(index + 1);
+
+            // `balanceOf` -> `balance_of`, `ERC20Name` -> `erc20_name`.
+            let boundary = prev.is_some_and(|it| it.is_ascii_lowercase() || it.is_ascii_digit())
+                || (prev.is_some_and(|it| it.is_ascii_uppercase())
+                    && next.is_some_and(char::is_ascii_lowercase));
+
+            if boundary && !out.ends_with('_') {
+                out.push('_');
+            }
+
+            out.push(ch.to_ascii_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+
+    escape_keyword(out)
+}
+
+fn pascal_case(name: &str) -> String {
+    let mut chars = name.trim_start_matches('_').chars();
+
+    chars.next().map(|it| it.to_ascii_uppercase()).into_iter().chain(chars).collect()
+}
+
+fn escape_keyword(name: String) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "async", "await", "box", "break", "const", "continue", "do", "dyn", "else", "enum",
+        "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "macro",
+        "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+        "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+        "where", "while", "yield",
+    ];
+
+    match name.as_str() {
+        // these can't be raw identifiers.
+        "self" | "super" | "crate" | "Self" => format!("{name}_"),
+        it if KEYWORDS.contains(&it) => format!("r#{name}"),
+        _ => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect_file;
+
+    use super::{
+        snake_case,
+        ContractBindings,
+    };
+    use crate::ContractAbi;
+
+    const ABI: &str = r#"[
+        {
+            "type": "constructor",
+            "inputs": [{ "name": "initialSupply", "type": "uint256" }, { "name": "abi", "type": "string" }]
+        },
+        {
+            "type": "function",
+            "name": "transfer",
+            "stateMutability": "nonpayable",
+            "inputs": [{ "name": "to", "type": "address" }, { "name": "amount", "type": "uint64" }],
+            "outputs": [{ "name": "", "type": "bool" }]
+        },
+        {
+            "type": "function",
+            "name": "balanceOf",
+            "stateMutability": "view",
+            "inputs": [{ "name": "account", "type": "address" }],
+            "outputs": [{ "name": "", "type": "uint24" }]
+        },
+        {
+            "type": "function",
+            "name": "order",
+            "stateMutability": "view",
+            "inputs": [{ "name": "type", "type": "uint8" }],
+            "outputs": [{
+                "name": "",
+                "type": "tuple",
+                "components": [{ "name": "to", "type": "address" }, { "name": "tags", "type": "string[]" }]
+            }, { "name": "", "type": "bytes4[2]" }]
+        },
+        {
+            "type": "event",
+            "name": "Transfer",
+            "anonymous": false,
+            "inputs": [
+                { "name": "from", "type": "address", "indexed": true },
+                { "name": "memo", "type": "string", "indexed": true },
+                { "name": "value", "type": "uint256", "indexed": false }
+            ]
+        },
+        {
+            "type": "function",
+            "name": "new",
+            "stateMutability": "nonpayable",
+            "inputs": [{ "name": "data", "type": "bytes" }, { "name": "request", "type": "bytes32" }],
+            "outputs": []
+        },
+        {
+            "type": "event",
+            "name": "Deployed",
+            "anonymous": false,
+            "inputs": [{ "name": "contractId", "type": "address", "indexed": false }]
+        },
+        {
+            "type": "event",
+            "name": "paused",
+            "anonymous": true,
+            "inputs": []
+        }
+    ]"#;
+
+    #[test]
+    fn generate() {
+        let bindings = ContractBindings::new("Token", ContractAbi::from_json(ABI).unwrap());
+
+        expect_file!["./snapshots/bindings/generate.txt"].assert_eq(&bindings.generate());
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(snake_case("balanceOf"), "balance_of");
+        assert_eq!(snake_case("DOMAIN_SEPARATOR"), "domain_separator");
+        assert_eq!(snake_case("safeTransferFrom"), "safe_transfer_from");
+        assert_eq!(snake_case("ERC20Name"), "erc20_name");
+        assert_eq!(snake_case("_owner"), "owner");
+        assert_eq!(snake_case("type"), "r#type");
+        assert_eq!(snake_case("self"), "self_");
+    }
+}
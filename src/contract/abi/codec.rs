@@ -0,0 +1,662 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! The Solidity ABI "head/tail" encoding.
+//!
+//! See <https://docs.soliditylang.org/en/latest/abi-spec.html#formal-specification-of-the-encoding>.
+
+use std::iter;
+
+use num_bigint::{
+    BigInt,
+    BigUint,
+    Sign,
+};
+
+use super::{
+    AbiType,
+    AbiValue,
+};
+use crate::{
+    Error,
+    EvmAddress,
+};
+
+const WORD: usize = 32;
+
+/// How well a value matches a type, used for picking between overloaded functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum TypeMatch {
+    /// The value can't be encoded as the type.
+    None,
+
+    /// The value can be encoded as the type, but only after a conversion
+    /// (for example a non-negative [`AbiValue::Int`] as a `uint256`).
+    Loose,
+
+    /// The value is exactly the kind of value the type holds.
+    Exact,
+}
+
+pub(crate) fn type_match(ty: &AbiType, value: &AbiValue) -> TypeMatch {
+    match (ty, value) {
+        (AbiType::Uint(bits), AbiValue::Uint(it)) if uint_fits(it, *bits) => TypeMatch::Exact,
+        (AbiType::Uint(bits), AbiValue::Int(it))
+            if it.sign() != Sign::Minus && uint_fits(it.magnitude(), *bits) =>
+        {
+            TypeMatch::Loose
+        }
+        (AbiType::Int(bits), AbiValue::Int(it)) if int_fits(it, *bits) => TypeMatch::Exact,
+        (AbiType::Int(bits), AbiValue::Uint(it)) if int_fits(&BigInt::from(it.clone()), *bits) => {
+            TypeMatch::Loose
+        }
+        (AbiType::Address, AbiValue::Address(_))
+        | (AbiType::Bool, AbiValue::Bool(_))
+        | (AbiType::Bytes, AbiValue::Bytes(_))
+        | (AbiType::String, AbiValue::String(_)) => TypeMatch::Exact,
+        (AbiType::FixedBytes(len), AbiValue::FixedBytes(it)) if it.len() == *len => {
+            TypeMatch::Exact
+        }
+        (AbiType::FixedBytes(len), AbiValue::Bytes(it)) if it.len() == *len => TypeMatch::Loose,
+        (AbiType::Bytes, AbiValue::FixedBytes(_)) => TypeMatch::Loose,
+        (AbiType::Array(inner), AbiValue::Array(values)) => {
+            all_match(iter::repeat(&**inner), values)
+        }
+        (AbiType::Array(inner), AbiValue::FixedArray(values)) => {
+            all_match(iter::repeat(&**inner), values).min(TypeMatch::Loose)
+        }
+        (AbiType::FixedArray(inner, len), AbiValue::FixedArray(values)) if values.len() == *len => {
+            all_match(iter::repeat(&**inner), values)
+        }
+        (AbiType::FixedArray(inner, len), AbiValue::Array(values)) if values.len() == *len => {
+            all_match(iter::repeat(&**inner), values).min(TypeMatch::Loose)
+        }
+        (AbiType::Tuple(types), AbiValue::Tuple(values)) if values.len() == types.len() => {
+            all_match(types.iter(), values)
+        }
+        _ => TypeMatch::None,
+    }
+}
+
+fn all_match<'a>(types: impl Iterator<Item = &'a AbiType>, values: &[AbiValue]) -> TypeMatch {
+    types.zip(values).map(|(ty, value)| type_match(ty, value)).min().unwrap_or(TypeMatch::Exact)
+}
+
+/// Encodes `values` as a tuple of `types`.
+pub(crate) fn encode(types: &[AbiType], values: &[AbiValue]) -> crate::Result<Vec<u8>> {
+    if types.len() != values.len() {
+        return Err(Error::basic_parse(format!(
+            "expected {} ABI values, found {}",
+            types.len(),
+            values.len()
+        )));
+    }
+
+    encode_sequence(types.iter().zip(values))
+}
+
+fn encode_sequence<'a>(
+    items: impl Iterator<Item = (&'a AbiType, &'a AbiValue)> + Clone,
+) -> crate::Result<Vec<u8>> {
+    let head_len = items.clone().map(|(ty, _)| ty.head_size()).sum();
+
+    let mut head = Vec::with_capacity(head_len);
+    let mut tail = Vec::new();
+
+    for (ty, value) in items {
+        let encoded = encode_value(ty, value)?;
+
+        if ty.is_dynamic() {
+            head.extend_from_slice(&usize_word(head_len + tail.len()));
+            tail.extend(encoded);
+        } else {
+            head.extend(encoded);
+        }
+    }
+
+    head.extend(tail);
+
+    Ok(head)
+}
+
+/// Encodes a single value, as it appears in the "head" if `ty` is static, or in the "tail" if it's dynamic.
+pub(crate) fn encode_value(ty: &AbiType, value: &AbiValue) -> crate::Result<Vec<u8>> {
+    ty.check_size()?;
+
+    let out_of_range = || Error::basic_parse(format!("ABI value out of range for `{ty}`"));
+
+    match (ty, value) {
+        (AbiType::Uint(bits), AbiValue::Uint(_) | AbiValue::Int(_)) => {
+            let value = match value {
+                AbiValue::Uint(it) => it.clone(),
+                AbiValue::Int(it) => it.to_biguint().ok_or_else(out_of_range)?,
+                _ => unreachable!(),
+            };
+
+            if !uint_fits(&value, *bits) {
+                return Err(out_of_range());
+            }
+
+            Ok(left_pad(&value.to_bytes_be(), 0).to_vec())
+        }
+
+        (AbiType::Int(bits), AbiValue::Uint(_) | AbiValue::Int(_)) => {
+            let value = match value {
+                AbiValue::Uint(it) => BigInt::from(it.clone()),
+                AbiValue::Int(it) => it.clone(),
+                _ => unreachable!(),
+            };
+
+            if !int_fits(&value, *bits) {
+                return Err(out_of_range());
+            }
+
+            let fill = if value.sign() == Sign::Minus { 0xff } else { 0 };
+
+            Ok(left_pad(&value.to_signed_bytes_be(), fill).to_vec())
+        }
+
+        (AbiType::Address, AbiValue::Address(it)) => Ok(left_pad(&it.to_bytes(), 0).to_vec()),
+
+        (AbiType::Bool, AbiValue::Bool(it)) => Ok(usize_word(usize::from(*it)).to_vec()),
+
+        (AbiType::FixedBytes(len), AbiValue::FixedBytes(it) | AbiValue::Bytes(it)) => {
+            if it.len() != *len {
+                return Err(out_of_range());
+            }
+
+            Ok(right_pad(it))
+        }
+
+        (AbiType::Bytes, AbiValue::Bytes(it) | AbiValue::FixedBytes(it)) => Ok(encode_bytes(it)),
+
+        (AbiType::String, AbiValue::String(it)) => Ok(encode_bytes(it.as_bytes())),
+
+        (AbiType::Array(inner), AbiValue::Array(values) | AbiValue::FixedArray(values)) => {
+            let mut out = usize_word(values.len()).to_vec();
+            out.extend(encode_sequence(values.iter().map(|it| (&**inner, it)))?);
+            Ok(out)
+        }
+
+        (
+            AbiType::FixedArray(inner, len),
+            AbiValue::FixedArray(values) | AbiValue::Array(values),
+        ) => {
+            if values.len() != *len {
+                return Err(Error::basic_parse(format!(
+                    "expected {len} elements for `{ty}`, found {}",
+                    values.len()
+                )));
+            }
+
+            encode_sequence(values.iter().map(|it| (&**inner, it)))
+        }
+
+        (AbiType::Tuple(types), AbiValue::Tuple(values)) => {
+            if values.len() != types.len() {
+                return Err(Error::basic_parse(format!(
+                    "expected {} fields for `{ty}`, found {}",
+                    types.len(),
+                    values.len()
+                )));
+            }
+
+            encode_sequence(types.iter().zip(values))
+        }
+
+        _ => Err(Error::basic_parse(format!(
+            "expected a value of type `{ty}`, found {}",
+            describe(value)
+        ))),
+    }
+}
+
+/// Decodes a tuple of `types` from `data`.
+pub(crate) fn decode(types: &[AbiType], data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+    decode_sequence(types.iter(), data)
+}
+
+fn decode_sequence<'a>(
+    types: impl Iterator<Item = &'a AbiType>,
+    data: &[u8],
+) -> crate::Result<Vec<AbiValue>> {
+    let mut head_offset = 0_usize;
+
+    types
+        .map(|ty| {
+            let head = slice(data, head_offset, ty.head_size())?;
+            head_offset += head.len();
+
+            if ty.is_dynamic() {
+                let offset = word_usize(head)?;
+                let tail = data.get(offset..).ok_or_else(|| truncated(ty))?;
+
+                decode_value(ty, tail)
+            } else {
+                decode_value(ty, head)
+            }
+        })
+        .collect()
+}
+
+/// Decodes a single value, from its position in the "head" if `ty` is static, or in the "tail" if it's dynamic.
+pub(crate) fn decode_value(ty: &AbiType, data: &[u8]) -> crate::Result<AbiValue> {
+    ty.check_size()?;
+
+    let invalid = || Error::basic_parse(format!("invalid ABI encoding for `{ty}`"));
+
+    match ty {
+        AbiType::Uint(bits) => {
+            let word = slice(data, 0, WORD)?;
+            let value = BigUint::from_bytes_be(word);
+
+            if !uint_fits(&value, *bits) {
+                return Err(invalid());
+            }
+
+            Ok(AbiValue::Uint(value))
+        }
+
+        AbiType::Int(bits) => {
+            let word = slice(data, 0, WORD)?;
+            let value = BigInt::from_signed_bytes_be(word);
+
+            if !int_fits(&value, *bits) {
+                return Err(invalid());
+            }
+
+            Ok(AbiValue::Int(value))
+        }
+
+        AbiType::Address => {
+            let word = slice(data, 0, WORD)?;
+            let (padding, address) = word.split_at(WORD - 20);
+
+            if padding.iter().any(|&it| it != 0) {
+                return Err(invalid());
+            }
+
+            Ok(AbiValue::Address(EvmAddress(address.try_into().unwrap())))
+        }
+
+        AbiType::Bool => match word_usize(slice(data, 0, WORD)?) {
+            Ok(0) => Ok(AbiValue::Bool(false)),
+            Ok(1) => Ok(AbiValue::Bool(true)),
+            _ => Err(invalid()),
+        },
+
+        AbiType::FixedBytes(len) => {
+            let word = slice(data, 0, WORD)?;
+            let (value, padding) = word.split_at(*len);
+
+            if padding.iter().any(|&it| it != 0) {
+                return Err(invalid());
+            }
+
+            Ok(AbiValue::FixedBytes(value.to_vec()))
+        }
+
+        AbiType::Bytes => decode_bytes(ty, data).map(|it| AbiValue::Bytes(it.to_vec())),
+
+        AbiType::String => {
+            let bytes = decode_bytes(ty, data)?;
+
+            String::from_utf8(bytes.to_vec()).map(AbiValue::String).map_err(Error::basic_parse)
+        }
+
+        AbiType::Array(inner) => {
+            let len = word_usize(slice(data, 0, WORD)?)?;
+            let elements = &data[WORD..];
+
+            // every element takes at least one word of head, so this bounds the allocation.
+            if len > elements.len() / WORD {
+                return Err(truncated(ty));
+            }
+
+            decode_sequence(iter::repeat_n(&**inner, len), elements).map(AbiValue::Array)
+        }
+
+        AbiType::FixedArray(inner, len) => {
+            if *len > data.len() / WORD {
+                return Err(truncated(ty));
+            }
+
+            decode_sequence(iter::repeat_n(&**inner, *len), data).map(AbiValue::FixedArray)
+        }
+
+        AbiType::Tuple(types) => decode_sequence(types.iter(), data).map(AbiValue::Tuple),
+    }
+}
+
+fn decode_bytes<'a>(ty: &AbiType, data: &'a [u8]) -> crate::Result<&'a [u8]> {
+    let len = word_usize(slice(data, 0, WORD)?)?;
+
+    data.get(WORD..).and_then(|it| it.get(..len)).ok_or_else(|| truncated(ty))
+}
+
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut out = usize_word(bytes.len()).to_vec();
+    out.extend(right_pad(bytes));
+    out
+}
+
+fn describe(value: &AbiValue) -> &'static str {
+    match value {
+        AbiValue::Uint(_) => "an unsigned integer",
+        AbiValue::Int(_) => "a signed integer",
+        AbiValue::Address(_) => "an address",
+        AbiValue::Bool(_) => "a bool",
+        AbiValue::FixedBytes(_) => "fixed bytes",
+        AbiValue::Bytes(_) => "bytes",
+        AbiValue::String(_) => "a string",
+        AbiValue::Array(_) | AbiValue::FixedArray(_) => "an array",
+        AbiValue::Tuple(_) => "a tuple",
+    }
+}
+
+fn uint_fits(value: &BigUint, bits: usize) -> bool {
+    value.bits() <= bits as u64
+}
+
+fn int_fits(value: &BigInt, bits: usize) -> bool {
+    // an invalid `int0` fits nothing.
+    let Some(bits) = bits.checked_sub(1) else {
+        return false;
+    };
+
+    let limit = BigInt::from(1) << bits;
+
+    -&limit <= *value && *value < limit
+}
+
+fn slice(data: &[u8], offset: usize, len: usize) -> crate::Result<&[u8]> {
+    offset
+        .checked_add(len)
+        .and_then(|end| data.get(offset..end))
+        .ok_or_else(|| Error::basic_parse("ABI encoded data is too short"))
+}
+
+fn truncated(ty: &AbiType) -> Error {
+    Error::basic_parse(format!("ABI encoded data is too short for `{ty}`"))
+}
+
+fn word_usize(word: &[u8]) -> crate::Result<usize> {
+    let (high, low) = word.split_at(WORD - 8);
+
+    if high.iter().any(|&it| it != 0) {
+        return Err(Error::basic_parse("ABI encoded length or offset is too large"));
+    }
+
+    usize::try_from(u64::from_be_bytes(low.try_into().unwrap()))
+        .map_err(|_| Error::basic_parse("ABI encoded length or offset is too large"))
+}
+
+fn usize_word(value: usize) -> [u8; WORD] {
+    left_pad(&(value as u64).to_be_bytes(), 0)
+}
+
+fn left_pad(bytes: &[u8], fill: u8) -> [u8; WORD] {
+    let mut word = [fill; WORD];
+    word[WORD - bytes.len()..].copy_from_slice(bytes);
+    word
+}
+
+fn right_pad(bytes: &[u8]) -> Vec<u8> {
+    let mut out = bytes.to_vec();
+    out.resize(bytes.len().div_ceil(WORD) * WORD, 0);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::{
+        decode,
+        encode,
+        type_match,
+        TypeMatch,
+    };
+    use crate::{
+        AbiType,
+        AbiValue,
+        EvmAddress,
+    };
+
+    fn types(names: &[&str]) -> Vec<AbiType> {
+        names.iter().map(|it| it.parse().unwrap()).collect()
+    }
+
+    // https://docs.soliditylang.org/en/latest/abi-spec.html#examples
+    #[test]
+    fn spec_example_dynamic() {
+        let types = types(&["uint256", "uint32[]", "bytes10", "bytes"]);
+        let values = [
+            AbiValue::from(0x123_u32),
+            AbiValue::Array(vec![0x456_u32.into(), 0x789_u32.into()]),
+            AbiValue::FixedBytes(b"1234567890".to_vec()),
+            AbiValue::Bytes(b"Hello, world!".to_vec()),
+        ];
+
+        let encoded = encode(&types, &values).unwrap();
+
+        assert_eq!(
+            hex::encode(&encoded),
+            concat!(
+                "0000000000000000000000000000000000000000000000000000000000000123",
+                "0000000000000000000000000000000000000000000000000000000000000080",
+                "3132333435363738393000000000000000000000000000000000000000000000",
+                "00000000000000000000000000000000000000000000000000000000000000e0",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "0000000000000000000000000000000000000000000000000000000000000456",
+                "0000000000000000000000000000000000000000000000000000000000000789",
+                "000000000000000000000000000000000000000000000000000000000000000d",
+                "48656c6c6f2c20776f726c642100000000000000000000000000000000000000",
+            )
+        );
+
+        assert_eq!(decode(&types, &encoded).unwrap(), values);
+    }
+
+    // https://docs.soliditylang.org/en/latest/abi-spec.html#use-of-dynamic-types
+    #[test]
+    fn spec_example_nested_dynamic() {
+        let types = types(&["uint256[][]", "string[]"]);
+        let values = [
+            AbiValue::Array(vec![
+                AbiValue::Array(vec![1_u8.into(), 2_u8.into()]),
+                AbiValue::Array(vec![3_u8.into()]),
+            ]),
+            AbiValue::Array(vec!["one".into(), "two".into(), "three".into()]),
+        ];
+
+        let encoded = encode(&types, &values).unwrap();
+
+        assert_eq!(
+            hex::encode(&encoded),
+            concat!(
+                "0000000000000000000000000000000000000000000000000000000000000040",
+                "0000000000000000000000000000000000000000000000000000000000000140",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "0000000000000000000000000000000000000000000000000000000000000040",
+                "00000000000000000000000000000000000000000000000000000000000000a0",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "0000000000000000000000000000000000000000000000000000000000000060",
+                "00000000000000000000000000000000000000000000000000000000000000a0",
+                "00000000000000000000000000000000000000000000000000000000000000e0",
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "6f6e650000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "74776f0000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000005",
+                "7468726565000000000000000000000000000000000000000000000000000000",
+            )
+        );
+
+        assert_eq!(decode(&types, &encoded).unwrap(), values);
+    }
+
+    #[test]
+    fn static_tuple_and_fixed_array() {
+        let types = types(&["(uint8,bool)", "bytes3[2]", "string"]);
+        let values = [
+            AbiValue::Tuple(vec![2_u8.into(), true.into()]),
+            AbiValue::FixedArray(vec![
+                AbiValue::FixedBytes(b"abc".to_vec()),
+                AbiValue::FixedBytes(b"def".to_vec()),
+            ]),
+            AbiValue::from("a"),
+        ];
+
+        let encoded = encode(&types, &values).unwrap();
+
+        // static tuples and fixed size arrays are encoded in place, so the string's offset is past 4 words.
+        assert_eq!(
+            hex::encode(&encoded),
+            concat!(
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "6162630000000000000000000000000000000000000000000000000000000000",
+                "6465660000000000000000000000000000000000000000000000000000000000",
+                "00000000000000000000000000000000000000000000000000000000000000a0",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "6100000000000000000000000000000000000000000000000000000000000000",
+            )
+        );
+
+        assert_eq!(decode(&types, &encoded).unwrap(), values);
+    }
+
+    #[test]
+    fn dynamic_tuple_round_trip() {
+        let types = types(&["(string,uint256[2])[]", "(bytes,(bool,address))"]);
+        let values = [
+            AbiValue::Array(vec![
+                AbiValue::Tuple(vec![
+                    "x".into(),
+                    AbiValue::FixedArray(vec![1_u8.into(), 2_u8.into()]),
+                ]),
+                AbiValue::Tuple(vec![
+                    "".into(),
+                    AbiValue::FixedArray(vec![3_u8.into(), 4_u8.into()]),
+                ]),
+            ]),
+            AbiValue::Tuple(vec![
+                vec![1_u8, 2, 3].into(),
+                AbiValue::Tuple(vec![false.into(), EvmAddress([0x11; 20]).into()]),
+            ]),
+        ];
+
+        let encoded = encode(&types, &values).unwrap();
+
+        assert_eq!(decode(&types, &encoded).unwrap(), values);
+    }
+
+    #[test]
+    fn wrong_lengths() {
+        assert!(encode(&types(&["uint8[2]"]), &[AbiValue::FixedArray(vec![1_u8.into()])]).is_err());
+        assert!(encode(&types(&["(uint8,bool)"]), &[AbiValue::Tuple(vec![1_u8.into()])]).is_err());
+    }
+
+    #[test]
+    fn round_trip_signed_and_address() {
+        let types = types(&["int8", "int256", "address", "bool", "string", "string[]"]);
+        let values = [
+            AbiValue::from(-128_i8),
+            AbiValue::from(-1_i64),
+            AbiValue::Address(EvmAddress(hex!("000000000000000000000000000000000000041a"))),
+            AbiValue::Bool(true),
+            AbiValue::from("hello"),
+            AbiValue::Array(vec!["a".into(), "".into()]),
+        ];
+
+        let encoded = encode(&types, &values).unwrap();
+
+        assert_eq!(
+            encoded[..32],
+            hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff80")
+        );
+        assert_eq!(decode(&types, &encoded).unwrap(), values);
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(encode(&types(&["uint8"]), &[256_u32.into()]).is_err());
+        assert!(encode(&types(&["int8"]), &[128_u32.into()]).is_err());
+        assert!(encode(&types(&["uint256"]), &[(-1_i32).into()]).is_err());
+        assert!(encode(&types(&["bytes4"]), &[AbiValue::FixedBytes(vec![0; 3])]).is_err());
+        assert!(encode(&types(&["bool"]), &[1_u8.into()]).is_err());
+    }
+
+    #[test]
+    fn decode_rejects_bad_data() {
+        // too short.
+        assert!(decode(&types(&["uint256"]), &[0; 31]).is_err());
+        // dirty high bits.
+        assert!(decode(
+            &types(&["uint8"]),
+            &hex!("0000000000000000000000000000000000000000000000000000000000000100")
+        )
+        .is_err());
+        // bool that isn't 0 or 1.
+        assert!(decode(
+            &types(&["bool"]),
+            &hex!("0000000000000000000000000000000000000000000000000000000000000002")
+        )
+        .is_err());
+        // array length far past the end of the data.
+        assert!(decode(
+            &types(&["uint256[]"]),
+            &hex!(
+                "0000000000000000000000000000000000000000000000000000000000000020"
+                "00000000000000000000000000000000000000000000000000000000ffffffff"
+            )
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn invalid_types() {
+        let word = [0xff; 64];
+
+        for ty in [AbiType::Uint(0), AbiType::Int(0), AbiType::Uint(512), AbiType::FixedBytes(40)] {
+            let value = match ty {
+                AbiType::FixedBytes(_) => AbiValue::FixedBytes(vec![0; 40]),
+                _ => 0_u8.into(),
+            };
+
+            assert!(encode(&[ty.clone()], &[value.clone()]).is_err(), "{ty}");
+            assert!(decode(&[ty.clone()], &word).is_err(), "{ty}");
+            assert!(encode(
+                &[AbiType::Array(Box::new(ty.clone()))],
+                &[AbiValue::Array(vec![value])]
+            )
+            .is_err());
+            assert_eq!(type_match(&ty, &(-1_i8).into()), TypeMatch::None);
+        }
+    }
+
+    #[test]
+    fn type_matches() {
+        let uint8 = AbiType::Uint(8);
+
+        assert_eq!(type_match(&uint8, &5_u8.into()), TypeMatch::Exact);
+        assert_eq!(type_match(&uint8, &5_i8.into()), TypeMatch::Loose);
+        assert_eq!(type_match(&uint8, &(-5_i8).into()), TypeMatch::None);
+        assert_eq!(type_match(&uint8, &300_u16.into()), TypeMatch::None);
+        assert_eq!(type_match(&AbiType::FixedBytes(2), &vec![1_u8, 2].into()), TypeMatch::Loose);
+        assert_eq!(type_match(&AbiType::String, &vec![1_u8].into()), TypeMatch::None);
+
+        let fixed = "uint8[2]".parse().unwrap();
+        let pair = vec![AbiValue::from(1_u8), 2_u8.into()];
+
+        assert_eq!(type_match(&fixed, &AbiValue::FixedArray(pair.clone())), TypeMatch::Exact);
+        assert_eq!(type_match(&fixed, &AbiValue::Array(pair.clone())), TypeMatch::Loose);
+        assert_eq!(type_match(&fixed, &AbiValue::Array(pair[..1].to_vec())), TypeMatch::None);
+        assert_eq!(type_match(&fixed, &AbiValue::Tuple(pair)), TypeMatch::None);
+    }
+}
@@ -0,0 +1,924 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Write as _;
+
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+use super::abi_type::split_types;
+use super::codec::{
+    self,
+    TypeMatch,
+};
+use super::{
+    AbiError,
+    AbiEvent,
+    AbiType,
+    AbiValue,
+    ContractRevert,
+    DecodedEvent,
+};
+use crate::{
+    ContractLogInfo,
+    Error,
+};
+
+/// A contract interface, loaded from the standard Solidity ABI JSON.
+///
+/// Encodes calls by function name from dynamically typed [`AbiValue`]s, picking the right
+/// overload based on the arguments, and decodes return values into named outputs.
+///
+/// # Examples
+/// ```
+/// # fn main() -> hiero_sdk::Result<()> {
+/// use hiero_sdk::{AbiValue, ContractAbi, ContractExecuteTransaction};
+///
+/// let abi = ContractAbi::from_json(r#"[{
+///     "type": "function",
+///     "name": "transfer",
+///     "stateMutability": "nonpayable",
+///     "inputs": [{ "name": "to", "type": "address" }, { "name": "amount", "type": "uint256" }],
+///     "outputs": [{ "name": "", "type": "bool" }]
+/// }]"#)?;
+///
+/// let to = "0x000000000000000000000000000000000000041a".parse()?;
+///
+/// let mut tx = ContractExecuteTransaction::new();
+/// tx.function_parameters(abi.encode_call("transfer", &[AbiValue::Address(to), 100_u64.into()])?);
+///
+/// assert_eq!(tx.get_function_parameters()[..4], [0xa9, 0x05, 0x9c, 0xbb]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContractAbi {
+    functions: Vec<AbiFunction>,
+    events: Vec<AbiEvent>,
+    errors: Vec<AbiError>,
+    constructor_inputs: Vec<AbiParam>,
+}
+
+impl ContractAbi {
+    /// Creates a new, empty, `ContractAbi`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a `ContractAbi` from solc ABI JSON (a list of ABI entries),
+    /// or from a Hardhat/Truffle artifact (an object with an `abi` field).
+    ///
+    /// Entries other than functions, events, errors, and the constructor are ignored.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `json` isn't valid ABI JSON or uses an unsupported type.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        json::parse_abi(json)
+    }
+
+    /// Adds a function to the interface.
+    pub fn add_function(&mut self, function: AbiFunction) -> &mut Self {
+        self.functions.push(function);
+        self
+    }
+
+    /// Adds an event to the interface.
+    pub fn add_event(&mut self, event: AbiEvent) -> &mut Self {
+        self.events.push(event);
+        self
+    }
+
+    /// Adds a custom error to the interface.
+    pub fn add_error(&mut self, error: AbiError) -> &mut Self {
+        self.errors.push(error);
+        self
+    }
+
+    /// Sets the constructor's parameters.
+    pub fn constructor(&mut self, inputs: Vec<AbiParam>) -> &mut Self {
+        self.constructor_inputs = inputs;
+        self
+    }
+
+    /// Returns every function in the interface.
+    #[must_use]
+    pub fn functions(&self) -> &[AbiFunction] {
+        &self.functions
+    }
+
+    /// Returns every event in the interface.
+    #[must_use]
+    pub fn events(&self) -> &[AbiEvent] {
+        &self.events
+    }
+
+    /// Returns the event called `name`.
+    ///
+    /// If there's more than one event called `name`, the first one is returned.
+    #[must_use]
+    pub fn event(&self, name: &str) -> Option<&AbiEvent> {
+        self.events.iter().find(|it| it.name == name)
+    }
+
+    /// Returns every custom error in the interface.
+    #[must_use]
+    pub fn errors(&self) -> &[AbiError] {
+        &self.errors
+    }
+
+    /// Returns the custom error called `name`.
+    #[must_use]
+    pub fn error(&self, name: &str) -> Option<&AbiError> {
+        self.errors.iter().find(|it| it.name == name)
+    }
+
+    /// Returns the constructor's parameters.
+    #[must_use]
+    pub fn constructor_inputs(&self) -> &[AbiParam] {
+        &self.constructor_inputs
+    }
+
+    /// Returns the function called `name`, or with the signature `name` (for example `transfer(address,uint256)`).
+    ///
+    /// Returns `None` if there's no such function, or if `name` is overloaded.
+    #[must_use]
+    pub fn function(&self, name: &str) -> Option<&AbiFunction> {
+        self.lookup(name).ok()
+    }
+
+    /// Encodes a call to the function `name` with `args`, including the function selector.
+    ///
+    /// `name` may be a bare function name, in which case the overload is picked from `args`
+    /// (preferring one where every argument is exactly the right kind of value),
+    /// or a full signature like `transfer(address,uint256)`.
+    ///
+    /// The result is meant for [`ContractExecuteTransaction::function_parameters`](crate::ContractExecuteTransaction::function_parameters)
+    /// or [`ContractCallQuery::function_parameters`](crate::ContractCallQuery::function_parameters).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if there's no matching function, the call is ambiguous,
+    ///   or an argument can't be encoded as its parameter type.
+    pub fn encode_call(&self, name: &str, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        self.resolve(name, args)?.encode_call(args)
+    }
+
+    /// Decodes the return value of the function `name` from `data`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if there's no such function, `name` is overloaded
+    ///   (use the full signature instead), or `data` isn't a valid encoding of its outputs.
+    pub fn decode_output(&self, name: &str, data: &[u8]) -> crate::Result<DecodedOutputs> {
+        self.lookup(name)?.decode_output(data)
+    }
+
+    /// Decodes `log` as whichever event in the interface emitted it.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `log` doesn't match any of the interface's events.
+    pub fn decode_log(&self, log: &ContractLogInfo) -> crate::Result<DecodedEvent> {
+        self.events
+            .iter()
+            .filter(|it| it.matches(log))
+            .find_map(|it| it.decode_log(log).ok())
+            .ok_or_else(|| Error::basic_parse("log doesn't match any event in ABI"))
+    }
+
+    /// Decodes revert `data` as a built-in `Error(string)` or `Panic(uint256)`, or one of the interface's custom errors.
+    ///
+    /// Revert data comes from [`ContractFunctionResult::revert_data`](crate::ContractFunctionResult::revert_data),
+    /// or [`ContractRevert::Unknown`] in an [`Error`].
+    #[must_use]
+    pub fn decode_revert(&self, data: &[u8]) -> ContractRevert {
+        if let Some(revert) = ContractRevert::decode_builtin(data) {
+            return revert;
+        }
+
+        self.errors
+            .iter()
+            .filter(|it| data.starts_with(&it.selector()))
+            .find_map(|it| {
+                let params = it.decode(data).ok()?;
+                Some(ContractRevert::Custom { name: it.name.clone(), params })
+            })
+            .unwrap_or_else(|| ContractRevert::Unknown(data.to_vec()))
+    }
+
+    /// Encodes `args` as the constructor's parameters.
+    ///
+    /// The result is meant for [`ContractCreateFlow::constructor_parameters`](crate::ContractCreateFlow::constructor_parameters).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an argument can't be encoded as its parameter type.
+    pub fn encode_constructor(&self, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        codec::encode(&param_types(&self.constructor_inputs), args)
+    }
+
+    fn lookup(&self, name: &str) -> crate::Result<&AbiFunction> {
+        if name.contains('(') {
+            let signature = AbiFunction::from_signature(name)?.signature();
+
+            return self
+                .functions
+                .iter()
+                .find(|it| it.signature() == signature)
+                .ok_or_else(|| Error::basic_parse(format!("no function `{signature}` in ABI")));
+        }
+
+        let mut candidates = self.functions.iter().filter(|it| it.name == name);
+
+        match (candidates.next(), candidates.next()) {
+            (Some(function), None) => Ok(function),
+            (None, _) => Err(Error::basic_parse(format!("no function named `{name}` in ABI"))),
+            (Some(_), Some(_)) => Err(Error::basic_parse(format!(
+                "function `{name}` is overloaded, use its full signature instead"
+            ))),
+        }
+    }
+
+    fn resolve(&self, name: &str, args: &[AbiValue]) -> crate::Result<&AbiFunction> {
+        if name.contains('(') {
+            return self.lookup(name);
+        }
+
+        let candidates: Vec<_> = self
+            .functions
+            .iter()
+            .filter(|it| it.name == name)
+            .filter(|it| it.inputs.len() == args.len())
+            .map(|it| (it.match_args(args), it))
+            .filter(|(score, _)| *score != TypeMatch::None)
+            .collect();
+
+        let Some(best) = candidates.iter().map(|(score, _)| *score).max() else {
+            return Err(if self.functions.iter().any(|it| it.name == name) {
+                Error::basic_parse(format!("no overload of `{name}` accepts the given arguments"))
+            } else {
+                Error::basic_parse(format!("no function named `{name}` in ABI"))
+            });
+        };
+
+        let mut best = candidates.into_iter().filter(|(score, _)| *score == best).map(|it| it.1);
+
+        match (best.next(), best.next()) {
+            (Some(function), None) => Ok(function),
+            (Some(first), Some(second)) => {
+                let mut signatures = format!("`{}`, `{}`", first.signature(), second.signature());
+                for it in best {
+                    let _ = write!(signatures, ", `{}`", it.signature());
+                }
+
+                Err(Error::basic_parse(format!(
+                    "call to `{name}` is ambiguous between {signatures}, use the full signature instead"
+                )))
+            }
+            (None, _) => unreachable!(),
+        }
+    }
+}
+
+/// A function in a [`ContractAbi`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiFunction {
+    /// The name of the function.
+    pub name: String,
+
+    /// The function's parameters.
+    pub inputs: Vec<AbiParam>,
+
+    /// The function's return values.
+    pub outputs: Vec<AbiParam>,
+
+    /// Whether the function reads or writes state, and whether it accepts hbar.
+    pub state_mutability: AbiStateMutability,
+}
+
+impl AbiFunction {
+    /// Parses a function from its signature, for example `transfer(address,uint256)`.
+    ///
+    /// The function has no outputs, and is assumed to be [`AbiStateMutability::NonPayable`].
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `signature` isn't a valid function signature.
+    pub fn from_signature(signature: &str) -> crate::Result<Self> {
+        let invalid = || Error::basic_parse(format!("invalid function signature `{signature}`"));
+
+        let (name, params) = signature.trim().split_once('(').ok_or_else(invalid)?;
+        let params = params.strip_suffix(')').ok_or_else(invalid)?;
+
+        if name.is_empty() {
+            return Err(invalid());
+        }
+
+        let inputs = split_types(params)
+            .into_iter()
+            .map(|it| Ok(AbiParam::new("", it.parse()?)))
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self {
+            name: name.to_owned(),
+            inputs,
+            outputs: Vec::new(),
+            state_mutability: AbiStateMutability::NonPayable,
+        })
+    }
+
+    /// Returns the canonical signature of this function, for example `transfer(address,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        let params: Vec<_> = self.inputs.iter().map(|it| it.param_type.to_string()).collect();
+
+        format!("{}({})", self.name, params.join(","))
+    }
+
+    /// Returns the 4 byte selector of this function.
+    #[must_use]
+    pub fn selector(&self) -> [u8; 4] {
+        let hash = Keccak256::digest(self.signature().as_bytes());
+
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
+
+    /// Returns `true` if calling this function can't change state (it's `view` or `pure`),
+    /// meaning it can be called with a [`ContractCallQuery`](crate::ContractCallQuery).
+    #[must_use]
+    pub fn is_read_only(&self) -> bool {
+        matches!(self.state_mutability, AbiStateMutability::Pure | AbiStateMutability::View)
+    }
+
+    /// Encodes a call to this function with `args`, including the function selector.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if there's the wrong number of `args`, or an argument can't be encoded as its parameter type.
+    pub fn encode_call(&self, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        let mut out = self.selector().to_vec();
+        out.extend(codec::encode(&param_types(&self.inputs), args)?);
+
+        Ok(out)
+    }
+
+    /// Decodes this function's return value from `data`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `data` isn't a valid encoding of this function's outputs.
+    pub fn decode_output(&self, data: &[u8]) -> crate::Result<DecodedOutputs> {
+        let values = codec::decode(&param_types(&self.outputs), data)?;
+
+        Ok(DecodedOutputs::new(self.outputs.iter().map(|it| it.name.clone()).collect(), values))
+    }
+
+    fn match_args(&self, args: &[AbiValue]) -> TypeMatch {
+        self.inputs
+            .iter()
+            .zip(args)
+            .map(|(param, arg)| codec::type_match(&param.param_type, arg))
+            .min()
+            .unwrap_or(TypeMatch::Exact)
+    }
+}
+
+/// A named parameter (or return value) of an [`AbiFunction`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiParam {
+    /// The name of the parameter, empty if it's unnamed.
+    pub name: String,
+
+    /// The type of the parameter.
+    pub param_type: AbiType,
+}
+
+impl AbiParam {
+    /// Creates a new `AbiParam`.
+    #[must_use]
+    pub fn new(name: impl Into<String>, param_type: AbiType) -> Self {
+        Self { name: name.into(), param_type }
+    }
+}
+
+/// Whether an [`AbiFunction`] reads or writes state, and whether it accepts hbar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AbiStateMutability {
+    /// Doesn't read or write state.
+    Pure,
+
+    /// Reads, but doesn't write, state.
+    View,
+
+    /// May write state, but doesn't accept hbar.
+    #[default]
+    NonPayable,
+
+    /// May write state, and accepts hbar.
+    Payable,
+}
+
+/// The decoded return values of an [`AbiFunction`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedOutputs {
+    names: Vec<String>,
+    values: Vec<AbiValue>,
+}
+
+impl DecodedOutputs {
+    pub(super) fn new(names: Vec<String>, values: Vec<AbiValue>) -> Self {
+        Self { names, values }
+    }
+
+    /// Returns the output called `name`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&AbiValue> {
+        self.names.iter().position(|it| !it.is_empty() && it == name).map(|it| &self.values[it])
+    }
+
+    /// Returns the output at `index`.
+    #[must_use]
+    pub fn get_index(&self, index: usize) -> Option<&AbiValue> {
+        self.values.get(index)
+    }
+
+    /// Returns the number of outputs.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the function has no outputs.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns an iterator over each output's name (empty if it's unnamed) and value.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &AbiValue)> {
+        self.names.iter().map(String::as_str).zip(&self.values)
+    }
+
+    /// Returns the output values, in order.
+    #[must_use]
+    pub fn into_values(self) -> Vec<AbiValue> {
+        self.values
+    }
+}
+
+fn param_types(params: &[AbiParam]) -> Vec<AbiType> {
+    params.iter().map(|it| it.param_type.clone()).collect()
+}
+
+#[cfg(feature = "serde")]
+mod json {
+    use serde_json::Value;
+
+    use super::{
+        AbiFunction,
+        AbiParam,
+        AbiStateMutability,
+        ContractAbi,
+    };
+    use crate::{
+        AbiError,
+        AbiEvent,
+        AbiEventParam,
+        AbiType,
+        Error,
+    };
+
+    pub(super) fn parse_abi(json: &str) -> crate::Result<ContractAbi> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|e| Error::basic_parse(format!("failed to parse ABI JSON: {e}")))?;
+
+        // Hardhat and Truffle artifacts wrap the ABI in an object.
+        let entries = value
+            .get("abi")
+            .unwrap_or(&value)
+            .as_array()
+            .ok_or_else(|| Error::basic_parse("ABI JSON is not a list"))?;
+
+        let mut abi = ContractAbi::new();
+
+        for entry in entries {
+            // `type` can be omitted, and defaults to `function`.
+            match entry.get("type").and_then(Value::as_str).unwrap_or("function") {
+                "function" => {
+                    abi.add_function(parse_function(entry)?);
+                }
+                "event" => {
+                    abi.add_event(parse_event(entry)?);
+                }
+                "error" => {
+                    abi.add_error(parse_error(entry)?);
+                }
+                "constructor" => {
+                    abi.constructor(parse_params(entry, "inputs")?);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(abi)
+    }
+
+    fn parse_function(entry: &Value) -> crate::Result<AbiFunction> {
+        let name = entry
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::basic_parse("ABI function is missing `name`"))?;
+
+        let state_mutability = match entry.get("stateMutability").and_then(Value::as_str) {
+            Some("pure") => AbiStateMutability::Pure,
+            Some("view") => AbiStateMutability::View,
+            Some("nonpayable") => AbiStateMutability::NonPayable,
+            Some("payable") => AbiStateMutability::Payable,
+            Some(other) => {
+                return Err(Error::basic_parse(format!(
+                    "invalid state mutability `{other}` for ABI function `{name}`"
+                )))
+            }
+            // older compilers use `constant` and `payable` instead.
+            None if entry.get("constant").and_then(Value::as_bool) == Some(true) => {
+                AbiStateMutability::View
+            }
+            None if entry.get("payable").and_then(Value::as_bool) == Some(true) => {
+                AbiStateMutability::Payable
+            }
+            None => AbiStateMutability::NonPayable,
+        };
+
+        Ok(AbiFunction {
+            name: name.to_owned(),
+            inputs: parse_params(entry, "inputs")?,
+            outputs: parse_params(entry, "outputs")?,
+            state_mutability,
+        })
+    }
+
+    fn parse_event(entry: &Value) -> crate::Result<AbiEvent> {
+        let name = entry
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::basic_parse("ABI event is missing `name`"))?;
+
+        let inputs = entry
+            .get("inputs")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .map(|param| {
+                let name = param.get("name").and_then(Value::as_str).unwrap_or_default();
+                let indexed = param.get("indexed").and_then(Value::as_bool).unwrap_or_default();
+
+                Ok(AbiEventParam::new(name, parse_type(param)?, indexed))
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(AbiEvent {
+            name: name.to_owned(),
+            inputs,
+            anonymous: entry.get("anonymous").and_then(Value::as_bool).unwrap_or_default(),
+        })
+    }
+
+    fn parse_error(entry: &Value) -> crate::Result<AbiError> {
+        let name = entry
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::basic_parse("ABI error is missing `name`"))?;
+
+        Ok(AbiError { name: name.to_owned(), inputs: parse_params(entry, "inputs")? })
+    }
+
+    fn parse_params(entry: &Value, key: &str) -> crate::Result<Vec<AbiParam>> {
+        let Some(params) = entry.get(key) else {
+            return Ok(Vec::new());
+        };
+
+        params
+            .as_array()
+            .ok_or_else(|| Error::basic_parse(format!("ABI `{key}` is not a list")))?
+            .iter()
+            .map(|param| {
+                let name = param.get("name").and_then(Value::as_str).unwrap_or_default();
+
+                Ok(AbiParam::new(name, parse_type(param)?))
+            })
+            .collect()
+    }
+
+    fn parse_type(param: &Value) -> crate::Result<AbiType> {
+        let param_type = param
+            .get("type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::basic_parse("ABI parameter is missing `type`"))?;
+
+        // structs are `tuple`, `tuple[]`, `tuple[2]`, ..., with their fields in `components`.
+        let Some(array_suffix) = param_type.strip_prefix("tuple") else {
+            return param_type.parse();
+        };
+
+        let components = param
+            .get("components")
+            .and_then(Value::as_array)
+            .ok_or_else(|| Error::basic_parse("ABI tuple parameter is missing `components`"))?
+            .iter()
+            .map(parse_type)
+            .collect::<crate::Result<_>>()?;
+
+        format!("{}{array_suffix}", AbiType::Tuple(components)).parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hex_literal::hex;
+
+    use super::{
+        AbiFunction,
+        AbiStateMutability,
+        ContractAbi,
+    };
+    use crate::{
+        AbiError,
+        AbiValue,
+        ContractId,
+        ContractLogInfo,
+        ContractRevert,
+        Error,
+        EvmAddress,
+    };
+
+    const ABI: &str = r#"{
+        "contractName": "Token",
+        "abi": [
+            {
+                "type": "constructor",
+                "inputs": [{ "name": "supply", "type": "uint256" }]
+            },
+            {
+                "type": "function",
+                "name": "transfer",
+                "stateMutability": "nonpayable",
+                "inputs": [{ "name": "to", "type": "address" }, { "name": "amount", "type": "uint256" }],
+                "outputs": [{ "name": "", "type": "bool" }]
+            },
+            {
+                "type": "function",
+                "name": "set",
+                "inputs": [{ "name": "value", "type": "uint256" }],
+                "outputs": []
+            },
+            {
+                "type": "function",
+                "name": "set",
+                "inputs": [{ "name": "value", "type": "string" }],
+                "outputs": []
+            },
+            {
+                "type": "function",
+                "name": "set",
+                "inputs": [{ "name": "value", "type": "int64" }],
+                "outputs": []
+            },
+            {
+                "type": "function",
+                "name": "info",
+                "stateMutability": "view",
+                "inputs": [],
+                "outputs": [{ "name": "owner", "type": "address" }, { "name": "name", "type": "string" }]
+            },
+            {
+                "type": "event",
+                "name": "Transfer",
+                "anonymous": false,
+                "inputs": [
+                    { "name": "from", "type": "address", "indexed": true },
+                    { "name": "to", "type": "address", "indexed": true },
+                    { "name": "value", "type": "uint256", "indexed": false }
+                ]
+            },
+            {
+                "type": "error",
+                "name": "InsufficientBalance",
+                "inputs": [{ "name": "available", "type": "uint256" }, { "name": "required", "type": "uint256" }]
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn from_json() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        assert_eq!(abi.functions().len(), 5);
+        assert_eq!(abi.constructor_inputs().len(), 1);
+        assert_eq!(abi.function("info").unwrap().state_mutability, AbiStateMutability::View);
+        assert!(abi.function("info").unwrap().is_read_only());
+        assert!(abi.function("set").is_none());
+        assert!(abi.function("set(string)").is_some());
+    }
+
+    #[test]
+    fn encode_transfer() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        let to = EvmAddress(hex!("000000000000000000000000000000000000041a"));
+
+        let encoded = abi.encode_call("transfer", &[to.into(), 100_u64.into()]).unwrap();
+
+        assert_eq!(
+            hex::encode(encoded),
+            concat!(
+                "a9059cbb",
+                "000000000000000000000000000000000000000000000000000000000000041a",
+                "0000000000000000000000000000000000000000000000000000000000000064",
+            )
+        );
+    }
+
+    #[test]
+    fn overloads() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        let selector = |sig: &str| AbiFunction::from_signature(sig).unwrap().selector();
+
+        assert_eq!(abi.encode_call("set", &["hi".into()]).unwrap()[..4], selector("set(string)"));
+        assert_eq!(abi.encode_call("set", &[1_u8.into()]).unwrap()[..4], selector("set(uint256)"));
+        assert_eq!(abi.encode_call("set", &[(-1_i8).into()]).unwrap()[..4], selector("set(int64)"));
+
+        // a uint fits both `uint256` (exactly) and `int64` (loosely), but a huge one only fits `uint256`
+        assert_eq!(
+            abi.encode_call("set", &[u128::MAX.into()]).unwrap()[..4],
+            selector("set(uint256)")
+        );
+
+        assert_eq!(
+            abi.encode_call("set(int64)", &[1_u8.into()]).unwrap()[..4],
+            selector("set(int64)")
+        );
+
+        assert_matches!(abi.encode_call("set", &[true.into()]), Err(Error::BasicParse(_)));
+        assert_matches!(abi.encode_call("nope", &[]), Err(Error::BasicParse(_)));
+    }
+
+    #[test]
+    fn ambiguous() {
+        let mut abi = ContractAbi::new();
+        abi.add_function(AbiFunction::from_signature("f(uint8)").unwrap())
+            .add_function(AbiFunction::from_signature("f(uint256)").unwrap());
+
+        let err = abi.encode_call("f", &[1_u8.into()]).unwrap_err().to_string();
+        assert!(err.contains("ambiguous"), "{err}");
+
+        // only `uint256` fits.
+        assert!(abi.encode_call("f", &[256_u16.into()]).is_ok());
+    }
+
+    #[test]
+    fn decode_named_outputs() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        let data = hex!(
+            "000000000000000000000000000000000000000000000000000000000000041a"
+            "0000000000000000000000000000000000000000000000000000000000000040"
+            "0000000000000000000000000000000000000000000000000000000000000005"
+            "746f6b656e000000000000000000000000000000000000000000000000000000"
+        );
+
+        let outputs = abi.decode_output("info", &data).unwrap();
+
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(
+            outputs.get("owner").and_then(AbiValue::as_address),
+            Some(EvmAddress(hex!("000000000000000000000000000000000000041a")))
+        );
+        assert_eq!(outputs.get("name").and_then(AbiValue::as_str), Some("token"));
+        assert_eq!(outputs.get_index(1), outputs.get("name"));
+        assert_eq!(outputs.get("missing"), None);
+    }
+
+    #[test]
+    fn struct_params() {
+        let abi = ContractAbi::from_json(
+            r#"[{
+                "type": "function",
+                "name": "submit",
+                "inputs": [{
+                    "name": "orders",
+                    "type": "tuple[]",
+                    "components": [
+                        { "name": "amount", "type": "uint256" },
+                        { "name": "to", "type": "address" }
+                    ]
+                }],
+                "outputs": []
+            }]"#,
+        )
+        .unwrap();
+
+        let submit = abi.function("submit").unwrap();
+
+        assert_eq!(submit.signature(), "submit((uint256,address)[])");
+
+        let order = AbiValue::Tuple(vec![
+            5_u8.into(),
+            EvmAddress(hex!("000000000000000000000000000000000000041a")).into(),
+        ]);
+
+        let encoded = abi.encode_call("submit", &[AbiValue::Array(vec![order])]).unwrap();
+
+        assert_eq!(encoded[..4], submit.selector());
+        assert_eq!(
+            hex::encode(&encoded[4..]),
+            concat!(
+                "0000000000000000000000000000000000000000000000000000000000000020",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000005",
+                "000000000000000000000000000000000000000000000000000000000000041a",
+            )
+        );
+    }
+
+    #[test]
+    fn decode_log() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        let transfer = abi.event("Transfer").unwrap();
+        assert_eq!(transfer.signature(), "Transfer(address,address,uint256)");
+
+        let log = ContractLogInfo {
+            contract_id: ContractId::new(0, 0, 1001),
+            bloom: Vec::new(),
+            topics: vec![
+                transfer.topic().to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000401").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000402").to_vec(),
+            ],
+            data: hex!("0000000000000000000000000000000000000000000000000000000000000064").to_vec(),
+        };
+
+        let event = abi.decode_log(&log).unwrap();
+
+        assert_eq!(event.name, "Transfer");
+        assert_eq!(event.params.get("value"), Some(&AbiValue::from(100_u8)));
+
+        assert!(abi.decode_log(&ContractLogInfo { topics: Vec::new(), ..log }).is_err());
+    }
+
+    #[test]
+    fn decode_revert() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        let error = abi.error("InsufficientBalance").unwrap();
+        assert_eq!(error.signature(), "InsufficientBalance(uint256,uint256)");
+        assert_eq!(
+            error.selector(),
+            AbiError::from_signature("InsufficientBalance(uint256,uint256)").unwrap().selector()
+        );
+        assert_eq!(error.selector(), hex!("cf479181"));
+
+        let mut data = error.selector().to_vec();
+        data.extend(hex!(
+            "0000000000000000000000000000000000000000000000000000000000000005"
+            "000000000000000000000000000000000000000000000000000000000000000a"
+        ));
+
+        let revert = abi.decode_revert(&data);
+
+        assert_matches!(&revert, ContractRevert::Custom { name, params } if name == "InsufficientBalance" && params.get("required") == Some(&AbiValue::from(10_u8)));
+        assert_eq!(revert.to_string(), "reverted with custom error `InsufficientBalance`");
+
+        // built-in errors don't need to be in the ABI.
+        let data =
+            hex!("4e487b71" "0000000000000000000000000000000000000000000000000000000000000001");
+        assert_eq!(abi.decode_revert(&data), ContractRevert::Panic(1));
+
+        // a custom error that isn't.
+        assert_eq!(
+            abi.decode_revert(&hex!("deadbeef")),
+            ContractRevert::Unknown(hex!("deadbeef").to_vec())
+        );
+    }
+
+    #[test]
+    fn encode_constructor() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        assert_eq!(
+            abi.encode_constructor(&[1_u8.into()]).unwrap(),
+            hex!("0000000000000000000000000000000000000000000000000000000000000001")
+        );
+    }
+
+    #[test]
+    fn signature() {
+        let function = AbiFunction::from_signature("transfer(address, uint)").unwrap();
+
+        assert_eq!(function.signature(), "transfer(address,uint256)");
+        assert_eq!(function.selector(), hex!("a9059cbb"));
+
+        assert!(AbiFunction::from_signature("transfer").is_err());
+        assert!(AbiFunction::from_signature("(uint256)").is_err());
+        assert_eq!(AbiFunction::from_signature("f()").unwrap().inputs.len(), 0);
+    }
+}
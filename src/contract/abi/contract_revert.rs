@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use super::{
+    codec,
+    AbiType,
+    DecodedOutputs,
+};
+
+// `Error(string)`
+pub(crate) const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+// `Panic(uint256)`
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Why a contract call reverted, decoded from its revert data.
+///
+/// # Examples
+/// ```
+/// use hiero_sdk::ContractRevert;
+///
+/// let data = hex::decode(
+///     "4e487b710000000000000000000000000000000000000000000000000000000000000011",
+/// )
+/// .unwrap();
+///
+/// let revert = ContractRevert::decode(&data);
+///
+/// assert_eq!(revert, ContractRevert::Panic(0x11));
+/// assert_eq!(revert.to_string(), "panicked with code 0x11 (arithmetic overflow or underflow)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ContractRevert {
+    /// `revert("reason")` or `require(condition, "reason")`, encoded as `Error(string)`.
+    Error(String),
+
+    /// A failed `assert`, an arithmetic overflow, an out of bounds index, etc, encoded as `Panic(uint256)`.
+    ///
+    /// See [`ContractRevert::panic_reason`] for what the code means.
+    Panic(u64),
+
+    /// A custom error declared in the contract's ABI.
+    ///
+    /// Only returned by [`ContractAbi::decode_revert`](crate::ContractAbi::decode_revert).
+    Custom {
+        /// The name of the error.
+        name: String,
+
+        /// The error's parameters.
+        params: DecodedOutputs,
+    },
+
+    /// Revert data that isn't a built-in error (or a known custom error).
+    ///
+    /// Empty for `revert()` and `require(condition)` without a reason.
+    Unknown(Vec<u8>),
+}
+
+impl ContractRevert {
+    /// Decodes revert `data` as either a built-in `Error(string)` or `Panic(uint256)`.
+    ///
+    /// Custom errors decode to [`ContractRevert::Unknown`], use [`ContractAbi::decode_revert`](crate::ContractAbi::decode_revert) for those.
+    #[must_use]
+    pub fn decode(data: &[u8]) -> Self {
+        Self::decode_builtin(data).unwrap_or_else(|| Self::Unknown(data.to_vec()))
+    }
+
+    /// Returns what a Solidity panic `code` means, for example `0x11` is an arithmetic overflow or underflow.
+    #[must_use]
+    pub fn panic_reason(code: u64) -> Option<&'static str> {
+        let reason = match code {
+            0x00 => "generic compiler panic",
+            0x01 => "assertion failed",
+            0x11 => "arithmetic overflow or underflow",
+            0x12 => "division or modulo by zero",
+            0x21 => "invalid enum value",
+            0x22 => "invalid storage byte array encoding",
+            0x31 => "pop on an empty array",
+            0x32 => "array index out of bounds",
+            0x41 => "out of memory",
+            0x51 => "call to an uninitialized internal function",
+            _ => return None,
+        };
+
+        Some(reason)
+    }
+
+    pub(super) fn decode_builtin(data: &[u8]) -> Option<Self> {
+        let (selector, params) = data.split_first_chunk::<4>()?;
+
+        let ty = match *selector {
+            ERROR_SELECTOR => AbiType::String,
+            PANIC_SELECTOR => AbiType::Uint(256),
+            _ => return None,
+        };
+
+        let types = [ty];
+        let values = codec::decode(&types, params).ok()?;
+
+        // anything but the exact encoding (like trailing bytes) is some other error with the same selector.
+        if codec::encode(&types, &values).ok()? != params {
+            return None;
+        }
+
+        let value = &values[0];
+
+        match *selector {
+            ERROR_SELECTOR => value.as_str().map(|it| Self::Error(it.to_owned())),
+            _ => value.as_uint().and_then(|it| u64::try_from(it).ok()).map(Self::Panic),
+        }
+    }
+}
+
+impl fmt::Display for ContractRevert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error(reason) => write!(f, "reverted with reason {reason:?}"),
+            Self::Panic(code) => match Self::panic_reason(*code) {
+                Some(reason) => write!(f, "panicked with code {code:#04x} ({reason})"),
+                None => write!(f, "panicked with code {code:#04x}"),
+            },
+            Self::Custom { name, .. } => write!(f, "reverted with custom error `{name}`"),
+            Self::Unknown(data) if data.is_empty() => f.write_str("reverted without a reason"),
+            Self::Unknown(data) => write!(f, "reverted with data 0x{}", hex::encode(data)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::ContractRevert;
+
+    #[test]
+    fn decode_error() {
+        // `require(false, "Not enough Ether provided.")`
+        let data = hex!(
+            "08c379a0"
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "000000000000000000000000000000000000000000000000000000000000001a"
+            "4e6f7420656e6f7567682045746865722070726f76696465642e000000000000"
+        );
+
+        let revert = ContractRevert::decode(&data);
+
+        assert_eq!(revert, ContractRevert::Error("Not enough Ether provided.".to_owned()));
+        assert_eq!(revert.to_string(), r#"reverted with reason "Not enough Ether provided.""#);
+    }
+
+    #[test]
+    fn decode_panic() {
+        let data = hex!(
+            "4e487b71"
+            "0000000000000000000000000000000000000000000000000000000000000032"
+        );
+
+        let revert = ContractRevert::decode(&data);
+
+        assert_eq!(revert, ContractRevert::Panic(0x32));
+        assert_eq!(revert.to_string(), "panicked with code 0x32 (array index out of bounds)");
+
+        assert_eq!(ContractRevert::Panic(0x99).to_string(), "panicked with code 0x99");
+    }
+
+    #[test]
+    fn decode_unknown() {
+        assert_eq!(ContractRevert::decode(&[]), ContractRevert::Unknown(Vec::new()));
+        assert_eq!(ContractRevert::decode(&[]).to_string(), "reverted without a reason");
+
+        // a custom error.
+        let data = hex!("cf479181");
+        assert_eq!(ContractRevert::decode(&data), ContractRevert::Unknown(data.to_vec()));
+        assert_eq!(ContractRevert::decode(&data).to_string(), "reverted with data 0xcf479181");
+
+        // truncated `Error(string)`.
+        let data =
+            hex!("08c379a0" "0000000000000000000000000000000000000000000000000000000000000020");
+        assert_eq!(ContractRevert::decode(&data), ContractRevert::Unknown(data.to_vec()));
+
+        // `Panic(uint256)` with trailing data.
+        let data = hex!(
+            "4e487b71"
+            "0000000000000000000000000000000000000000000000000000000000000011"
+            "0000000000000000000000000000000000000000000000000000000000000001"
+        );
+        assert_eq!(ContractRevert::decode(&data), ContractRevert::Unknown(data.to_vec()));
+    }
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: Apache-2.0
+
+mod abi_error;
+mod abi_event;
+mod abi_type;
+mod abi_value;
+mod bindings;
+pub(super) mod codec;
+mod contract_abi;
+mod contract_revert;
+
+pub use abi_error::AbiError;
+pub use abi_event::{
+    AbiEvent,
+    AbiEventParam,
+    DecodedEvent,
+};
+pub use abi_type::AbiType;
+pub use abi_value::AbiValue;
+pub use bindings::ContractBindings;
+pub use contract_abi::{
+    AbiFunction,
+    AbiParam,
+    AbiStateMutability,
+    ContractAbi,
+    DecodedOutputs,
+};
+pub use contract_revert::ContractRevert;
+pub(super) use contract_revert::ERROR_SELECTOR;
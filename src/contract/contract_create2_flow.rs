@@ -0,0 +1,424 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+use crate::{
+    AbiType,
+    AccountId,
+    Client,
+    ContractExecuteTransaction,
+    ContractFunctionParameters,
+    ContractFunctionResult,
+    ContractId,
+    Error,
+    EvmAddress,
+    Hbar,
+};
+
+/// Deploy a smart contract to a deterministic address through a `CREATE2` factory contract.
+///
+/// The factory must have a `deploy(bytes32 salt, bytes initCode) returns (address)` function
+/// (or another name, see [`function`](Self::function)) which deploys `initCode` with `CREATE2` and `salt`,
+/// and returns the address of the deployed contract.
+///
+/// Because the address only depends on the factory, the salt, and the init code,
+/// it's known before the contract is deployed (see [`get_evm_address`](Self::get_evm_address)).
+///
+/// The operation of this flow is as follows:
+/// 1. Execute a [`ContractExecuteTransaction`] calling the factory's deploy function.
+/// 2. Wait for its record.
+/// 3. Check that the factory returned the expected address.
+/// 4. Return the [`ContractId`] of the deployed contract, derived from its EVM address.
+#[derive(Debug, Clone)]
+pub struct ContractCreate2Flow {
+    factory_contract_id: Option<ContractId>,
+    function: String,
+    bytecode: Vec<u8>,
+    constructor_parameters: Vec<u8>,
+    salt: [u8; 32],
+    gas: u64,
+    payable_amount: Hbar,
+    node_account_ids: Option<Vec<AccountId>>,
+}
+
+impl Default for ContractCreate2Flow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContractCreate2Flow {
+    /// Create a new `ContractCreate2Flow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            factory_contract_id: None,
+            function: "deploy".to_owned(),
+            bytecode: Vec::new(),
+            constructor_parameters: Vec::new(),
+            salt: [0; 32],
+            gas: 0,
+            payable_amount: Hbar::ZERO,
+            node_account_ids: None,
+        }
+    }
+
+    /// Returns the factory contract that deploys the contract.
+    #[must_use]
+    pub fn get_factory_contract_id(&self) -> Option<ContractId> {
+        self.factory_contract_id
+    }
+
+    /// Sets the factory contract that deploys the contract.
+    pub fn factory_contract_id(&mut self, factory_contract_id: ContractId) -> &mut Self {
+        self.factory_contract_id = Some(factory_contract_id);
+
+        self
+    }
+
+    /// Returns the name of the factory's deploy function.
+    #[must_use]
+    pub fn get_function(&self) -> &str {
+        &self.function
+    }
+
+    /// Sets the name of the factory's deploy function, which must take `(bytes32 salt, bytes initCode)`.
+    ///
+    /// The default is `deploy`.
+    pub fn function(&mut self, function: impl Into<String>) -> &mut Self {
+        self.function = function.into();
+
+        self
+    }
+
+    /// Returns the bytecode of the smart contract.
+    #[must_use]
+    pub fn get_bytecode(&self) -> &[u8] {
+        &self.bytecode
+    }
+
+    /// Sets the raw bytes of the smart contract.
+    pub fn bytecode(&mut self, bytecode: Vec<u8>) -> &mut Self {
+        self.bytecode = bytecode;
+
+        self
+    }
+
+    /// Sets the bytecode of the smart contract in hex.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](Error::BasicParse) if `bytecode` is invalid hex.
+    pub fn bytecode_hex(&mut self, bytecode: &str) -> crate::Result<&mut Self> {
+        self.bytecode = hex::decode(bytecode).map_err(Error::basic_parse)?;
+
+        Ok(self)
+    }
+
+    /// Returns the parameters to pass to the constructor.
+    #[must_use]
+    pub fn get_constructor_parameters(&self) -> &[u8] {
+        &self.constructor_parameters
+    }
+
+    /// Sets the parameters to pass to the constructor.
+    pub fn constructor_parameters(
+        &mut self,
+        constructor_parameters: impl Into<Vec<u8>>,
+    ) -> &mut Self {
+        self.constructor_parameters = constructor_parameters.into();
+
+        self
+    }
+
+    /// Returns the salt the contract is deployed with.
+    #[must_use]
+    pub fn get_salt(&self) -> [u8; 32] {
+        self.salt
+    }
+
+    /// Sets the salt the contract is deployed with.
+    ///
+    /// Deploying the same init code with the same salt twice fails, since the address is already taken.
+    pub fn salt(&mut self, salt: [u8; 32]) -> &mut Self {
+        self.salt = salt;
+
+        self
+    }
+
+    /// Returns the gas limit of the call to the factory.
+    #[must_use]
+    pub fn get_gas(&self) -> u64 {
+        self.gas
+    }
+
+    /// Sets the gas limit of the call to the factory.
+    pub fn gas(&mut self, gas: u64) -> &mut Self {
+        self.gas = gas;
+
+        self
+    }
+
+    /// Returns the number of hbars sent with the call to the factory.
+    #[must_use]
+    pub fn get_payable_amount(&self) -> Hbar {
+        self.payable_amount
+    }
+
+    /// Sets the number of hbars sent with the call to the factory.
+    pub fn payable_amount(&mut self, payable_amount: Hbar) -> &mut Self {
+        self.payable_amount = payable_amount;
+
+        self
+    }
+
+    /// Returns the account IDs of the nodes the transaction may be submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the account IDs of the nodes the transaction may be submitted to.
+    ///
+    /// Defaults to the full list of nodes configured on the client.
+    pub fn node_account_ids(
+        &mut self,
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> &mut Self {
+        self.node_account_ids = Some(node_account_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Returns the init code of the contract, its bytecode followed by the constructor parameters.
+    #[must_use]
+    pub fn get_init_code(&self) -> Vec<u8> {
+        [self.bytecode.as_slice(), &self.constructor_parameters].concat()
+    }
+
+    /// Returns the EVM address the contract will be deployed to.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the factory contract ID isn't set.
+    pub fn get_evm_address(&self) -> crate::Result<EvmAddress> {
+        let factory: EvmAddress =
+            self.require_factory_contract_id()?.to_solidity_address()?.parse()?;
+
+        Ok(EvmAddress::create2(factory, self.salt, Keccak256::digest(self.get_init_code()).into()))
+    }
+
+    /// Returns the ID the contract will have once it's deployed.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the factory contract ID isn't set.
+    pub fn get_contract_id(&self) -> crate::Result<ContractId> {
+        let address = self.get_evm_address()?;
+
+        // the deployed contract lives in the same shard and realm as its factory.
+        let ContractId { shard, realm, .. } = self.require_factory_contract_id()?;
+
+        ContractId::from_evm_address(shard, realm, &address.to_string())
+    }
+
+    /// Generates the required transaction, executes it, and returns the ID of the deployed contract.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the factory contract ID isn't set.
+    /// - [`Error::ReceiptStatus`] if the factory call fails, for example because the contract is already deployed,
+    ///   [`Error::contract_revert`] returns why.
+    /// - [`Error::BasicParse`] if the factory doesn't return the expected address.
+    pub async fn execute(&self, client: &Client) -> crate::Result<ContractId> {
+        self.execute_with_optional_timeout(client, None).await
+    }
+
+    /// Generates the required transaction, executes it, and returns the ID of the deployed contract.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the factory contract ID isn't set.
+    /// - [`Error::ReceiptStatus`] if the factory call fails, for example because the contract is already deployed,
+    ///   [`Error::contract_revert`] returns why.
+    /// - [`Error::BasicParse`] if the factory doesn't return the expected address.
+    pub async fn execute_with_timeout(
+        &self,
+        client: &Client,
+        timeout_per_transaction: std::time::Duration,
+    ) -> crate::Result<ContractId> {
+        self.execute_with_optional_timeout(client, Some(timeout_per_transaction)).await
+    }
+
+    async fn execute_with_optional_timeout(
+        &self,
+        client: &Client,
+        timeout_per_transaction: Option<std::time::Duration>,
+    ) -> crate::Result<ContractId> {
+        let contract_id = self.get_contract_id()?;
+
+        let record = self
+            .make_contract_execute_transaction()?
+            .execute_with_optional_timeout(client, timeout_per_transaction)
+            .await?
+            .get_record_query()
+            .execute_with_optional_timeout(client, timeout_per_transaction)
+            .await?;
+
+        check_deployed_address(self.get_evm_address()?, record.contract_function_result.as_ref())?;
+
+        Ok(contract_id)
+    }
+
+    fn require_factory_contract_id(&self) -> crate::Result<ContractId> {
+        self.factory_contract_id.ok_or_else(|| {
+            Error::basic_parse("factory contract ID must be set on ContractCreate2Flow")
+        })
+    }
+
+    fn make_contract_execute_transaction(&self) -> crate::Result<ContractExecuteTransaction> {
+        let factory = self.require_factory_contract_id()?;
+
+        let mut parameters = ContractFunctionParameters::new();
+        parameters.add_bytes32(&self.salt).add_bytes(&self.get_init_code());
+
+        let mut tmp = ContractExecuteTransaction::new();
+
+        tmp.contract_id(factory)
+            .function_with_parameters(&self.function, &parameters)
+            .gas(self.gas)
+            .payable_amount(self.payable_amount);
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            tmp.node_account_ids(node_account_ids.clone());
+        }
+
+        Ok(tmp)
+    }
+}
+
+// the factory returns the address it deployed to, which only matches ours if it used `CREATE2` with our salt and init code.
+fn check_deployed_address(
+    expected: EvmAddress,
+    result: Option<&ContractFunctionResult>,
+) -> crate::Result<()> {
+    let deployed = result
+        .and_then(|it| it.decode_values(&[AbiType::Address]).ok())
+        .and_then(|it| it.first()?.as_address())
+        .ok_or_else(|| {
+            Error::basic_parse("factory call didn't return the address of the deployed contract")
+        })?;
+
+    if deployed != expected {
+        return Err(Error::basic_parse(format!(
+            "factory deployed the contract to `{deployed}` instead of `{expected}`"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+    use hiero_sdk_proto::services;
+
+    use super::check_deployed_address;
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::{
+        ContractCreate2Flow,
+        ContractFunctionParameters,
+        ContractFunctionResult,
+        ContractId,
+        EvmAddress,
+    };
+
+    #[test]
+    fn get_evm_address() {
+        let mut flow = ContractCreate2Flow::new();
+        flow.bytecode(hex!("deadbeef").to_vec())
+            .salt(hex!("00000000000000000000000000000000000000000000000000000000cafebabe"));
+
+        assert!(flow.get_evm_address().is_err());
+
+        // example 5 from EIP-1014.
+        flow.factory_contract_id(ContractId::new(0, 0, 0xdeadbeef));
+
+        assert_eq!(
+            flow.get_evm_address().unwrap(),
+            "0x60f3f640a8508fc6a86d45df051962668e1e8ac7".parse::<EvmAddress>().unwrap()
+        );
+
+        assert_eq!(
+            flow.get_contract_id().unwrap(),
+            ContractId::from_evm_address(0, 0, "60f3f640a8508fc6a86d45df051962668e1e8ac7").unwrap()
+        );
+
+        // the constructor parameters are part of the init code.
+        flow.bytecode(hex!("dead").to_vec()).constructor_parameters(hex!("beef"));
+
+        assert_eq!(flow.get_init_code(), hex!("deadbeef"));
+        assert_eq!(
+            flow.get_evm_address().unwrap(),
+            "0x60f3f640a8508fc6a86d45df051962668e1e8ac7".parse::<EvmAddress>().unwrap()
+        );
+    }
+
+    #[test]
+    fn contract_execute_transaction() {
+        let mut flow = ContractCreate2Flow::new();
+        flow.factory_contract_id(ContractId::new(0, 0, 1234))
+            .function("deployContract")
+            .bytecode(hex!("6080").to_vec())
+            .salt([1; 32])
+            .gas(300_000);
+
+        let tx = flow.make_contract_execute_transaction().unwrap();
+
+        assert_eq!(tx.get_contract_id(), Some(ContractId::new(0, 0, 1234)));
+        assert_eq!(tx.get_gas(), 300_000);
+        assert_eq!(
+            tx.get_function_parameters(),
+            ContractFunctionParameters::new()
+                .add_bytes32(&[1; 32])
+                .add_bytes(&hex!("6080"))
+                .to_bytes(Some("deployContract"))
+        );
+    }
+
+    #[test]
+    fn deployed_address() {
+        let expected: EvmAddress = "0x60f3f640a8508fc6a86d45df051962668e1e8ac7".parse().unwrap();
+
+        let result = |address: &str| {
+            ContractFunctionResult::from_protobuf(services::ContractFunctionResult {
+                contract_id: Some(ContractId::new(0, 0, 1234).to_protobuf()),
+                contract_call_result: ContractFunctionParameters::new()
+                    .add_address(address)
+                    .to_bytes(None),
+                ..Default::default()
+            })
+            .unwrap()
+        };
+
+        check_deployed_address(expected, Some(&result("60f3f640a8508fc6a86d45df051962668e1e8ac7")))
+            .unwrap();
+
+        assert!(check_deployed_address(
+            expected,
+            Some(&result("00000000000000000000000000000000deadbeef"))
+        )
+        .is_err());
+
+        // the factory has to return the address.
+        let empty = ContractFunctionResult::from_protobuf(services::ContractFunctionResult {
+            contract_id: Some(ContractId::new(0, 0, 1234).to_protobuf()),
+            ..Default::default()
+        })
+        .unwrap();
+
+        assert!(check_deployed_address(expected, Some(&empty)).is_err());
+        assert!(check_deployed_address(expected, None).is_err());
+    }
+}
@@ -1,5 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
+use sha3::{
+    Digest,
+    Keccak256,
+};
 use time::Duration;
 
 use crate::signer::AnySigner;
@@ -8,6 +14,7 @@ use crate::{
     AccountId,
     Client,
     ContractCreateTransaction,
+    ContractId,
     Error,
     FileAppendTransaction,
     FileCreateTransaction,
@@ -26,9 +33,12 @@ use crate::{
 /// 1. Create a file for the contract's bytecode (via a [`FileCreateTransaction`] and zero or more [`FileAppendTransaction`]s)
 /// 2. Execute a [`ContractCreateTransaction`] using the provided information and the newly created file.
 /// 3. Delete the file created in step 1.
+///
+/// Bytecode that uses external libraries must have them linked with [`link_library`](Self::link_library) first.
 #[derive(Default, Debug)]
 pub struct ContractCreateFlow {
     bytecode: Vec<u8>,
+    library_references: Vec<LibraryReference>,
     file_append_max_chunks: Option<usize>,
     node_account_ids: Option<Vec<AccountId>>,
     contract_data: ContractData,
@@ -42,6 +52,8 @@ impl ContractCreateFlow {
     }
 
     /// Returns bytes of the smart contract.
+    ///
+    /// Placeholders for libraries that haven't been linked yet are zeroed.
     #[must_use]
     pub fn get_bytecode(&self) -> &[u8] {
         &self.bytecode
@@ -50,16 +62,76 @@ impl ContractCreateFlow {
     /// Sets the raw bytes of the smart contract.
     pub fn bytecode(&mut self, bytecode: Vec<u8>) -> &mut Self {
         self.bytecode = bytecode;
+        self.library_references.clear();
 
         self
     }
 
     /// Sets the bytecode of the smart contract in hex.
     ///
+    /// The bytecode may contain `__$<hash>$__` placeholders for libraries, which must then be linked with [`link_library`](Self::link_library).
+    /// `// $<hash>$ -> <path>:<name>` lines (as printed by `solc --bin`) name the library each placeholder is for.
+    ///
     /// # Errors
-    /// - [`Error::BasicParse`](Error::BasicParse) if `bytecode` is invalid hex.
+    /// - [`Error::BasicParse`](Error::BasicParse) if `bytecode` is invalid hex, or has a malformed placeholder.
     pub fn bytecode_hex(&mut self, bytecode: &str) -> crate::Result<&mut Self> {
-        self.bytecode = hex::decode(bytecode).map_err(Error::basic_parse)?;
+        (self.bytecode, self.library_references) = parse_bytecode_hex(bytecode)?;
+
+        Ok(self)
+    }
+
+    /// Returns the libraries the bytecode still has placeholders for.
+    ///
+    /// Libraries are returned by their fully qualified name (`<path>:<name>`) if known, otherwise as their placeholder.
+    #[must_use]
+    pub fn get_unlinked_libraries(&self) -> Vec<String> {
+        let mut libraries: Vec<String> = Vec::new();
+
+        for reference in &self.library_references {
+            let library = reference.to_string();
+
+            if !libraries.contains(&library) {
+                libraries.push(library);
+            }
+        }
+
+        libraries
+    }
+
+    /// Replaces the placeholders for a library with the address of `contract_id`, where it's deployed.
+    ///
+    /// `name_or_fqn` is either the library's fully qualified name (`contracts/Math.sol:Math`),
+    /// or its name (`Math`) if the bytecode names its placeholders.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](Error::BasicParse) if the bytecode has no placeholder for the library.
+    pub fn link_library(
+        &mut self,
+        name_or_fqn: &str,
+        contract_id: ContractId,
+    ) -> crate::Result<&mut Self> {
+        let address =
+            hex::decode(contract_id.to_solidity_address()?).map_err(Error::basic_parse)?;
+        let hash = library_hash(name_or_fqn);
+
+        let mut linked = false;
+
+        self.library_references.retain(|reference| {
+            if !reference.matches(name_or_fqn, &hash) {
+                return true;
+            }
+
+            self.bytecode[reference.offset..][..address.len()].copy_from_slice(&address);
+            linked = true;
+
+            false
+        });
+
+        if !linked {
+            return Err(Error::basic_parse(format!(
+                "bytecode has no placeholder for library `{name_or_fqn}`"
+            )));
+        }
 
         Ok(self)
     }
@@ -300,6 +372,10 @@ impl ContractCreateFlow {
         client: &Client,
         timeout_per_transaction: Option<std::time::Duration>,
     ) -> crate::Result<TransactionResponse> {
+        if !self.library_references.is_empty() {
+            return Err(Error::UnlinkedLibraries(self.get_unlinked_libraries()));
+        }
+
         // todo: proper error
         let operator_public_key = client
             .load_operator()
@@ -376,6 +452,95 @@ struct ContractData {
     signer: Option<AnySigner>,
 }
 
+// A `__$<hash>$__` placeholder for the address of a library in bytecode, where `hash` is
+// the first 34 hex digits of the keccak256 hash of the library's fully qualified name.
+#[derive(Debug)]
+struct LibraryReference {
+    offset: usize,
+    hash: String,
+    fully_qualified_name: Option<String>,
+}
+
+impl LibraryReference {
+    const PLACEHOLDER_LEN: usize = 40;
+
+    fn matches(&self, name_or_fqn: &str, hash: &str) -> bool {
+        if self.hash == hash {
+            return true;
+        }
+
+        self.fully_qualified_name.as_deref().is_some_and(|fqn| {
+            fqn == name_or_fqn || fqn.rsplit_once(':').is_some_and(|(_, name)| name == name_or_fqn)
+        })
+    }
+}
+
+impl std::fmt::Display for LibraryReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.fully_qualified_name {
+            Some(fqn) => f.write_str(fqn),
+            None => write!(f, "__${}$__", self.hash),
+        }
+    }
+}
+
+fn library_hash(fully_qualified_name: &str) -> String {
+    let mut hash = hex::encode(Keccak256::digest(fully_qualified_name));
+    hash.truncate(34);
+
+    hash
+}
+
+fn parse_bytecode_hex(bytecode: &str) -> crate::Result<(Vec<u8>, Vec<LibraryReference>)> {
+    let mut names = HashMap::new();
+    let mut code = String::new();
+
+    for line in bytecode.lines().map(str::trim) {
+        match line.strip_prefix("//") {
+            // `// $<hash>$ -> <path>:<name>`
+            Some(comment) => {
+                if let Some((hash, name)) = comment.split_once("->") {
+                    names.insert(hash.trim().trim_matches('$'), name.trim());
+                }
+            }
+            None => code.push_str(line),
+        }
+    }
+
+    let mut bytes = Vec::with_capacity(code.len() / 2);
+    let mut references = Vec::new();
+    let mut rest = code.as_str();
+
+    while let Some(start) = rest.find("__$") {
+        let (before, placeholder) = rest.split_at(start);
+        bytes.extend(hex::decode(before).map_err(Error::basic_parse)?);
+
+        let hash = placeholder
+            .get(..LibraryReference::PLACEHOLDER_LEN)
+            .and_then(|it| it.strip_prefix("__$")?.strip_suffix("$__"))
+            .filter(|it| it.len() == 34 && it.bytes().all(|it| it.is_ascii_hexdigit()))
+            .ok_or_else(|| {
+                Error::basic_parse(format!(
+                    "malformed library placeholder at offset {} of bytecode",
+                    bytes.len()
+                ))
+            })?;
+
+        references.push(LibraryReference {
+            offset: bytes.len(),
+            hash: hash.to_owned(),
+            fully_qualified_name: names.get(hash).map(|&it| it.to_owned()),
+        });
+
+        bytes.extend([0; 20]);
+        rest = &placeholder[LibraryReference::PLACEHOLDER_LEN..];
+    }
+
+    bytes.extend(hex::decode(rest).map_err(Error::basic_parse)?);
+
+    Ok((bytes, references))
+}
+
 fn split_bytecode(bytecode: &[u8]) -> (Vec<u8>, Option<Vec<u8>>) {
     const MAX_FILE_CREATE_DATA_BYTES: usize = 2048;
 
@@ -507,15 +672,24 @@ fn make_file_delete_transaction(
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use time::Duration;
 
     use crate::{
         AccountId,
         ContractCreateFlow,
+        ContractId,
+        Error,
         Hbar,
         PrivateKey,
     };
 
+    // `contracts/Math.sol:Math`
+    const MATH: &str = "__$6ad30996409d058139477db06ae39abaac$__";
+
+    // `contracts/Strings.sol:Strings`
+    const STRINGS: &str = "__$7b2a8354ec0764d0cb81ee98618437d34b$__";
+
     #[test]
     fn get_set_bytecode() {
         const BYTECODE: [u8; 3] = [2, 3, 4];
@@ -525,6 +699,46 @@ mod tests {
         assert_eq!(flow.get_bytecode(), &BYTECODE)
     }
 
+    #[test]
+    fn link_library() {
+        let bytecode = format!(
+            "608073{MATH}600073{STRINGS}73{MATH}\n\n// $6ad30996409d058139477db06ae39abaac$ -> contracts/Math.sol:Math\n"
+        );
+
+        let mut flow = ContractCreateFlow::new();
+        flow.bytecode_hex(&bytecode).unwrap();
+
+        assert_eq!(flow.get_unlinked_libraries(), ["contracts/Math.sol:Math", STRINGS]);
+
+        flow.link_library("Math", ContractId::new(0, 0, 0x1234))
+            .unwrap()
+            .link_library("contracts/Strings.sol:Strings", ContractId::new(0, 0, 0x5678))
+            .unwrap();
+
+        assert!(flow.get_unlinked_libraries().is_empty());
+        assert_eq!(
+            hex::encode(flow.get_bytecode()),
+            format!("608073{:040x}600073{:040x}73{:040x}", 0x1234, 0x5678, 0x1234)
+        );
+    }
+
+    #[test]
+    fn link_library_errors() {
+        let mut flow = ContractCreateFlow::new();
+        flow.bytecode_hex(&format!("73{MATH}")).unwrap();
+
+        assert_matches!(
+            flow.link_library("Strings", ContractId::new(0, 0, 1)),
+            Err(Error::BasicParse(_))
+        );
+        assert_eq!(flow.get_unlinked_libraries(), [MATH]);
+
+        assert_matches!(
+            ContractCreateFlow::new().bytecode_hex("73__$6ad3$__"),
+            Err(Error::BasicParse(_))
+        );
+    }
+
     #[test]
     fn get_set_max_chunks() {
         let mut flow = ContractCreateFlow::new();
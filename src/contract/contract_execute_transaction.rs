@@ -24,6 +24,11 @@ use crate::{
     Transaction,
     ValidateChecksums,
 };
+#[cfg(feature = "serde")]
+use crate::{
+    Client,
+    ContractSimulateQuery,
+};
 
 /// Call a function of the given smart contract instance, giving it
 /// parameters as its inputs.
@@ -114,6 +119,46 @@ impl ContractExecuteTransaction {
     ) -> &mut Self {
         self.function_parameters(parameters.to_bytes(Some(name)))
     }
+
+    /// Sets the gas to the amount a [`ContractSimulateQuery`] estimates this call needs,
+    /// plus `margin_percent` percent on top.
+    ///
+    /// The call is simulated as the payer of the transaction ID if set, otherwise as the client's operator.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the contract ID isn't set, or the estimate fails.
+    /// - [`Error::QueryNoPaymentPreCheckStatus`] if the simulated call reverts, [`Error::contract_revert`] returns why.
+    #[cfg(feature = "serde")]
+    pub async fn estimate_gas(
+        &mut self,
+        client: &Client,
+        margin_percent: u32,
+    ) -> crate::Result<&mut Self> {
+        let data = self.data();
+
+        let mut query = ContractSimulateQuery::new();
+        query
+            .contract_id(
+                data.contract_id
+                    .ok_or_else(|| Error::basic_parse("contract ID must be set to estimate gas"))?,
+            )
+            .function_parameters(data.function_parameters.clone())
+            .value(data.payable_amount);
+
+        let sender = self
+            .get_transaction_id()
+            .map(|it| it.account_id)
+            .or_else(|| client.get_operator_account_id());
+
+        if let Some(sender) = sender {
+            query.sender_account_id(sender);
+        }
+
+        let estimate = query.estimate_gas(client).await?;
+        let margin = estimate.saturating_mul(u64::from(margin_percent)) / 100;
+
+        Ok(self.gas(estimate.saturating_add(margin)))
+    }
 }
 
 impl TransactionData for ContractExecuteTransactionData {}
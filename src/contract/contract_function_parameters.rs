@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::cmp::max;
 use std::str::FromStr;
 
@@ -8,8 +9,13 @@ use num_bigint::{
 };
 
 use self::private::Sealed;
+use super::abi::codec;
 use crate::contract::contract_function_selector::ContractFunctionSelector;
 use crate::ethereum::SolidityAddress;
+use crate::{
+    AbiType,
+    AbiValue,
+};
 
 /// Builder for encoding parameters for a Solidity contract constructor/function call.
 #[derive(Debug, Clone, Default)]
@@ -19,7 +25,7 @@ pub struct ContractFunctionParameters {
 
 #[derive(Debug, Clone)]
 struct Argument {
-    type_name: &'static str,
+    type_name: Cow<'static, str>,
     value_bytes: Vec<u8>,
     is_dynamic: bool,
 }
@@ -133,13 +139,18 @@ impl ContractFunctionParameters {
     // since downstream code can just...
     // Call this with `Option<&A>` anyway if they want to keep ownership of it.
     pub fn to_bytes(&self, func_name: Option<&str>) -> Vec<u8> {
-        let mut current_dynamic_offset = self.args.len() * 32;
+        // static tuples and fixed size arrays are encoded in place, so they can take up more than one slot.
+        let mut current_dynamic_offset: usize = self
+            .args
+            .iter()
+            .map(|arg| if arg.is_dynamic { 32 } else { arg.value_bytes.len() })
+            .sum();
         let mut arg_bytes = Vec::new();
         let mut dynamic_arg_bytes = Vec::new();
         let mut function_selector = func_name.map(ContractFunctionSelector::new);
         for arg in &self.args {
             if let Some(selector) = &mut function_selector {
-                selector.add_param_type(arg.type_name);
+                selector.add_param_type(&arg.type_name);
             }
             if arg.is_dynamic {
                 arg_bytes.extend_from_slice(
@@ -167,7 +178,7 @@ impl ContractFunctionParameters {
     /// Add a `string` argument to the `ContractFunctionParameters`
     pub fn add_string<T: AsRef<str>>(&mut self, val: T) -> &mut Self {
         self.args.push(Argument {
-            type_name: "string",
+            type_name: "string".into(),
             value_bytes: encode_dynamic_bytes(val.as_ref().as_bytes()),
             is_dynamic: true,
         });
@@ -177,7 +188,7 @@ impl ContractFunctionParameters {
     /// Add a `string[]` argument to the `ContractFunctionParameters`
     pub fn add_string_array<T: AsRef<str>>(&mut self, val: &[T]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "string[]",
+            type_name: "string[]".into(),
             value_bytes: encode_array_of_dynamic_byte_arrays(
                 val.iter().map(|s| s.as_ref().as_bytes()),
                 val.len(),
@@ -190,7 +201,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes` argument to the `ContractFunctionParameters`
     pub fn add_bytes(&mut self, val: &[u8]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes",
+            type_name: "bytes".into(),
             value_bytes: encode_dynamic_bytes(val),
             is_dynamic: true,
         });
@@ -200,7 +211,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes[]` argument to the `ContractFunctionParameters`
     pub fn add_bytes_array(&mut self, val: &[&[u8]]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes[]",
+            type_name: "bytes[]".into(),
             value_bytes: encode_array_of_dynamic_byte_arrays(val, val.len()),
             is_dynamic: true,
         });
@@ -210,7 +221,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes32` argument to the `ContractFunctionParameters`
     pub fn add_bytes32<T: AsBytes32 + ?Sized>(&mut self, val: &T) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes32",
+            type_name: "bytes32".into(),
             value_bytes: encode_array_of_32_byte(val),
             is_dynamic: false,
         });
@@ -220,7 +231,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes32[]` argument to the `ContractFunctionParameters`
     pub fn add_bytes32_array(&mut self, val: &[[u8; 32]]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes32",
+            type_name: "bytes32".into(),
             value_bytes: encode_array_of_32_byte_elements(val.iter().copied(), val.len()),
             is_dynamic: true,
         });
@@ -230,7 +241,7 @@ impl ContractFunctionParameters {
     /// Add a `bool` argument to the `ContractFunctionParameters`
     pub fn add_bool(&mut self, val: bool) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bool",
+            type_name: "bool".into(),
             value_bytes: left_pad_32_bytes(
                 // a bool in rust is guaranteed to be of value 0 or 1
                 u32::from(val).to_be_bytes().as_slice(),
@@ -247,7 +258,7 @@ impl ContractFunctionParameters {
         T: IntEncode,
     {
         self.args.push(Argument {
-            type_name,
+            type_name: type_name.into(),
             value_bytes: truncate_and_left_pad_32_bytes(val, byte_count).to_vec(),
             is_dynamic: false,
         });
@@ -264,7 +275,7 @@ impl ContractFunctionParameters {
         T: IntEncode,
     {
         self.args.push(Argument {
-            type_name,
+            type_name: type_name.into(),
             value_bytes: encode_array_of_32_byte_elements(
                 values.iter().map(|val| truncate_and_left_pad_32_bytes(val, byte_count)),
                 values.len(),
@@ -917,7 +928,7 @@ impl ContractFunctionParameters {
     /// Add an `address` argument to the `ContractFunctionParameters`
     pub fn add_address(&mut self, address: &str) -> &mut Self {
         self.args.push(Argument {
-            type_name: "address",
+            type_name: "address".into(),
             value_bytes: encode_address(address).to_vec(),
             is_dynamic: false,
         });
@@ -927,7 +938,7 @@ impl ContractFunctionParameters {
     /// Add an `address[]` argument to the `ContractFunctionParameters`
     pub fn add_address_array(&mut self, addresses: &[&str]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "address[]",
+            type_name: "address[]".into(),
             value_bytes: encode_array_of_32_byte_elements(
                 addresses.iter().map(|addr| encode_address(addr)),
                 addresses.len(),
@@ -937,6 +948,25 @@ impl ContractFunctionParameters {
         self
     }
 
+    /// Add an argument of any ABI type to the `ContractFunctionParameters`,
+    /// including tuples (structs), fixed size arrays, and nested arrays.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if `value` can't be encoded as `param_type`.
+    pub fn add_value(
+        &mut self,
+        param_type: &AbiType,
+        value: &AbiValue,
+    ) -> crate::Result<&mut Self> {
+        self.args.push(Argument {
+            type_name: param_type.to_string().into(),
+            value_bytes: codec::encode_value(param_type, value)?,
+            is_dynamic: param_type.is_dynamic(),
+        });
+
+        Ok(self)
+    }
+
     /// Add a `function` argument to the `ContractFunctionParameters`
     ///
     /// # Panics
@@ -951,7 +981,7 @@ impl ContractFunctionParameters {
         right_pad_32_bytes(&mut value_bytes);
 
         self.args.push(Argument {
-            type_name: "function",
+            type_name: "function".into(),
             value_bytes: value_bytes,
             is_dynamic: false,
         });
@@ -1044,6 +1074,7 @@ fn encode_array_of_32_byte<T: AsBytes32 + ?Sized>(elements: &T) -> Vec<u8> {
 
 #[cfg(test)]
 mod tests {
+    use hex_literal::hex;
     use num_bigint::{
         BigInt,
         BigUint,
@@ -1051,6 +1082,12 @@ mod tests {
 
     use crate::contract::contract_function_parameters::ContractFunctionParameters;
     use crate::contract::contract_function_selector::ContractFunctionSelector;
+    use crate::{
+        AbiFunction,
+        AbiType,
+        AbiValue,
+        EvmAddress,
+    };
 
     #[test]
     fn misc_params() {
@@ -1271,6 +1308,54 @@ mod tests {
             .assert_eq(&buf);
     }
 
+    #[test]
+    fn abi_values() {
+        let order_type = "(uint256,address)[]".parse().unwrap();
+        let orders = AbiValue::Array(vec![AbiValue::Tuple(vec![
+            5_u8.into(),
+            EvmAddress(hex!("000000000000000000000000000000000000041a")).into(),
+        ])]);
+
+        let pair_type = "(uint8,bool)".parse().unwrap();
+        let pair = AbiValue::Tuple(vec![2_u8.into(), true.into()]);
+
+        let bytes = ContractFunctionParameters::new()
+            .add_value(&pair_type, &pair)
+            .unwrap()
+            .add_value(&order_type, &orders)
+            .unwrap()
+            .add_string("a")
+            .to_bytes(Some("f"));
+
+        assert_eq!(
+            bytes[..4],
+            AbiFunction::from_signature("f((uint8,bool),(uint256,address)[],string)")
+                .unwrap()
+                .selector()
+        );
+
+        // the static tuple takes up two slots of the head, so the first offset is `0x80`.
+        assert_eq!(
+            hex::encode(&bytes[4..]),
+            "0000000000000000000000000000000000000000000000000000000000000002\
+                0000000000000000000000000000000000000000000000000000000000000001\
+                0000000000000000000000000000000000000000000000000000000000000080\
+                00000000000000000000000000000000000000000000000000000000000000e0\
+                0000000000000000000000000000000000000000000000000000000000000001\
+                0000000000000000000000000000000000000000000000000000000000000005\
+                000000000000000000000000000000000000000000000000000000000000041a\
+                0000000000000000000000000000000000000000000000000000000000000001\
+                6100000000000000000000000000000000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn abi_value_type_mismatch() {
+        assert!(ContractFunctionParameters::new()
+            .add_value(&AbiType::Bool, &AbiValue::from("true"))
+            .is_err());
+    }
+
     #[test]
     #[should_panic]
     fn bytes32_panic() {
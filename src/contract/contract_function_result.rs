@@ -9,12 +9,23 @@ use num_bigint::{
     BigUint,
 };
 
+use super::abi::{
+    codec,
+    ERROR_SELECTOR,
+};
 use crate::protobuf::ToProtobuf;
 use crate::{
+    AbiEvent,
+    AbiFunction,
+    AbiType,
+    AbiValue,
     AccountId,
     ContractId,
     ContractLogInfo,
     ContractNonceInfo,
+    ContractRevert,
+    DecodedEvent,
+    DecodedOutputs,
     FromProtobuf,
 };
 
@@ -128,6 +139,68 @@ impl ContractFunctionResult {
         Some(v)
     }
 
+    /// Decode the whole function result as the outputs of `function`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if the result isn't a valid encoding of `function`'s outputs.
+    pub fn decode(&self, function: &AbiFunction) -> crate::Result<DecodedOutputs> {
+        function.decode_output(&self.bytes)
+    }
+
+    /// Decode the whole function result as a tuple of `types`.
+    ///
+    /// Unlike the `get_*` functions, this supports tuples (structs), fixed size arrays, and nested arrays.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if the result isn't a valid encoding of `types`.
+    pub fn decode_values(&self, types: &[AbiType]) -> crate::Result<Vec<AbiValue>> {
+        codec::decode(types, &self.bytes)
+    }
+
+    /// Decode every log emitted for `event`, skipping logs emitted for other events.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if a log emitted for `event` isn't a valid encoding of it,
+    ///   which usually means `event` doesn't match the contract's ABI.
+    pub fn decode_events(&self, event: &AbiEvent) -> crate::Result<Vec<DecodedEvent>> {
+        self.logs.iter().filter(|it| event.matches(it)).map(|it| event.decode_log(it)).collect()
+    }
+
+    /// Returns the data the call reverted with, or `None` if it didn't revert.
+    ///
+    /// The data is empty for `revert()` and `require(condition)` without a reason.
+    #[must_use]
+    pub fn revert_data(&self) -> Option<Vec<u8>> {
+        let message = self.error_message.as_deref()?;
+
+        // consensus nodes hex encode the revert data into the error message.
+        if let Some(data) = message.strip_prefix("0x").and_then(|it| hex::decode(it).ok()) {
+            return Some(data);
+        }
+
+        // other messages are failures that aren't reverts, like `INSUFFICIENT_GAS`.
+        if message != "CONTRACT_REVERT_EXECUTED" {
+            return None;
+        }
+
+        // `from_protobuf` strips the `Error(string)` selector, put it back if that's what it was.
+        let data: Vec<_> = ERROR_SELECTOR.iter().chain(&self.bytes).copied().collect();
+
+        match ContractRevert::decode(&data) {
+            ContractRevert::Error(_) => Some(data),
+            _ => Some(self.bytes.clone()),
+        }
+    }
+
+    /// Decodes why the call reverted, or returns `None` if it didn't revert.
+    ///
+    /// Custom errors decode to [`ContractRevert::Unknown`], pass [`revert_data`](Self::revert_data)
+    /// to [`ContractAbi::decode_revert`](crate::ContractAbi::decode_revert) to decode those.
+    #[must_use]
+    pub fn revert(&self) -> Option<ContractRevert> {
+        self.revert_data().map(|it| ContractRevert::decode(&it))
+    }
+
     /// Get the value at `index` as solidity `bytes`.
     #[must_use]
     pub fn get_bytes(&self, index: usize) -> Option<&[u8]> {
@@ -310,10 +383,15 @@ mod tests {
         ToProtobuf,
     };
     use crate::{
+        AbiEvent,
+        AbiType,
+        AbiValue,
         AccountId,
         ContractFunctionResult,
         ContractId,
+        ContractLogInfo,
         ContractNonceInfo,
+        ContractRevert,
     };
 
     const CALL_RESULT: [u8; 320] = hex!(
@@ -366,6 +444,117 @@ mod tests {
         "72616E646F6D2062797465732032000000000000decaff000000000000000000"
     );
 
+    #[test]
+    fn decode_values() {
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            contract_call_result: STRING_ARRAY_RESULT.to_vec(),
+            ..Default::default()
+        };
+
+        let result = ContractFunctionResult::from_protobuf(result).unwrap();
+
+        assert_eq!(
+            result.decode_values(&[AbiType::Array(Box::new(AbiType::String))]).unwrap(),
+            [AbiValue::Array(vec!["random bytes".into(), "random bytes 2".into()])]
+        );
+
+        assert!(result.decode_values(&[AbiType::Bool]).is_err());
+    }
+
+    #[test]
+    fn decode_events() {
+        let transfer = AbiEvent::from_signature(
+            "Transfer(address indexed from, address indexed to, uint256 value)",
+        )
+        .unwrap();
+
+        let approval = AbiEvent::from_signature(
+            "Approval(address indexed owner, address indexed spender, uint256 value)",
+        )
+        .unwrap();
+
+        let log = |event: &AbiEvent, value: u8| ContractLogInfo {
+            contract_id: ContractId::from(3),
+            bloom: Vec::new(),
+            topics: vec![event.topic().to_vec(), [0; 32].to_vec(), [1; 32].to_vec()],
+            data: [0; 31].into_iter().chain([value]).collect(),
+        };
+
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            log_info: vec![
+                log(&transfer, 1).to_protobuf(),
+                log(&approval, 2).to_protobuf(),
+                log(&transfer, 3).to_protobuf(),
+            ],
+            ..Default::default()
+        };
+
+        let result = ContractFunctionResult::from_protobuf(result).unwrap();
+
+        let values: Vec<_> = result
+            .decode_events(&transfer)
+            .unwrap()
+            .into_iter()
+            .map(|it| it.params.get("value").cloned())
+            .collect();
+
+        assert_eq!(values, [Some(AbiValue::from(1_u8)), Some(AbiValue::from(3_u8))]);
+
+        // a log with the event's topic, that doesn't decode as the event.
+        let mut result = result;
+        result.logs[2].data.truncate(16);
+
+        assert!(matches!(result.decode_events(&transfer), Err(crate::Error::BasicParse(_))));
+        assert_eq!(result.decode_events(&approval).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn revert() {
+        let result = |error_message: &str, contract_call_result: &[u8]| {
+            ContractFunctionResult::from_protobuf(services::ContractFunctionResult {
+                contract_id: Some(ContractId::from(3).to_protobuf()),
+                error_message: error_message.to_owned(),
+                contract_call_result: contract_call_result.to_vec(),
+                ..Default::default()
+            })
+            .unwrap()
+        };
+
+        let reason = hex!(
+            "08c379a0"
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+            "6e6f000000000000000000000000000000000000000000000000000000000000"
+        );
+
+        assert_eq!(result("", &[]).revert(), None);
+        assert_eq!(result("INSUFFICIENT_GAS", &[]).revert(), None);
+        assert_eq!(result("INSUFFICIENT_GAS", &hex!("cf479181")).revert(), None);
+
+        assert_eq!(
+            result("CONTRACT_REVERT_EXECUTED", &reason).revert(),
+            Some(ContractRevert::Error("no".to_owned()))
+        );
+
+        assert_eq!(
+            result(&format!("0x{}", hex::encode(reason)), &[]).revert(),
+            Some(ContractRevert::Error("no".to_owned()))
+        );
+
+        assert_eq!(
+            result("CONTRACT_REVERT_EXECUTED", &[]).revert(),
+            Some(ContractRevert::Unknown(Vec::new()))
+        );
+
+        // custom errors are left as they are.
+        assert_eq!(
+            result("CONTRACT_REVERT_EXECUTED", &hex!("cf479181")).revert(),
+            Some(ContractRevert::Unknown(hex!("cf479181").to_vec()))
+        );
+    }
+
     #[test]
     fn evm_address() {
         const EVM_ADDRESS: [u8; 20] = hex!("98329e006610472e6b372c080833f6d79ed833cf");
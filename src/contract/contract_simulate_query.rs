@@ -0,0 +1,516 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use bytes::Bytes;
+use hyper::Method;
+use serde_json::{
+    json,
+    Value,
+};
+use time::OffsetDateTime;
+
+use crate::mirror_rest::{
+    self,
+    DEFAULT_MAX_ATTEMPTS,
+    DEFAULT_MAX_BACKOFF,
+};
+use crate::{
+    AccountId,
+    Client,
+    ContractFunctionParameters,
+    ContractFunctionResult,
+    ContractId,
+    ContractRevert,
+    Error,
+    Hbar,
+    Status,
+};
+
+/// Simulates a call to a smart contract on a mirror node, without submitting anything to the network.
+///
+/// Unlike [`ContractCallQuery`](crate::ContractCallQuery) this is free, doesn't need a gas limit,
+/// can call functions that change state (the changes are discarded), and can estimate the gas a call needs.
+///
+/// # Examples
+/// ```no_run
+/// # async fn example() -> hiero_sdk::Result<()> {
+/// use hiero_sdk::{Client, ContractId, ContractSimulateQuery};
+///
+/// let client = Client::for_testnet();
+///
+/// let mut query = ContractSimulateQuery::new();
+/// query.contract_id(ContractId::new(0, 0, 1234)).function("totalSupply");
+///
+/// let result = query.execute(&client).await?;
+/// let gas = query.estimate_gas(&client).await?;
+///
+/// println!("total supply: {:?}, gas: {gas}", result.get_u256(0));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContractSimulateQuery {
+    contract_id: Option<ContractId>,
+    function_parameters: Vec<u8>,
+    sender_account_id: Option<AccountId>,
+    gas: Option<u64>,
+    value: Hbar,
+    block: ContractSimulateBlock,
+    max_attempts: usize,
+    max_backoff: Duration,
+}
+
+/// The block a [`ContractSimulateQuery`] runs against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContractSimulateBlock {
+    /// The most recent block.
+    #[default]
+    Latest,
+
+    /// The first block.
+    Earliest,
+
+    /// The block currently being built.
+    Pending,
+
+    /// The block with the given number.
+    Number(u64),
+
+    /// The last block at or before the given time.
+    Timestamp(OffsetDateTime),
+}
+
+impl Default for ContractSimulateQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContractSimulateQuery {
+    /// Creates a new `ContractSimulateQuery`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            contract_id: None,
+            function_parameters: Vec::new(),
+            sender_account_id: None,
+            gas: None,
+            value: Hbar::ZERO,
+            block: ContractSimulateBlock::Latest,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+
+    /// Returns the contract to call.
+    #[must_use]
+    pub fn get_contract_id(&self) -> Option<ContractId> {
+        self.contract_id
+    }
+
+    /// Sets the contract to call.
+    pub fn contract_id(&mut self, contract_id: ContractId) -> &mut Self {
+        self.contract_id = Some(contract_id);
+        self
+    }
+
+    /// Returns the function parameters as their raw bytes.
+    #[must_use]
+    pub fn get_function_parameters(&self) -> &[u8] {
+        &self.function_parameters
+    }
+
+    /// Sets the function parameters as their raw bytes.
+    pub fn function_parameters(&mut self, data: Vec<u8>) -> &mut Self {
+        self.function_parameters = data;
+        self
+    }
+
+    /// Sets the function with no parameters.
+    pub fn function(&mut self, name: &str) -> &mut Self {
+        self.function_with_parameters(name, &ContractFunctionParameters::new())
+    }
+
+    /// Sets the function with parameters.
+    pub fn function_with_parameters(
+        &mut self,
+        name: &str,
+        parameters: &ContractFunctionParameters,
+    ) -> &mut Self {
+        self.function_parameters(parameters.to_bytes(Some(name)))
+    }
+
+    /// Returns the account the call is made from.
+    #[must_use]
+    pub fn get_sender_account_id(&self) -> Option<AccountId> {
+        self.sender_account_id
+    }
+
+    /// Sets the account the call is made from (`msg.sender`).
+    pub fn sender_account_id(&mut self, sender_account_id: AccountId) -> &mut Self {
+        self.sender_account_id = Some(sender_account_id);
+        self
+    }
+
+    /// Returns the gas limit of the call.
+    #[must_use]
+    pub fn get_gas(&self) -> Option<u64> {
+        self.gas
+    }
+
+    /// Sets the gas limit of the call, by default the mirror node's maximum is used.
+    pub fn gas(&mut self, gas: u64) -> &mut Self {
+        self.gas = Some(gas);
+        self
+    }
+
+    /// Returns the hbar sent with the call.
+    #[must_use]
+    pub fn get_value(&self) -> Hbar {
+        self.value
+    }
+
+    /// Sets the hbar sent with the call (`msg.value`).
+    pub fn value(&mut self, value: Hbar) -> &mut Self {
+        self.value = value;
+        self
+    }
+
+    /// Returns the block the call runs against.
+    #[must_use]
+    pub fn get_block(&self) -> ContractSimulateBlock {
+        self.block
+    }
+
+    /// Sets the block the call runs against, by default the latest block.
+    pub fn block(&mut self, block: ContractSimulateBlock) -> &mut Self {
+        self.block = block;
+        self
+    }
+
+    /// Returns the maximum number of retry attempts.
+    #[must_use]
+    pub fn get_max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    /// Sets the maximum number of retry attempts.
+    pub fn max_attempts(&mut self, max_attempts: usize) -> &mut Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Returns the maximum backoff duration between retries.
+    #[must_use]
+    pub fn get_max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// Sets the maximum backoff duration between retries.
+    pub fn max_backoff(&mut self, max_backoff: Duration) -> &mut Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Simulates the call and returns its result.
+    ///
+    /// Only [`bytes`](ContractFunctionResult::bytes) (and the fields this query sets) are filled in,
+    /// the mirror node doesn't return logs or gas used.
+    ///
+    /// # Errors
+    /// - [`Error::QueryNoPaymentPreCheckStatus`] with [`Status::ContractRevertExecuted`] if the call reverts,
+    ///   [`Error::contract_revert`] returns why.
+    /// - [`Error::BasicParse`] if the contract ID isn't set, or the mirror node returns any other error.
+    pub async fn execute(&self, client: &Client) -> crate::Result<ContractFunctionResult> {
+        let contract_id = self.require_contract_id()?;
+        let bytes = self.call(client, false).await?;
+
+        Ok(ContractFunctionResult {
+            contract_id,
+            evm_address: None,
+            bytes,
+            error_message: None,
+            bloom: Vec::new(),
+            gas_used: 0,
+            gas: self.gas.unwrap_or_default(),
+            hbar_amount: self.value.to_tinybars() as u64,
+            contract_function_parameters_bytes: self.function_parameters.clone(),
+            sender_account_id: self.sender_account_id,
+            logs: Vec::new(),
+            contract_nonces: Vec::new(),
+            signer_nonce: None,
+        })
+    }
+
+    /// Estimates the gas the call needs.
+    ///
+    /// # Errors
+    /// - [`Error::QueryNoPaymentPreCheckStatus`] with [`Status::ContractRevertExecuted`] if the call reverts,
+    ///   [`Error::contract_revert`] returns why.
+    /// - [`Error::BasicParse`] if the contract ID isn't set, or the mirror node returns any other error.
+    pub async fn estimate_gas(&self, client: &Client) -> crate::Result<u64> {
+        let result = self.call(client, true).await?;
+
+        // the estimate is returned as a big endian number, usually without leading zeros.
+        if result.len() > 8 && result[..result.len() - 8].iter().any(|it| *it != 0) {
+            return Err(Error::basic_parse("gas estimate doesn't fit in a u64"));
+        }
+
+        Ok(result.iter().fold(0, |acc, it| (acc << 8) | u64::from(*it)))
+    }
+
+    fn require_contract_id(&self) -> crate::Result<ContractId> {
+        self.contract_id
+            .ok_or_else(|| Error::basic_parse("contract ID must be set on ContractSimulateQuery"))
+    }
+
+    async fn call(&self, client: &Client, estimate: bool) -> crate::Result<Vec<u8>> {
+        let base_url = mirror_rest::base_url(client);
+
+        let block = match self.block {
+            ContractSimulateBlock::Timestamp(timestamp) => {
+                self.block_at(&base_url, timestamp).await?
+            }
+            block => block,
+        };
+
+        let body = self.request_body(estimate, block)?;
+
+        let (status, body) = mirror_rest::send(
+            Method::POST,
+            &format!("{base_url}/contracts/call"),
+            Some(("application/json", Bytes::from(body.to_string()))),
+            self.max_attempts,
+            self.max_backoff,
+        )
+        .await?;
+
+        if !status.is_success() {
+            return Err(parse_error(status, &body));
+        }
+
+        let value: Value = serde_json::from_str(&body).map_err(|e| {
+            Error::basic_parse(format!("failed to parse contract call response JSON: {e}"))
+        })?;
+
+        let result = value
+            .get("result")
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::basic_parse("contract call response is missing `result`"))?;
+
+        decode_hex(result)
+    }
+
+    // the mirror node only selects blocks by number, so look up the block for `timestamp`.
+    async fn block_at(
+        &self,
+        base_url: &str,
+        timestamp: OffsetDateTime,
+    ) -> crate::Result<ContractSimulateBlock> {
+        let url = format!(
+            "{base_url}/blocks?timestamp=lte:{}.{:09}&order=desc&limit=1",
+            timestamp.unix_timestamp(),
+            timestamp.nanosecond()
+        );
+
+        let (status, body) =
+            mirror_rest::send(Method::GET, &url, None, self.max_attempts, self.max_backoff).await?;
+
+        if !status.is_success() {
+            return Err(Error::basic_parse(format!(
+                "block lookup failed with HTTP {status}: {body}"
+            )));
+        }
+
+        let value: Value = serde_json::from_str(&body)
+            .map_err(|e| Error::basic_parse(format!("failed to parse blocks JSON: {e}")))?;
+
+        value
+            .pointer("/blocks/0/number")
+            .and_then(Value::as_u64)
+            .map(ContractSimulateBlock::Number)
+            .ok_or_else(|| Error::basic_parse(format!("no block at or before {timestamp}")))
+    }
+
+    fn request_body(&self, estimate: bool, block: ContractSimulateBlock) -> crate::Result<Value> {
+        let contract_id = self.require_contract_id()?;
+
+        let block = match block {
+            ContractSimulateBlock::Latest | ContractSimulateBlock::Timestamp(_) => {
+                "latest".to_owned()
+            }
+            ContractSimulateBlock::Earliest => "earliest".to_owned(),
+            ContractSimulateBlock::Pending => "pending".to_owned(),
+            ContractSimulateBlock::Number(number) => format!("0x{number:x}"),
+        };
+
+        let mut body = json!({
+            "block": block,
+            "data": format!("0x{}", hex::encode(&self.function_parameters)),
+            "estimate": estimate,
+            "to": format!("0x{}", contract_id.to_solidity_address()?),
+        });
+
+        if let Some(sender) = &self.sender_account_id {
+            let address = match sender.evm_address {
+                Some(address) => hex::encode(address.to_bytes()),
+                None => sender.to_solidity_address()?,
+            };
+
+            body["from"] = json!(format!("0x{address}"));
+        }
+
+        if let Some(gas) = self.gas {
+            body["gas"] = json!(gas);
+        }
+
+        if self.value != Hbar::ZERO {
+            body["value"] = json!(self.value.to_tinybars());
+        }
+
+        Ok(body)
+    }
+}
+
+// `{"_status": {"messages": [{"message": "CONTRACT_REVERT_EXECUTED", "detail": "...", "data": "0x..."}]}}`
+fn parse_error(status: hyper::StatusCode, body: &str) -> Error {
+    let message = serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|it| it.pointer("/_status/messages/0").cloned());
+
+    let Some(message) = message else {
+        return Error::basic_parse(format!("contract call failed with HTTP {status}: {body}"));
+    };
+
+    if message.get("message").and_then(Value::as_str) == Some("CONTRACT_REVERT_EXECUTED") {
+        let data = message
+            .get("data")
+            .and_then(Value::as_str)
+            .and_then(|it| decode_hex(it).ok())
+            .unwrap_or_default();
+
+        return Error::QueryNoPaymentPreCheckStatus {
+            status: Status::ContractRevertExecuted,
+            revert: Some(Box::new(ContractRevert::decode(&data))),
+        };
+    }
+
+    let detail = ["message", "detail"]
+        .iter()
+        .filter_map(|key| message.get(key).and_then(Value::as_str))
+        .filter(|it| !it.is_empty())
+        .collect::<Vec<_>>()
+        .join(": ");
+
+    Error::basic_parse(format!("contract call failed with HTTP {status}: {detail}"))
+}
+
+fn decode_hex(value: &str) -> crate::Result<Vec<u8>> {
+    let value = value.strip_prefix("0x").unwrap_or(value);
+
+    // quantities (like the gas estimate) can have an odd number of digits.
+    if value.len() % 2 == 1 {
+        return hex::decode(format!("0{value}")).map_err(Error::basic_parse);
+    }
+
+    hex::decode(value).map_err(Error::basic_parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use expect_test::expect;
+    use hyper::StatusCode;
+
+    use super::{
+        decode_hex,
+        parse_error,
+        ContractSimulateBlock,
+        ContractSimulateQuery,
+    };
+    use crate::{
+        AccountId,
+        ContractId,
+        ContractRevert,
+        Error,
+        EvmAddress,
+        Hbar,
+        Status,
+    };
+
+    #[test]
+    fn request_body() {
+        let mut query = ContractSimulateQuery::new();
+        query
+            .contract_id(ContractId::new(0, 0, 1234))
+            .function_parameters(vec![0x18, 0x16, 0x0d, 0xdd])
+            .sender_account_id(AccountId::new(0, 0, 5005))
+            .value(Hbar::from_tinybars(10))
+            .gas(50_000);
+
+        expect![[r#"
+            {"block":"0x2a","data":"0x18160ddd","estimate":true,"from":"0x000000000000000000000000000000000000138d","gas":50000,"to":"0x00000000000000000000000000000000000004d2","value":10}
+        "#]]
+        .assert_eq(&format!(
+            "{}\n",
+            query.request_body(true, ContractSimulateBlock::Number(42)).unwrap()
+        ));
+
+        let mut query = ContractSimulateQuery::new();
+        query
+            .contract_id(ContractId::new(0, 0, 1234))
+            .sender_account_id(AccountId::from_evm_address(&EvmAddress([0xab; 20]), 0, 0));
+
+        expect![[r#"
+            {"block":"latest","data":"0x","estimate":false,"from":"0xabababababababababababababababababababab","to":"0x00000000000000000000000000000000000004d2"}
+        "#]]
+        .assert_eq(&format!(
+            "{}\n",
+            query.request_body(false, ContractSimulateBlock::Latest).unwrap()
+        ));
+
+        assert!(ContractSimulateQuery::new()
+            .request_body(false, ContractSimulateBlock::Latest)
+            .is_err());
+    }
+
+    #[test]
+    fn revert_error() {
+        let body = r#"{
+            "_status": {
+                "messages": [{
+                    "message": "CONTRACT_REVERT_EXECUTED",
+                    "detail": "",
+                    "data": "0x4e487b710000000000000000000000000000000000000000000000000000000000000012"
+                }]
+            }
+        }"#;
+
+        let error = parse_error(StatusCode::BAD_REQUEST, body);
+
+        assert_matches!(
+            error,
+            Error::QueryNoPaymentPreCheckStatus { status: Status::ContractRevertExecuted, .. }
+        );
+        assert_eq!(error.contract_revert(), Some(&ContractRevert::Panic(0x12)));
+
+        let body =
+            r#"{"_status":{"messages":[{"message":"Bad request","detail":"Invalid block"}]}}"#;
+        let error = parse_error(StatusCode::BAD_REQUEST, body);
+
+        assert_eq!(error.contract_revert(), None);
+        assert_eq!(
+            error.to_string(),
+            "failed to parse: contract call failed with HTTP 400 Bad Request: Bad request: Invalid block"
+        );
+    }
+
+    #[test]
+    fn hex_quantities() {
+        assert_eq!(decode_hex("0x5208").unwrap(), [0x52, 0x08]);
+        assert_eq!(decode_hex("0x208").unwrap(), [0x02, 0x08]);
+        assert!(decode_hex("0x").unwrap().is_empty());
+        assert!(decode_hex("0xzz").is_err());
+    }
+}
@@ -1,7 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
+mod abi;
 mod contract_bytecode_query;
 mod contract_call_query;
+mod contract_create2_flow;
 mod contract_create_flow;
 mod contract_create_transaction;
 mod contract_delete_transaction;
@@ -14,13 +16,31 @@ mod contract_info;
 mod contract_info_query;
 mod contract_log_info;
 mod contract_nonce_info;
+#[cfg(feature = "serde")]
+mod contract_simulate_query;
 mod contract_update_transaction;
 mod delegate_contract_id;
 
+pub use abi::{
+    AbiError,
+    AbiEvent,
+    AbiEventParam,
+    AbiFunction,
+    AbiParam,
+    AbiStateMutability,
+    AbiType,
+    AbiValue,
+    ContractAbi,
+    ContractBindings,
+    ContractRevert,
+    DecodedEvent,
+    DecodedOutputs,
+};
 pub use contract_bytecode_query::ContractBytecodeQuery;
 pub(crate) use contract_bytecode_query::ContractBytecodeQueryData;
 pub use contract_call_query::ContractCallQuery;
 pub(crate) use contract_call_query::ContractCallQueryData;
+pub use contract_create2_flow::ContractCreate2Flow;
 pub use contract_create_flow::ContractCreateFlow;
 pub use contract_create_transaction::ContractCreateTransaction;
 pub(crate) use contract_create_transaction::ContractCreateTransactionData;
@@ -36,6 +56,11 @@ pub use contract_info_query::ContractInfoQuery;
 pub(crate) use contract_info_query::ContractInfoQueryData;
 pub use contract_log_info::ContractLogInfo;
 pub use contract_nonce_info::ContractNonceInfo;
+#[cfg(feature = "serde")]
+pub use contract_simulate_query::{
+    ContractSimulateBlock,
+    ContractSimulateQuery,
+};
 pub use contract_update_transaction::ContractUpdateTransaction;
 pub(crate) use contract_update_transaction::ContractUpdateTransactionData;
 pub use delegate_contract_id::DelegateContractId;
@@ -6,6 +6,7 @@ use std::result::Result as StdResult;
 use crate::entity_id::Checksum;
 use crate::{
     AccountId,
+    ContractRevert,
     Hbar,
     Status,
     TransactionId,
@@ -71,13 +72,16 @@ pub enum Error {
     ///
     /// Caused by `status` being an error.
     #[error(
-    "query with payment transaction `{transaction_id}` failed pre-check with status `{status:?}`"
+    "query with payment transaction `{transaction_id}` failed pre-check with status `{status:?}`{}",
+    revert_suffix(revert.as_deref())
     )]
     QueryPaymentPreCheckStatus {
         /// The `Status` that caused the [`Query`](crate::Query) to fail pre-check.
         status: Status,
         /// The associated `PaymentTransaction`'s `TransactionId`.
         transaction_id: Box<TransactionId>,
+        /// Why the contract reverted, if the query was a reverted [`ContractCallQuery`](crate::ContractCallQuery).
+        revert: Option<Box<ContractRevert>>,
     },
 
     /// A [`Query`](crate::Query) failed pre-check.
@@ -85,10 +89,15 @@ pub enum Error {
     /// The query had no `PaymentTransaction`.
     ///
     /// Caused by `status` being an error.
-    #[error("query with no payment transaction failed pre-check with status `{status:?}`")]
+    #[error(
+        "query with no payment transaction failed pre-check with status `{status:?}`{}",
+        revert_suffix(revert.as_deref())
+    )]
     QueryNoPaymentPreCheckStatus {
         /// The `Status` that caused the [`Query`](crate::Query) to fail pre-check.
         status: Status,
+        /// Why the contract reverted, if the query was a reverted [`ContractCallQuery`](crate::ContractCallQuery).
+        revert: Option<Box<ContractRevert>>,
     },
 
     /// Failed to parse a basic type from string
@@ -177,17 +186,41 @@ pub enum Error {
 
     // fixme(sr): Citation needed (unsure if this is accurate).
     /// Getting the receipt for `transaction_id` failed with `status`.
-    #[error("receipt for transaction `{transaction_id:?}` failed with status `{status:?}`")]
+    #[error(
+        "receipt for transaction `{transaction_id:?}` failed with status `{status:?}`{}",
+        revert_suffix(revert.as_deref())
+    )]
     ReceiptStatus {
         /// The Error's status code.
         status: Status,
         /// The [`Transaction`](crate::Transaction)'s ID.
         transaction_id: Option<Box<TransactionId>>,
+        /// Why the contract reverted, if the transaction was a reverted contract call.
+        ///
+        /// Only available when the error comes from a [`TransactionRecord`](crate::TransactionRecord),
+        /// since receipts don't include the contract's result.
+        revert: Option<Box<ContractRevert>>,
     },
 
+    /// Contract bytecode still has placeholders for the libraries in the list, which must be linked before it can be deployed.
+    #[error("bytecode has unlinked libraries: {}", .0.join(", "))]
+    UnlinkedLibraries(Vec<String>),
+
+    /// Simulating the custom fees of a transfer failed with `status`,
+    /// the network would reject the transaction with the same status.
+    #[error("custom fee assessment failed with status `{0:?}`")]
+    CustomFeeAssessment(Status),
+
     /// Failed to verify a signature.
     #[error("failed to verify a signature: {0}")]
     SignatureVerify(#[source] BoxStdError),
+
+    /// Failed to create a signature.
+    ///
+    /// Examples of when this can happen (non-exhaustive):
+    /// - Producing a recoverable (Ethereum style) signature with an `Ed25519` key.
+    #[error("failed to create a signature: {0}")]
+    SignatureCreate(#[source] BoxStdError),
 }
 
 impl Error {
@@ -210,6 +243,29 @@ impl Error {
     pub(crate) fn signature_verify(error: impl Into<BoxStdError>) -> Self {
         Self::SignatureVerify(error.into())
     }
+
+    pub(crate) fn signature_create(error: impl Into<BoxStdError>) -> Self {
+        Self::SignatureCreate(error.into())
+    }
+
+    /// Returns why a contract call reverted, if this error is for a reverted call and the reason is known.
+    ///
+    /// The reason comes from the `revert` of [`Error::ReceiptStatus`], [`Error::QueryPaymentPreCheckStatus`]
+    /// or [`Error::QueryNoPaymentPreCheckStatus`].
+    #[must_use]
+    pub fn contract_revert(&self) -> Option<&ContractRevert> {
+        match self {
+            Self::ReceiptStatus { revert, .. }
+            | Self::QueryPaymentPreCheckStatus { revert, .. }
+            | Self::QueryNoPaymentPreCheckStatus { revert, .. } => revert.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+// `: <reason>` when a contract reverted.
+fn revert_suffix(revert: Option<&ContractRevert>) -> String {
+    revert.map(|it| format!(": {it}")).unwrap_or_default()
 }
 
 /// Failed to parse a mnemonic.
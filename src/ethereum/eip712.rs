@@ -0,0 +1,1041 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+
+use num_bigint::{
+    BigInt,
+    BigUint,
+    Sign,
+};
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+use crate::{
+    Error,
+    EvmAddress,
+    PrivateKey,
+    PublicKey,
+};
+
+const DOMAIN_TYPE_NAME: &str = "EIP712Domain";
+
+/// A member of an EIP-712 struct type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip712Field {
+    /// The name of the member.
+    pub name: String,
+
+    /// The Solidity type of the member, for example `uint256`, `Person` or `Person[]`.
+    pub type_name: String,
+}
+
+impl Eip712Field {
+    /// Create a new `Eip712Field` with the given `name` and `type_name`.
+    #[must_use]
+    pub fn new(name: impl Into<String>, type_name: impl Into<String>) -> Self {
+        Self { name: name.into(), type_name: type_name.into() }
+    }
+}
+
+/// A value in EIP-712 typed data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Eip712Value {
+    /// A `bool`.
+    Bool(bool),
+
+    /// A `uintN`.
+    Uint(BigUint),
+
+    /// An `intN`.
+    Int(BigInt),
+
+    /// An `address`.
+    Address(EvmAddress),
+
+    /// Either `bytes` or `bytesN`.
+    Bytes(Vec<u8>),
+
+    /// A `string`.
+    String(String),
+
+    /// Either a dynamic (`T[]`) or fixed size (`T[N]`) array.
+    Array(Vec<Eip712Value>),
+
+    /// A struct, by member name.
+    Struct(BTreeMap<String, Eip712Value>),
+}
+
+impl From<bool> for Eip712Value {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<u64> for Eip712Value {
+    fn from(value: u64) -> Self {
+        Self::Uint(value.into())
+    }
+}
+
+impl From<i64> for Eip712Value {
+    fn from(value: i64) -> Self {
+        Self::Int(value.into())
+    }
+}
+
+impl From<BigUint> for Eip712Value {
+    fn from(value: BigUint) -> Self {
+        Self::Uint(value)
+    }
+}
+
+impl From<BigInt> for Eip712Value {
+    fn from(value: BigInt) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<EvmAddress> for Eip712Value {
+    fn from(value: EvmAddress) -> Self {
+        Self::Address(value)
+    }
+}
+
+impl From<Vec<u8>> for Eip712Value {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl From<String> for Eip712Value {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for Eip712Value {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<Vec<Eip712Value>> for Eip712Value {
+    fn from(value: Vec<Eip712Value>) -> Self {
+        Self::Array(value)
+    }
+}
+
+impl From<BTreeMap<String, Eip712Value>> for Eip712Value {
+    fn from(value: BTreeMap<String, Eip712Value>) -> Self {
+        Self::Struct(value)
+    }
+}
+
+/// The EIP-712 domain, used to compute the domain separator.
+///
+/// Unless an `EIP712Domain` type is added with [`Eip712TypedData::add_type`],
+/// only the fields that are `Some` are part of the `EIP712Domain` type, in the order listed here.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Eip712Domain {
+    /// The user readable name of the signing domain.
+    pub name: Option<String>,
+
+    /// The current major version of the signing domain.
+    pub version: Option<String>,
+
+    /// The EIP-155 chain ID, for example `295` for Hedera mainnet.
+    pub chain_id: Option<u64>,
+
+    /// The address of the contract that will verify the signature.
+    pub verifying_contract: Option<EvmAddress>,
+
+    /// A disambiguating salt for the protocol.
+    pub salt: Option<[u8; 32]>,
+}
+
+impl Eip712Domain {
+    fn fields_and_values(&self) -> (Vec<Eip712Field>, BTreeMap<String, Eip712Value>) {
+        let mut fields = Vec::new();
+        let mut values = BTreeMap::new();
+
+        let mut push = |name: &str, type_name: &str, value: Eip712Value| {
+            fields.push(Eip712Field::new(name, type_name));
+            values.insert(name.to_owned(), value);
+        };
+
+        if let Some(name) = &self.name {
+            push("name", "string", name.as_str().into());
+        }
+
+        if let Some(version) = &self.version {
+            push("version", "string", version.as_str().into());
+        }
+
+        if let Some(chain_id) = self.chain_id {
+            push("chainId", "uint256", chain_id.into());
+        }
+
+        if let Some(verifying_contract) = self.verifying_contract {
+            push("verifyingContract", "address", verifying_contract.into());
+        }
+
+        if let Some(salt) = self.salt {
+            push("salt", "bytes32", salt.to_vec().into());
+        }
+
+        (fields, values)
+    }
+}
+
+/// EIP-712 typed structured data, ready to be hashed and signed.
+///
+/// # Examples
+/// ```
+/// # fn main() -> hiero_sdk::Result<()> {
+/// use hiero_sdk::{Eip712Domain, Eip712Field, Eip712TypedData, PrivateKey};
+///
+/// let mut typed_data = Eip712TypedData::new(
+///     Eip712Domain { name: Some("Ether Mail".to_owned()), chain_id: Some(296), ..Default::default() },
+///     "Mail",
+/// );
+///
+/// typed_data
+///     .add_type("Mail", [Eip712Field::new("contents", "string")])
+///     .field("contents", "Hello, Bob!");
+///
+/// let key = PrivateKey::generate_ecdsa();
+/// let signature = typed_data.sign(&key)?;
+///
+/// assert_eq!(typed_data.recover_signer(&signature)?, key.public_key().to_evm_address().unwrap());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Eip712TypedData {
+    types: BTreeMap<String, Vec<Eip712Field>>,
+    primary_type: String,
+    domain: Eip712Domain,
+    message: BTreeMap<String, Eip712Value>,
+}
+
+impl Eip712TypedData {
+    /// Create new, empty typed data for `primary_type` in `domain`.
+    #[must_use]
+    pub fn new(domain: Eip712Domain, primary_type: impl Into<String>) -> Self {
+        Self {
+            types: BTreeMap::new(),
+            primary_type: primary_type.into(),
+            domain,
+            message: BTreeMap::new(),
+        }
+    }
+
+    /// Parse typed data from the JSON format used by `eth_signTypedData_v4`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `json` isn't valid typed data.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        json::parse_typed_data(json)
+    }
+
+    /// Returns the domain of this typed data.
+    #[must_use]
+    pub fn get_domain(&self) -> &Eip712Domain {
+        &self.domain
+    }
+
+    /// Returns the name of the primary type of the message.
+    #[must_use]
+    pub fn get_primary_type(&self) -> &str {
+        &self.primary_type
+    }
+
+    /// Returns the struct types known to this typed data.
+    #[must_use]
+    pub fn get_types(&self) -> &BTreeMap<String, Vec<Eip712Field>> {
+        &self.types
+    }
+
+    /// Returns the message.
+    #[must_use]
+    pub fn get_message(&self) -> &BTreeMap<String, Eip712Value> {
+        &self.message
+    }
+
+    /// Adds (or replaces) the struct type `name`, made of `fields`.
+    pub fn add_type(
+        &mut self,
+        name: impl Into<String>,
+        fields: impl IntoIterator<Item = Eip712Field>,
+    ) -> &mut Self {
+        self.types.insert(name.into(), fields.into_iter().collect());
+        self
+    }
+
+    /// Sets the member `name` of the message to `value`.
+    pub fn field(&mut self, name: impl Into<String>, value: impl Into<Eip712Value>) -> &mut Self {
+        self.message.insert(name.into(), value.into());
+        self
+    }
+
+    /// Replaces the message.
+    pub fn message(&mut self, message: BTreeMap<String, Eip712Value>) -> &mut Self {
+        self.message = message;
+        self
+    }
+
+    /// Returns the encoding of the struct type `name`, for example
+    /// `Mail(Person from,Person to,string contents)Person(string name,address wallet)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `name`, or any type it references, is unknown.
+    pub fn encode_type(&self, name: &str) -> crate::Result<String> {
+        let mut dependencies = BTreeSet::new();
+        self.collect_dependencies(name, &mut dependencies)?;
+        dependencies.remove(name);
+
+        let mut out = encode_type_fields(name, self.get_struct(name)?);
+
+        for dependency in dependencies {
+            out.push_str(&encode_type_fields(dependency, self.get_struct(dependency)?));
+        }
+
+        Ok(out)
+    }
+
+    /// Returns `keccak256(encode_type(name))`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `name`, or any type it references, is unknown.
+    pub fn type_hash(&self, name: &str) -> crate::Result<[u8; 32]> {
+        Ok(keccak256(self.encode_type(name)?.as_bytes()))
+    }
+
+    /// Returns `hashStruct(domain)`, the domain separator.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the domain is invalid.
+    pub fn domain_separator(&self) -> crate::Result<[u8; 32]> {
+        let (fields, values) = self.domain.fields_and_values();
+
+        // a declared domain type decides which fields are hashed, and in which order.
+        let fields = self.types.get(DOMAIN_TYPE_NAME).unwrap_or(&fields);
+
+        let type_hash = keccak256(encode_type_fields(DOMAIN_TYPE_NAME, fields).as_bytes());
+
+        self.hash_fields(type_hash, fields, &values)
+    }
+
+    /// Returns `hashStruct(message)` for the primary type.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the message doesn't match its type.
+    pub fn hash_struct(&self) -> crate::Result<[u8; 32]> {
+        self.hash_struct_value(&self.primary_type, &self.message)
+    }
+
+    /// Returns the hash to sign: `keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(message))`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the domain or message are invalid.
+    pub fn signing_hash(&self) -> crate::Result<[u8; 32]> {
+        let mut hasher = Keccak256::new();
+        hasher.update([0x19, 0x01]);
+        hasher.update(self.domain_separator()?);
+        hasher.update(self.hash_struct()?);
+
+        Ok(hasher.finalize().into())
+    }
+
+    /// Signs this typed data with an ECDSA(secp256k1) `key`.
+    ///
+    /// Returns the 65 byte signature `r ‖ s ‖ v`, with `v` being `27` or `28`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the domain or message are invalid.
+    /// - [`Error::SignatureCreate`] if `key` is not an ECDSA(secp256k1) key.
+    pub fn sign(&self, key: &PrivateKey) -> crate::Result<[u8; 65]> {
+        let (signature, recovery_id) = key.sign_prehash_recoverable(&self.signing_hash()?)?;
+
+        let mut out = [0; 65];
+        out[..64].copy_from_slice(&signature);
+        out[64] = 27 + recovery_id;
+
+        Ok(out)
+    }
+
+    /// Recovers the address of the account that created `signature` (`r ‖ s ‖ v`) over this typed data.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the domain or message are invalid.
+    /// - [`Error::SignatureVerify`] if `signature` is malformed, or no signer can be recovered from it.
+    // the only panic is for a recovered key not being ECDSA, which is impossible.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn recover_signer(&self, signature: &[u8]) -> crate::Result<EvmAddress> {
+        if signature.len() != 65 {
+            return Err(Error::signature_verify(format!(
+                "expected a 65 byte signature, got `{}` bytes",
+                signature.len()
+            )));
+        }
+
+        let mut rs = [0; 64];
+        rs.copy_from_slice(&signature[..64]);
+
+        let recovery_id = match signature[64] {
+            v @ 27..=28 => v - 27,
+            v => v,
+        };
+
+        let public_key = PublicKey::recover_from_prehash(&self.signing_hash()?, &rs, recovery_id)?;
+
+        Ok(public_key.to_evm_address().expect("recovered keys are always ECDSA keys"))
+    }
+
+    fn get_struct(&self, name: &str) -> crate::Result<&[Eip712Field]> {
+        self.types
+            .get(name)
+            .map(Vec::as_slice)
+            .ok_or_else(|| Error::basic_parse(format!("unknown EIP-712 type `{name}`")))
+    }
+
+    fn collect_dependencies<'a>(
+        &'a self,
+        name: &'a str,
+        out: &mut BTreeSet<&'a str>,
+    ) -> crate::Result<()> {
+        if !out.insert(name) {
+            return Ok(());
+        }
+
+        for field in self.get_struct(name)? {
+            let base = base_type(&field.type_name);
+            if self.types.contains_key(base) {
+                self.collect_dependencies(base, out)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn hash_struct_value(
+        &self,
+        name: &str,
+        values: &BTreeMap<String, Eip712Value>,
+    ) -> crate::Result<[u8; 32]> {
+        self.hash_fields(self.type_hash(name)?, self.get_struct(name)?, values)
+    }
+
+    fn hash_fields(
+        &self,
+        type_hash: [u8; 32],
+        fields: &[Eip712Field],
+        values: &BTreeMap<String, Eip712Value>,
+    ) -> crate::Result<[u8; 32]> {
+        let mut hasher = Keccak256::new();
+        hasher.update(type_hash);
+
+        for field in fields {
+            let value = values.get(&field.name).ok_or_else(|| {
+                Error::basic_parse(format!("missing value for EIP-712 field `{}`", field.name))
+            })?;
+
+            hasher.update(self.encode_value(&field.type_name, value)?);
+        }
+
+        Ok(hasher.finalize().into())
+    }
+
+    fn encode_value(&self, type_name: &str, value: &Eip712Value) -> crate::Result<[u8; 32]> {
+        if let Some((element_type, len)) = split_array_type(type_name)? {
+            let Eip712Value::Array(elements) = value else {
+                return Err(mismatch(type_name, value));
+            };
+
+            if let Some(len) = len.filter(|&len| len != elements.len()) {
+                return Err(Error::basic_parse(format!(
+                    "expected {len} elements for EIP-712 type `{type_name}`, got {}",
+                    elements.len()
+                )));
+            }
+
+            let mut hasher = Keccak256::new();
+            for element in elements {
+                hasher.update(self.encode_value(element_type, element)?);
+            }
+
+            return Ok(hasher.finalize().into());
+        }
+
+        if self.types.contains_key(type_name) {
+            let Eip712Value::Struct(values) = value else {
+                return Err(mismatch(type_name, value));
+            };
+
+            return self.hash_struct_value(type_name, values);
+        }
+
+        encode_atomic(type_name, value)
+    }
+}
+
+fn encode_type_fields(name: &str, fields: &[Eip712Field]) -> String {
+    let fields: Vec<_> =
+        fields.iter().map(|field| format!("{} {}", field.type_name, field.name)).collect();
+
+    format!("{name}({})", fields.join(","))
+}
+
+/// Returns the type with all array suffixes removed (`Person[][2]` -> `Person`).
+fn base_type(type_name: &str) -> &str {
+    type_name.split_once('[').map_or(type_name, |(base, _)| base)
+}
+
+/// Splits an array type into its element type and length (`Person[][2]` -> (`Person[]`, `Some(2)`)).
+fn split_array_type(type_name: &str) -> crate::Result<Option<(&str, Option<usize>)>> {
+    let Some(inner) = type_name.strip_suffix(']') else {
+        return Ok(None);
+    };
+
+    let (element_type, len) = inner
+        .rsplit_once('[')
+        .ok_or_else(|| Error::basic_parse(format!("invalid EIP-712 type `{type_name}`")))?;
+
+    let len = match len {
+        "" => None,
+        len => Some(len.parse().map_err(Error::basic_parse)?),
+    };
+
+    Ok(Some((element_type, len)))
+}
+
+fn encode_atomic(type_name: &str, value: &Eip712Value) -> crate::Result<[u8; 32]> {
+    let mut word = [0; 32];
+
+    match (type_name, value) {
+        ("bool", Eip712Value::Bool(value)) => word[31] = u8::from(*value),
+        ("address", Eip712Value::Address(address)) => word[12..].copy_from_slice(&address.0),
+        ("string", Eip712Value::String(value)) => word = keccak256(value.as_bytes()),
+        ("bytes", Eip712Value::Bytes(value)) => word = keccak256(value),
+        (_, Eip712Value::Bytes(value)) if type_name.starts_with("bytes") => {
+            let size = type_size(type_name, "bytes", 32)?;
+            if value.len() > size {
+                return Err(Error::basic_parse(format!(
+                    "value of {} bytes is too long for EIP-712 type `{type_name}`",
+                    value.len()
+                )));
+            }
+
+            word[..value.len()].copy_from_slice(value);
+        }
+        (_, Eip712Value::Uint(_) | Eip712Value::Int(_)) if type_name.starts_with("uint") => {
+            let bits = type_size(type_name, "uint", 256)?;
+            let value = match value {
+                Eip712Value::Uint(value) => value.clone(),
+                Eip712Value::Int(value) => value.to_biguint().ok_or_else(|| {
+                    Error::basic_parse(format!("negative value for EIP-712 type `{type_name}`"))
+                })?,
+                _ => unreachable!(),
+            };
+
+            if value.bits() > bits as u64 {
+                return Err(out_of_range(type_name));
+            }
+
+            let bytes = value.to_bytes_be();
+            word[32 - bytes.len()..].copy_from_slice(&bytes);
+        }
+        (_, Eip712Value::Uint(_) | Eip712Value::Int(_)) if type_name.starts_with("int") => {
+            let bits = type_size(type_name, "int", 256)?;
+            let value = match value {
+                Eip712Value::Uint(value) => BigInt::from(value.clone()),
+                Eip712Value::Int(value) => value.clone(),
+                _ => unreachable!(),
+            };
+
+            let magnitude = if value.sign() == Sign::Minus {
+                -(&value + BigInt::from(1))
+            } else {
+                value.clone()
+            };
+            if magnitude.bits() >= bits as u64 {
+                return Err(out_of_range(type_name));
+            }
+
+            if value.sign() == Sign::Minus {
+                word = [0xff; 32];
+            }
+
+            let bytes = value.to_signed_bytes_be();
+            word[32 - bytes.len()..].copy_from_slice(&bytes);
+        }
+        _ => return Err(mismatch(type_name, value)),
+    }
+
+    Ok(word)
+}
+
+/// Parses the size suffix of `type_name` (`uint64` -> 64), defaulting to `default` if it has none.
+fn type_size(type_name: &str, prefix: &str, default: usize) -> crate::Result<usize> {
+    match type_name.strip_prefix(prefix) {
+        Some("") => Ok(default),
+        Some(size) => match size.parse() {
+            Ok(size) if size > 0 && size <= default => Ok(size),
+            _ => Err(Error::basic_parse(format!("invalid EIP-712 type `{type_name}`"))),
+        },
+        None => Err(Error::basic_parse(format!("invalid EIP-712 type `{type_name}`"))),
+    }
+}
+
+fn mismatch(type_name: &str, value: &Eip712Value) -> Error {
+    Error::basic_parse(format!("value `{value:?}` doesn't match EIP-712 type `{type_name}`"))
+}
+
+fn out_of_range(type_name: &str) -> Error {
+    Error::basic_parse(format!("value is out of range for EIP-712 type `{type_name}`"))
+}
+
+fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    Keccak256::digest(bytes).into()
+}
+
+#[cfg(feature = "serde")]
+mod json {
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
+
+    use num_bigint::{
+        BigInt,
+        BigUint,
+    };
+    use serde_json::Value;
+
+    use super::{
+        base_type,
+        split_array_type,
+        Eip712Domain,
+        Eip712Field,
+        Eip712TypedData,
+        Eip712Value,
+    };
+    use crate::{
+        Error,
+        EvmAddress,
+    };
+
+    pub(super) fn parse_typed_data(json: &str) -> crate::Result<Eip712TypedData> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|e| Error::basic_parse(format!("failed to parse EIP-712 JSON: {e}")))?;
+
+        let types = value
+            .get("types")
+            .and_then(Value::as_object)
+            .ok_or_else(|| Error::basic_parse("EIP-712 JSON is missing `types`"))?;
+
+        let mut typed_data = Eip712TypedData::new(
+            parse_domain(value.get("domain"))?,
+            get_str(&value, "primaryType")?,
+        );
+
+        for (name, fields) in types {
+            let fields = fields
+                .as_array()
+                .ok_or_else(|| Error::basic_parse(format!("EIP-712 type `{name}` is not a list")))?
+                .iter()
+                .map(|field| Ok(Eip712Field::new(get_str(field, "name")?, get_str(field, "type")?)))
+                .collect::<crate::Result<Vec<_>>>()?;
+
+            typed_data.add_type(name.as_str(), fields);
+        }
+
+        let primary_type = typed_data.primary_type.clone();
+        let message = value
+            .get("message")
+            .ok_or_else(|| Error::basic_parse("EIP-712 JSON is missing `message`"))?;
+
+        let Eip712Value::Struct(message) = to_value(&typed_data, &primary_type, message)? else {
+            return Err(Error::basic_parse(format!(
+                "EIP-712 primary type `{primary_type}` is not a struct type"
+            )));
+        };
+
+        typed_data.message(message);
+
+        Ok(typed_data)
+    }
+
+    fn parse_domain(value: Option<&Value>) -> crate::Result<Eip712Domain> {
+        let Some(value) = value else {
+            return Ok(Eip712Domain::default());
+        };
+
+        let salt = match value.get("salt") {
+            Some(salt) => {
+                let salt = parse_hex(salt)?;
+                Some(salt.try_into().map_err(|_| Error::basic_parse("`salt` must be 32 bytes"))?)
+            }
+            None => None,
+        };
+
+        Ok(Eip712Domain {
+            name: value.get("name").and_then(Value::as_str).map(str::to_owned),
+            version: value.get("version").and_then(Value::as_str).map(str::to_owned),
+            chain_id: value
+                .get("chainId")
+                .map(|it| {
+                    u64::try_from(parse_uint(it)?)
+                        .map_err(|_| Error::basic_parse("`chainId` must fit in 64 bits"))
+                })
+                .transpose()?,
+            verifying_contract: value
+                .get("verifyingContract")
+                .and_then(Value::as_str)
+                .map(EvmAddress::from_str)
+                .transpose()?,
+            salt,
+        })
+    }
+
+    fn to_value(
+        typed_data: &Eip712TypedData,
+        type_name: &str,
+        value: &Value,
+    ) -> crate::Result<Eip712Value> {
+        if let Some((element_type, _)) = split_array_type(type_name)? {
+            let elements = value.as_array().ok_or_else(|| expected(type_name, value))?;
+
+            return elements
+                .iter()
+                .map(|element| to_value(typed_data, element_type, element))
+                .collect::<crate::Result<Vec<_>>>()
+                .map(Eip712Value::Array);
+        }
+
+        if let Some(fields) = typed_data.types.get(type_name) {
+            let mut values = BTreeMap::new();
+
+            for field in fields {
+                let value = value.get(&field.name).ok_or_else(|| {
+                    Error::basic_parse(format!("missing value for EIP-712 field `{}`", field.name))
+                })?;
+
+                values.insert(field.name.clone(), to_value(typed_data, &field.type_name, value)?);
+            }
+
+            return Ok(Eip712Value::Struct(values));
+        }
+
+        let value = match base_type(type_name) {
+            "bool" => Eip712Value::Bool(value.as_bool().ok_or_else(|| expected(type_name, value))?),
+            "address" => Eip712Value::Address(
+                value.as_str().ok_or_else(|| expected(type_name, value))?.parse()?,
+            ),
+            "string" => Eip712Value::String(
+                value.as_str().ok_or_else(|| expected(type_name, value))?.to_owned(),
+            ),
+            it if it.starts_with("bytes") => Eip712Value::Bytes(parse_hex(value)?),
+            it if it.starts_with("uint") => Eip712Value::Uint(parse_uint(value)?),
+            it if it.starts_with("int") => Eip712Value::Int(parse_int(value)?),
+            _ => return Err(Error::basic_parse(format!("unknown EIP-712 type `{type_name}`"))),
+        };
+
+        Ok(value)
+    }
+
+    fn parse_uint(value: &Value) -> crate::Result<BigUint> {
+        parse_int(value)?.to_biguint().ok_or_else(|| {
+            Error::basic_parse(format!("expected an unsigned integer, got `{value}`"))
+        })
+    }
+
+    fn parse_int(value: &Value) -> crate::Result<BigInt> {
+        if let Some(value) = value.as_i64() {
+            return Ok(value.into());
+        }
+
+        if let Some(value) = value.as_u64() {
+            return Ok(value.into());
+        }
+
+        let s = value
+            .as_str()
+            .ok_or_else(|| Error::basic_parse(format!("expected an integer, got `{value}`")))?;
+
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s),
+        };
+
+        let magnitude = match digits.strip_prefix("0x") {
+            Some(hex) => BigInt::parse_bytes(hex.as_bytes(), 16),
+            None => BigInt::parse_bytes(digits.as_bytes(), 10),
+        }
+        .ok_or_else(|| Error::basic_parse(format!("expected an integer, got `{s}`")))?;
+
+        Ok(if negative { -magnitude } else { magnitude })
+    }
+
+    fn parse_hex(value: &Value) -> crate::Result<Vec<u8>> {
+        let s = value
+            .as_str()
+            .ok_or_else(|| Error::basic_parse(format!("expected a hex string, got `{value}`")))?;
+
+        hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(Error::basic_parse)
+    }
+
+    fn get_str<'a>(value: &'a Value, field: &str) -> crate::Result<&'a str> {
+        value
+            .get(field)
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::basic_parse(format!("EIP-712 JSON is missing `{field}`")))
+    }
+
+    fn expected(type_name: &str, value: &Value) -> Error {
+        Error::basic_parse(format!("value `{value}` doesn't match EIP-712 type `{type_name}`"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use hex_literal::hex;
+
+    use super::{
+        Eip712Domain,
+        Eip712Field,
+        Eip712TypedData,
+        Eip712Value,
+    };
+    use crate::{
+        EvmAddress,
+        PrivateKey,
+    };
+
+    // the example from the EIP-712 specification.
+    fn mail() -> Eip712TypedData {
+        let mut typed_data = Eip712TypedData::new(
+            Eip712Domain {
+                name: Some("Ether Mail".to_owned()),
+                version: Some("1".to_owned()),
+                chain_id: Some(1),
+                verifying_contract: Some(EvmAddress([0xcc; 20])),
+                salt: None,
+            },
+            "Mail",
+        );
+
+        let person = |name: &str, wallet: [u8; 20]| {
+            Eip712Value::Struct(BTreeMap::from([
+                ("name".to_owned(), name.into()),
+                ("wallet".to_owned(), EvmAddress(wallet).into()),
+            ]))
+        };
+
+        typed_data
+            .add_type(
+                "Person",
+                [Eip712Field::new("name", "string"), Eip712Field::new("wallet", "address")],
+            )
+            .add_type(
+                "Mail",
+                [
+                    Eip712Field::new("from", "Person"),
+                    Eip712Field::new("to", "Person"),
+                    Eip712Field::new("contents", "string"),
+                ],
+            )
+            .field("from", person("Cow", hex!("cd2a3d9f938e13cd947ec05abc7fe734df8dd826")))
+            .field("to", person("Bob", hex!("bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb")))
+            .field("contents", "Hello, Bob!");
+
+        typed_data
+    }
+
+    #[test]
+    fn encode_type() {
+        assert_eq!(
+            mail().encode_type("Mail").unwrap(),
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+    }
+
+    #[test]
+    fn hashes() {
+        let typed_data = mail();
+
+        assert_eq!(
+            typed_data.domain_separator().unwrap(),
+            hex!("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f")
+        );
+        assert_eq!(
+            typed_data.hash_struct().unwrap(),
+            hex!("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e")
+        );
+        assert_eq!(
+            typed_data.signing_hash().unwrap(),
+            hex!("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2")
+        );
+    }
+
+    #[test]
+    fn sign_and_recover() {
+        // keccak256("cow")
+        let key = PrivateKey::from_bytes_ecdsa(&hex!(
+            "c85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4"
+        ))
+        .unwrap();
+
+        let typed_data = mail();
+        let signature = typed_data.sign(&key).unwrap();
+
+        assert_eq!(
+            signature,
+            hex!(
+                "4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d"
+                "07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562"
+                "1c"
+            )
+        );
+
+        assert_eq!(
+            typed_data.recover_signer(&signature).unwrap(),
+            EvmAddress(hex!("cd2a3d9f938e13cd947ec05abc7fe734df8dd826"))
+        );
+    }
+
+    #[test]
+    fn sign_with_ed25519_fails() {
+        let key = PrivateKey::generate_ed25519();
+
+        assert!(matches!(mail().sign(&key), Err(crate::Error::SignatureCreate(_))));
+    }
+
+    #[test]
+    fn missing_field_fails() {
+        let mut typed_data = mail();
+        typed_data.message(BTreeMap::new());
+
+        assert!(matches!(typed_data.hash_struct(), Err(crate::Error::BasicParse(_))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_json() {
+        let typed_data = Eip712TypedData::from_json(
+            r#"{
+                "types": {
+                    "EIP712Domain": [
+                        { "name": "name", "type": "string" },
+                        { "name": "version", "type": "string" },
+                        { "name": "chainId", "type": "uint256" },
+                        { "name": "verifyingContract", "type": "address" }
+                    ],
+                    "Person": [
+                        { "name": "name", "type": "string" },
+                        { "name": "wallet", "type": "address" }
+                    ],
+                    "Mail": [
+                        { "name": "from", "type": "Person" },
+                        { "name": "to", "type": "Person" },
+                        { "name": "contents", "type": "string" }
+                    ]
+                },
+                "primaryType": "Mail",
+                "domain": {
+                    "name": "Ether Mail",
+                    "version": "1",
+                    "chainId": 1,
+                    "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+                },
+                "message": {
+                    "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+                    "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+                    "contents": "Hello, Bob!"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(typed_data.signing_hash().unwrap(), mail().signing_hash().unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_json_declared_domain() {
+        // the declared domain type leaves out `version`, even though the domain has one.
+        let mut typed_data = Eip712TypedData::from_json(
+            r#"{
+                "types": {
+                    "EIP712Domain": [
+                        { "name": "name", "type": "string" },
+                        { "name": "chainId", "type": "uint256" }
+                    ],
+                    "Greeting": [
+                        { "name": "text", "type": "string" }
+                    ]
+                },
+                "primaryType": "Greeting",
+                "domain": { "name": "Ether Mail", "version": "1", "chainId": 1 },
+                "message": { "text": "Hello" }
+            }"#,
+        )
+        .unwrap();
+
+        let mut expected = Eip712TypedData::new(
+            Eip712Domain {
+                name: Some("Ether Mail".to_owned()),
+                chain_id: Some(1),
+                ..Default::default()
+            },
+            "Greeting",
+        );
+
+        expected.add_type("Greeting", [Eip712Field::new("text", "string")]).field("text", "Hello");
+
+        assert_eq!(typed_data.signing_hash().unwrap(), expected.signing_hash().unwrap());
+
+        typed_data.add_type(
+            "EIP712Domain",
+            [Eip712Field::new("chainId", "uint256"), Eip712Field::new("name", "string")],
+        );
+
+        assert_ne!(typed_data.domain_separator().unwrap(), expected.domain_separator().unwrap());
+
+        typed_data.add_type("EIP712Domain", [Eip712Field::new("salt", "bytes32")]);
+
+        assert!(matches!(typed_data.domain_separator(), Err(crate::Error::BasicParse(_))));
+    }
+
+    #[test]
+    fn arrays_and_integers() {
+        let mut typed_data = Eip712TypedData::new(Eip712Domain::default(), "Batch");
+
+        typed_data
+            .add_type(
+                "Batch",
+                [
+                    Eip712Field::new("amounts", "uint64[2]"),
+                    Eip712Field::new("delta", "int8"),
+                    Eip712Field::new("tag", "bytes4"),
+                ],
+            )
+            .field("amounts", vec![Eip712Value::from(1_u64), Eip712Value::from(2_u64)])
+            .field("delta", -1_i64)
+            .field("tag", vec![0xde_u8, 0xad, 0xbe, 0xef]);
+
+        assert!(typed_data.hash_struct().is_ok());
+
+        typed_data.field("delta", 128_i64);
+        assert!(typed_data.hash_struct().is_err());
+
+        typed_data.field("delta", -128_i64).field("amounts", vec![Eip712Value::from(1_u64)]);
+        assert!(typed_data.hash_struct().is_err());
+    }
+}
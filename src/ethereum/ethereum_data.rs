@@ -6,9 +6,23 @@ use bytes::{
     BufMut,
     BytesMut,
 };
-use rlp::Rlp;
+use rlp::{
+    Decodable,
+    DecoderError,
+    Encodable,
+    Rlp,
+    RlpStream,
+};
+use sha3::{
+    Digest,
+    Keccak256,
+};
 
-use crate::Error;
+use crate::{
+    Error,
+    EvmAddress,
+    PublicKey,
+};
 
 /// Data for an [`EthereumTransaction`](crate::EthereumTransaction).
 #[derive(Debug, Clone)]
@@ -19,6 +33,12 @@ pub enum EthereumData {
 
     /// Data for an Eip 1559 ethereum transaction.
     Eip1559(Eip1559EthereumData),
+
+    /// Data for an Eip 2930 (access list) ethereum transaction.
+    Eip2930(Eip2930EthereumData),
+
+    /// Data for an Eip 7702 (set code) ethereum transaction.
+    Eip7702(Eip7702EthereumData),
 }
 
 impl EthereumData {
@@ -26,17 +46,33 @@ impl EthereumData {
         match self {
             EthereumData::Legacy(it) => &mut it.call_data,
             EthereumData::Eip1559(it) => &mut it.call_data,
+            EthereumData::Eip2930(it) => &mut it.call_data,
+            EthereumData::Eip7702(it) => &mut it.call_data,
         }
     }
 
     pub(crate) fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
         match bytes.split_first() {
-            // note: eating the 2 here involves a bit of extra work.
+            // note: eating the type here involves a bit of extra work.
+            Some((1, bytes)) => Eip2930EthereumData::decode_rlp(&Rlp::new(bytes))
+                .map(Self::Eip2930)
+                .map_err(Error::basic_parse),
+
             Some((2, bytes)) => Eip1559EthereumData::decode_rlp(&Rlp::new(bytes))
                 .map(Self::Eip1559)
                 .map_err(Error::basic_parse),
 
-            Some(_) => Ok(Self::Legacy(LegacyEthereumData::from_bytes(bytes)?)),
+            Some((4, bytes)) => Eip7702EthereumData::decode_rlp(&Rlp::new(bytes))
+                .map(Self::Eip7702)
+                .map_err(Error::basic_parse),
+
+            // legacy transactions are an rlp list, which always starts with a byte >= 0xc0.
+            Some((0xc0.., _)) => Ok(Self::Legacy(LegacyEthereumData::from_bytes(bytes)?)),
+
+            Some((kind, _)) => Err(Error::basic_parse(format!(
+                "unsupported ethereum transaction type `{kind:#04x}`"
+            ))),
+
             None => Err(Error::basic_parse("Empty ethereum transaction data")),
         }
     }
@@ -47,8 +83,55 @@ impl EthereumData {
         match self {
             EthereumData::Legacy(it) => it.to_bytes(),
             EthereumData::Eip1559(it) => it.to_bytes(),
+            EthereumData::Eip2930(it) => it.to_bytes(),
+            EthereumData::Eip7702(it) => it.to_bytes(),
         }
     }
+
+    /// Recovers the address of the account that signed this transaction.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is malformed, or no signer can be recovered from it.
+    pub fn recover_sender(&self) -> crate::Result<EvmAddress> {
+        match self {
+            EthereumData::Legacy(it) => it.recover_sender(),
+            EthereumData::Eip1559(it) => it.recover_sender(),
+            EthereumData::Eip2930(it) => it.recover_sender(),
+            EthereumData::Eip7702(it) => it.recover_sender(),
+        }
+    }
+}
+
+impl From<LegacyEthereumData> for EthereumData {
+    fn from(value: LegacyEthereumData) -> Self {
+        Self::Legacy(value)
+    }
+}
+
+impl From<Eip1559EthereumData> for EthereumData {
+    fn from(value: Eip1559EthereumData) -> Self {
+        Self::Eip1559(value)
+    }
+}
+
+impl From<Eip2930EthereumData> for EthereumData {
+    fn from(value: Eip2930EthereumData) -> Self {
+        Self::Eip2930(value)
+    }
+}
+
+impl From<Eip7702EthereumData> for EthereumData {
+    fn from(value: Eip7702EthereumData) -> Self {
+        Self::Eip7702(value)
+    }
+}
+
+struct HexList<'a, T: AsRef<[u8]>>(&'a [T]);
+
+impl<'a, T: AsRef<[u8]>> fmt::Debug for HexList<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter().map(hex::encode)).finish()
+    }
 }
 
 /// Data for a legacy ethereum transaction.
@@ -147,6 +230,58 @@ impl LegacyEthereumData {
 
         rlp.out().to_vec()
     }
+
+    /// Returns the chain ID this transaction was signed for,
+    /// or `None` if it isn't replay protected ([EIP-155](https://eips.ethereum.org/EIPS/eip-155)) or isn't signed.
+    #[must_use]
+    pub fn chain_id(&self) -> Option<u64> {
+        match decode_uint(&self.v)? {
+            v @ 35.. => Some((v - 35) / 2),
+            _ => None,
+        }
+    }
+
+    /// Returns the hash that's signed to produce `v`, `r`, and `s`.
+    ///
+    /// With a `chain_id` this is the [EIP-155](https://eips.ethereum.org/EIPS/eip-155) signing hash,
+    /// without one it's the original (unprotected) signing hash.
+    pub(super) fn signing_hash(&self, chain_id: Option<u64>) -> [u8; 32] {
+        let mut rlp = RlpStream::new_list(if chain_id.is_some() { 9 } else { 6 });
+
+        rlp.append(&self.nonce)
+            .append(&self.gas_price)
+            .append(&self.gas_limit)
+            .append(&self.to)
+            .append(&self.value)
+            .append(&self.call_data);
+
+        if let Some(chain_id) = chain_id {
+            rlp.append(&encode_uint(chain_id.into())).append_empty_data().append_empty_data();
+        }
+
+        Keccak256::digest(rlp.out()).into()
+    }
+
+    /// Recovers the address of the account that signed this transaction.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is malformed, or no signer can be recovered from it.
+    pub fn recover_sender(&self) -> crate::Result<EvmAddress> {
+        let (chain_id, recovery_id) = match decode_uint(&self.v) {
+            Some(v @ 27..=28) => (None, v - 27),
+            Some(v @ 35..) => (Some((v - 35) / 2), (v - 35) % 2),
+            _ => {
+                return Err(Error::signature_verify(format!(
+                    "invalid `v` value `{}`",
+                    hex::encode(&self.v)
+                )))
+            }
+        };
+
+        // `recovery_id` is either `0` or `1`.
+        #[allow(clippy::cast_possible_truncation)]
+        recover_address(&self.signing_hash(chain_id), &self.r, &self.s, recovery_id as u8)
+    }
 }
 
 /// Data for an Eip 1559 ethereum transaction.
@@ -180,7 +315,7 @@ pub struct Eip1559EthereumData {
     pub call_data: Vec<u8>,
 
     /// Specifies an array of addresses and storage keys that the transaction plans to access.
-    pub access_list: Vec<Vec<u8>>,
+    pub access_list: Vec<EthereumAccessListItem>,
 
     /// Recovery parameter used to ease the signature verification.
     pub recovery_id: Vec<u8>,
@@ -195,14 +330,6 @@ pub struct Eip1559EthereumData {
 // manual impl of debug for the hex encoding of everything.
 impl fmt::Debug for Eip1559EthereumData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        struct HexList<'a, T: AsRef<[u8]>>(&'a [T]);
-
-        impl<'a, T: AsRef<[u8]>> fmt::Debug for HexList<'a, T> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.debug_list().entries(self.0.iter().map(hex::encode)).finish()
-            }
-        }
-
         let Self {
             chain_id,
             nonce,
@@ -227,7 +354,7 @@ impl fmt::Debug for Eip1559EthereumData {
             .field("to", &hex::encode(to))
             .field("value", &hex::encode(value))
             .field("call_data", &hex::encode(call_data))
-            .field("access_list", &HexList(access_list))
+            .field("access_list", access_list)
             .field("recovery_id", &hex::encode(recovery_id))
             .field("r", &hex::encode(r))
             .field("s", &hex::encode(s))
@@ -288,13 +415,550 @@ impl Eip1559EthereumData {
             .append(&self.to)
             .append(&self.value)
             .append(&self.call_data)
-            .append_list::<Vec<_>, _>(self.access_list.as_slice())
+            .append_list(&self.access_list)
             .append(&self.recovery_id)
             .append(&self.r)
             .append(&self.s);
 
         rlp.out().to_vec()
     }
+
+    /// Returns the hash that's signed to produce `recovery_id`, `r`, and `s`.
+    pub(super) fn signing_hash(&self) -> [u8; 32] {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(0x02);
+        let mut rlp = RlpStream::new_list_with_buffer(buffer, 9);
+
+        rlp.append(&self.chain_id)
+            .append(&self.nonce)
+            .append(&self.max_priority_gas)
+            .append(&self.max_gas)
+            .append(&self.gas_limit)
+            .append(&self.to)
+            .append(&self.value)
+            .append(&self.call_data)
+            .append_list(&self.access_list);
+
+        Keccak256::digest(rlp.out()).into()
+    }
+
+    /// Recovers the address of the account that signed this transaction.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is malformed, or no signer can be recovered from it.
+    pub fn recover_sender(&self) -> crate::Result<EvmAddress> {
+        recover_address(
+            &self.signing_hash(),
+            &self.r,
+            &self.s,
+            parse_recovery_id(&self.recovery_id)?,
+        )
+    }
+}
+
+/// An address and the storage keys a transaction plans to access in it,
+/// see [EIP-2930](https://eips.ethereum.org/EIPS/eip-2930).
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct EthereumAccessListItem {
+    /// The address of the account or contract.
+    pub address: Vec<u8>,
+
+    /// The storage keys.
+    pub storage_keys: Vec<Vec<u8>>,
+}
+
+impl fmt::Debug for EthereumAccessListItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EthereumAccessListItem")
+            .field("address", &hex::encode(&self.address))
+            .field("storage_keys", &HexList(&self.storage_keys))
+            .finish()
+    }
+}
+
+impl Encodable for EthereumAccessListItem {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(2).append(&self.address).append_list::<Vec<_>, _>(&self.storage_keys);
+    }
+}
+
+impl Decodable for EthereumAccessListItem {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        if rlp.item_count()? != 2 {
+            return Err(DecoderError::RlpIncorrectListLen);
+        }
+
+        Ok(Self { address: rlp.val_at(0)?, storage_keys: rlp.list_at(1)? })
+    }
+}
+
+/// Authorization for an account to run the code of `address`,
+/// see [EIP-7702](https://eips.ethereum.org/EIPS/eip-7702).
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Eip7702Authorization {
+    /// ID of the chain the authorization is valid on, or empty (zero) for every chain.
+    pub chain_id: Vec<u8>,
+
+    /// The address of the code the account delegates to.
+    pub address: Vec<u8>,
+
+    /// The nonce of the account.
+    pub nonce: Vec<u8>,
+
+    /// The y parity (recovery ID) of the signature.
+    pub y_parity: Vec<u8>,
+
+    /// The R value of the signature.
+    pub r: Vec<u8>,
+
+    /// The S value of the signature.
+    pub s: Vec<u8>,
+}
+
+impl fmt::Debug for Eip7702Authorization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { chain_id, address, nonce, y_parity, r, s } = self;
+
+        f.debug_struct("Eip7702Authorization")
+            .field("chain_id", &hex::encode(chain_id))
+            .field("address", &hex::encode(address))
+            .field("nonce", &hex::encode(nonce))
+            .field("y_parity", &hex::encode(y_parity))
+            .field("r", &hex::encode(r))
+            .field("s", &hex::encode(s))
+            .finish()
+    }
+}
+
+impl Encodable for Eip7702Authorization {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(6)
+            .append(&self.chain_id)
+            .append(&self.address)
+            .append(&self.nonce)
+            .append(&self.y_parity)
+            .append(&self.r)
+            .append(&self.s);
+    }
+}
+
+impl Decodable for Eip7702Authorization {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        if rlp.item_count()? != 6 {
+            return Err(DecoderError::RlpIncorrectListLen);
+        }
+
+        Ok(Self {
+            chain_id: rlp.val_at(0)?,
+            address: rlp.val_at(1)?,
+            nonce: rlp.val_at(2)?,
+            y_parity: rlp.val_at(3)?,
+            r: rlp.val_at(4)?,
+            s: rlp.val_at(5)?,
+        })
+    }
+}
+
+impl Eip7702Authorization {
+    /// Returns the hash that's signed to produce `y_parity`, `r`, and `s`.
+    pub(super) fn signing_hash(&self) -> [u8; 32] {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(0x05);
+        let mut rlp = RlpStream::new_list_with_buffer(buffer, 3);
+
+        rlp.append(&self.chain_id).append(&self.address).append(&self.nonce);
+
+        Keccak256::digest(rlp.out()).into()
+    }
+
+    /// Recovers the address of the account that signed this authorization (the account that delegates its code).
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is malformed, or no signer can be recovered from it.
+    pub fn recover_authority(&self) -> crate::Result<EvmAddress> {
+        recover_address(&self.signing_hash(), &self.r, &self.s, parse_recovery_id(&self.y_parity)?)
+    }
+}
+
+/// Data for an Eip 2930 (access list) ethereum transaction.
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct Eip2930EthereumData {
+    /// ID of the chain.
+    pub chain_id: Vec<u8>,
+
+    /// Transaction's nonce.
+    pub nonce: Vec<u8>,
+
+    /// Price for 1 gas.
+    pub gas_price: Vec<u8>,
+
+    /// The amount of gas available for the transaction.
+    pub gas_limit: Vec<u8>,
+
+    /// The receiver of the transaction.
+    pub to: Vec<u8>,
+
+    /// The transaction value.
+    pub value: Vec<u8>,
+
+    /// The raw call data.
+    pub call_data: Vec<u8>,
+
+    /// The addresses and storage keys that the transaction plans to access.
+    pub access_list: Vec<EthereumAccessListItem>,
+
+    /// Recovery parameter used to ease the signature verification.
+    pub recovery_id: Vec<u8>,
+
+    /// The R value of the signature.
+    pub r: Vec<u8>,
+
+    /// The S value of the signature.
+    pub s: Vec<u8>,
+}
+
+// manual impl of debug for the hex encoding of everything.
+impl fmt::Debug for Eip2930EthereumData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            chain_id,
+            nonce,
+            gas_price,
+            gas_limit,
+            to,
+            value,
+            call_data,
+            access_list,
+            recovery_id,
+            r,
+            s,
+        } = self;
+
+        f.debug_struct("Eip2930EthereumData")
+            .field("chain_id", &hex::encode(chain_id))
+            .field("nonce", &hex::encode(nonce))
+            .field("gas_price", &hex::encode(gas_price))
+            .field("gas_limit", &hex::encode(gas_limit))
+            .field("to", &hex::encode(to))
+            .field("value", &hex::encode(value))
+            .field("call_data", &hex::encode(call_data))
+            .field("access_list", access_list)
+            .field("recovery_id", &hex::encode(recovery_id))
+            .field("r", &hex::encode(r))
+            .field("s", &hex::encode(s))
+            .finish()
+    }
+}
+
+impl Eip2930EthereumData {
+    fn decode_rlp(rlp: &Rlp) -> Result<Self, rlp::DecoderError> {
+        if rlp.item_count()? != 11 {
+            return Err(rlp::DecoderError::RlpIncorrectListLen);
+        }
+
+        Ok(Self {
+            chain_id: rlp.val_at(0)?,
+            nonce: rlp.val_at(1)?,
+            gas_price: rlp.val_at(2)?,
+            gas_limit: rlp.val_at(3)?,
+            to: rlp.val_at(4)?,
+            value: rlp.val_at(5)?,
+            call_data: rlp.val_at(6)?,
+            access_list: rlp.list_at(7)?,
+            recovery_id: rlp.val_at(8)?,
+            r: rlp.val_at(9)?,
+            s: rlp.val_at(10)?,
+        })
+    }
+
+    /// Deserialize this data from rlp encoded bytes.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if decoding the bytes fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let (&first, bytes) = bytes
+            .split_first()
+            .ok_or_else(|| Error::basic_parse("Empty ethereum transaction data"))?;
+
+        if first != 1 {
+            return Err(Error::basic_parse(rlp::DecoderError::Custom("Invalid kind")));
+        }
+
+        Self::decode_rlp(&Rlp::new(bytes)).map_err(Error::basic_parse)
+    }
+
+    fn append_unsigned(&self, rlp: &mut RlpStream) {
+        rlp.append(&self.chain_id)
+            .append(&self.nonce)
+            .append(&self.gas_price)
+            .append(&self.gas_limit)
+            .append(&self.to)
+            .append(&self.value)
+            .append(&self.call_data)
+            .append_list(&self.access_list);
+    }
+
+    /// Convert this data to rlp encoded bytes.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(0x01);
+        let mut rlp = RlpStream::new_list_with_buffer(buffer, 11);
+
+        self.append_unsigned(&mut rlp);
+        rlp.append(&self.recovery_id).append(&self.r).append(&self.s);
+
+        rlp.out().to_vec()
+    }
+
+    /// Returns the hash that's signed to produce `recovery_id`, `r`, and `s`.
+    pub(super) fn signing_hash(&self) -> [u8; 32] {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(0x01);
+        let mut rlp = RlpStream::new_list_with_buffer(buffer, 8);
+
+        self.append_unsigned(&mut rlp);
+
+        Keccak256::digest(rlp.out()).into()
+    }
+
+    /// Recovers the address of the account that signed this transaction.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is malformed, or no signer can be recovered from it.
+    pub fn recover_sender(&self) -> crate::Result<EvmAddress> {
+        recover_address(
+            &self.signing_hash(),
+            &self.r,
+            &self.s,
+            parse_recovery_id(&self.recovery_id)?,
+        )
+    }
+}
+
+/// Data for an Eip 7702 (set code) ethereum transaction.
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct Eip7702EthereumData {
+    /// ID of the chain.
+    pub chain_id: Vec<u8>,
+
+    /// Transaction's nonce.
+    pub nonce: Vec<u8>,
+
+    /// An 'optional' additional fee in Ethereum that is paid directly to miners in order to incentivize
+    /// them to include your transaction in a block. Not used in Hiero.
+    pub max_priority_gas: Vec<u8>,
+
+    /// The maximum amount, in tinybars, that the payer of the hedera transaction
+    /// is willing to pay to complete the transaction.
+    pub max_gas: Vec<u8>,
+
+    /// The amount of gas available for the transaction.
+    pub gas_limit: Vec<u8>,
+
+    /// The receiver of the transaction.
+    pub to: Vec<u8>,
+
+    /// The transaction value.
+    pub value: Vec<u8>,
+
+    /// The raw call data.
+    pub call_data: Vec<u8>,
+
+    /// The addresses and storage keys that the transaction plans to access.
+    pub access_list: Vec<EthereumAccessListItem>,
+
+    /// The authorizations for accounts to run the code of other addresses.
+    pub authorization_list: Vec<Eip7702Authorization>,
+
+    /// Recovery parameter used to ease the signature verification.
+    pub recovery_id: Vec<u8>,
+
+    /// The R value of the signature.
+    pub r: Vec<u8>,
+
+    /// The S value of the signature.
+    pub s: Vec<u8>,
+}
+
+// manual impl of debug for the hex encoding of everything.
+impl fmt::Debug for Eip7702EthereumData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            chain_id,
+            nonce,
+            max_priority_gas,
+            max_gas,
+            gas_limit,
+            to,
+            value,
+            call_data,
+            access_list,
+            authorization_list,
+            recovery_id,
+            r,
+            s,
+        } = self;
+
+        f.debug_struct("Eip7702EthereumData")
+            .field("chain_id", &hex::encode(chain_id))
+            .field("nonce", &hex::encode(nonce))
+            .field("max_priority_gas", &hex::encode(max_priority_gas))
+            .field("max_gas", &hex::encode(max_gas))
+            .field("gas_limit", &hex::encode(gas_limit))
+            .field("to", &hex::encode(to))
+            .field("value", &hex::encode(value))
+            .field("call_data", &hex::encode(call_data))
+            .field("access_list", access_list)
+            .field("authorization_list", authorization_list)
+            .field("recovery_id", &hex::encode(recovery_id))
+            .field("r", &hex::encode(r))
+            .field("s", &hex::encode(s))
+            .finish()
+    }
+}
+
+impl Eip7702EthereumData {
+    fn decode_rlp(rlp: &Rlp) -> Result<Self, rlp::DecoderError> {
+        if rlp.item_count()? != 13 {
+            return Err(rlp::DecoderError::RlpIncorrectListLen);
+        }
+
+        Ok(Self {
+            chain_id: rlp.val_at(0)?,
+            nonce: rlp.val_at(1)?,
+            max_priority_gas: rlp.val_at(2)?,
+            max_gas: rlp.val_at(3)?,
+            gas_limit: rlp.val_at(4)?,
+            to: rlp.val_at(5)?,
+            value: rlp.val_at(6)?,
+            call_data: rlp.val_at(7)?,
+            access_list: rlp.list_at(8)?,
+            authorization_list: rlp.list_at(9)?,
+            recovery_id: rlp.val_at(10)?,
+            r: rlp.val_at(11)?,
+            s: rlp.val_at(12)?,
+        })
+    }
+
+    /// Deserialize this data from rlp encoded bytes.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if decoding the bytes fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let (&first, bytes) = bytes
+            .split_first()
+            .ok_or_else(|| Error::basic_parse("Empty ethereum transaction data"))?;
+
+        if first != 4 {
+            return Err(Error::basic_parse(rlp::DecoderError::Custom("Invalid kind")));
+        }
+
+        Self::decode_rlp(&Rlp::new(bytes)).map_err(Error::basic_parse)
+    }
+
+    fn append_unsigned(&self, rlp: &mut RlpStream) {
+        rlp.append(&self.chain_id)
+            .append(&self.nonce)
+            .append(&self.max_priority_gas)
+            .append(&self.max_gas)
+            .append(&self.gas_limit)
+            .append(&self.to)
+            .append(&self.value)
+            .append(&self.call_data)
+            .append_list(&self.access_list)
+            .append_list(&self.authorization_list);
+    }
+
+    /// Convert this data to rlp encoded bytes.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(0x04);
+        let mut rlp = RlpStream::new_list_with_buffer(buffer, 13);
+
+        self.append_unsigned(&mut rlp);
+        rlp.append(&self.recovery_id).append(&self.r).append(&self.s);
+
+        rlp.out().to_vec()
+    }
+
+    /// Returns the hash that's signed to produce `recovery_id`, `r`, and `s`.
+    pub(super) fn signing_hash(&self) -> [u8; 32] {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(0x04);
+        let mut rlp = RlpStream::new_list_with_buffer(buffer, 10);
+
+        self.append_unsigned(&mut rlp);
+
+        Keccak256::digest(rlp.out()).into()
+    }
+
+    /// Recovers the address of the account that signed this transaction.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is malformed, or no signer can be recovered from it.
+    pub fn recover_sender(&self) -> crate::Result<EvmAddress> {
+        recover_address(
+            &self.signing_hash(),
+            &self.r,
+            &self.s,
+            parse_recovery_id(&self.recovery_id)?,
+        )
+    }
+}
+
+/// Encodes `value` as an RLP integer: big endian, with no leading zeros.
+pub(super) fn encode_uint(value: u128) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let leading_zeros = bytes.iter().take_while(|&&it| it == 0).count();
+
+    bytes[leading_zeros..].to_vec()
+}
+
+/// Decodes a big endian RLP integer, returning `None` if it doesn't fit in a `u64`.
+fn decode_uint(bytes: &[u8]) -> Option<u64> {
+    let leading_zeros = bytes.iter().take_while(|&&it| it == 0).count();
+    let bytes = &bytes[leading_zeros..];
+
+    if bytes.len() > 8 {
+        return None;
+    }
+
+    Some(bytes.iter().fold(0, |acc, &it| (acc << 8) | u64::from(it)))
+}
+
+fn parse_recovery_id(recovery_id: &[u8]) -> crate::Result<u8> {
+    match decode_uint(recovery_id) {
+        // `0` or `1`
+        #[allow(clippy::cast_possible_truncation)]
+        Some(it @ 0..=1) => Ok(it as u8),
+        _ => Err(Error::signature_verify(format!(
+            "invalid recovery id `{}`",
+            hex::encode(recovery_id)
+        ))),
+    }
+}
+
+fn recover_address(
+    prehash: &[u8; 32],
+    r: &[u8],
+    s: &[u8],
+    recovery_id: u8,
+) -> crate::Result<EvmAddress> {
+    if r.len() > 32 || s.len() > 32 {
+        return Err(Error::signature_verify("signature `r` and `s` must be at most 32 bytes"));
+    }
+
+    let mut signature = [0; 64];
+    signature[(32 - r.len())..32].copy_from_slice(r);
+    signature[(64 - s.len())..].copy_from_slice(s);
+
+    let public_key = PublicKey::recover_from_prehash(prehash, &signature, recovery_id)?;
+
+    Ok(public_key.to_evm_address().expect("recovered keys are always ECDSA keys"))
 }
 
 #[cfg(test)]
@@ -302,7 +966,17 @@ mod test {
     use expect_test::expect;
     use hex_literal::hex;
 
-    use crate::ethereum::EthereumData;
+    use crate::ethereum::{
+        Eip2930EthereumData,
+        Eip7702Authorization,
+        Eip7702EthereumData,
+        EthereumAccessListItem,
+        EthereumData,
+    };
+    use crate::{
+        EvmAddress,
+        PrivateKey,
+    };
     // https://github.com/hashgraph/hedera-services/blob/1e01d9c6b8923639b41359c55413640b589c4ec7/hapi-utils/src/test/java/com/hedera/services/ethereum/EthTxDataTest.java#L49
     const RAW_TX_TYPE_0: &[u8]  =
         &hex!("f864012f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc18180827653820277a0f9fbff985d374be4a55f296915002eec11ac96f1ce2df183adf992baa9390b2fa00c1e867cc960d9c74ec2e6a662b7908ec4c8cc9f3091e886bcefbeb2290fb792");
@@ -310,6 +984,23 @@ mod test {
     const RAW_TX_TYPE_2: &[u8] =
         &hex!("02f87082012a022f2f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181880de0b6b3a764000083123456c001a0df48f2efd10421811de2bfb125ab75b2d3c44139c4642837fb1fccce911fd479a01aaf7ae92bee896651dfc9d99ae422a296bf5d9f1ca49b2d96d82b79eb112d66");
 
+    // `signedEip2718Tx` from https://github.com/ethereum/go-ethereum/blob/v1.13.0/core/types/transaction_test.go,
+    // its signature is arbitrary, so it's only checked against geth's signing hash.
+    const RAW_TX_TYPE_1: &[u8] =
+        &hex!("01f8630103018261a894b94f5374fce5edbc8e2a8697c15331677e6ebf0b0a825544c001a0c9519f4f2b30335884581971573fadf60c6204f59a911df35ee8a540456b2660a032f1e8e2c5dd761f9e4f88f41c8310aeaba26a8bfcdacfedfa12ec3862d37521");
+
+    // signed by the EIP-155 example key (`0x4646…46`) with a standalone implementation of EIP-2930, EIP-1559 and EIP-7702,
+    // every one of them with the access list from `access_list()`.
+    const RAW_TX_TYPE_1_ACCESS_LIST: &[u8] =
+        &hex!("01f8c382012a022f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc1818083123456f85bf859947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181f842a00000000000000000000000000000000000000000000000000000000000000000a0010101010101010101010101010101010101010101010101010101010101010180a00bd2df26981b34e19e16c8a2c2621c322197e46da615576d9dabd6a29eba3df7a073850d1fd5f2e107f70b749c9b9cacefd42f5afe1f6686f2f29f29b391373bad");
+
+    const RAW_TX_TYPE_2_ACCESS_LIST: &[u8] =
+        &hex!("02f8c482012a022f2f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc1818083123456f85bf859947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181f842a00000000000000000000000000000000000000000000000000000000000000000a0010101010101010101010101010101010101010101010101010101010101010180a0824af185ec4e32c2dd8a2dc4ba6af2716fd9985ec18fccdee8e2fc1ce9f3e56da005dad86aa45d445211847e5dff54cca57cc18de73217121a4f4311a5c0f1ec84");
+
+    // also has one authorization, for `0x…03ea` with nonce `3`, valid on every chain.
+    const RAW_TX_TYPE_4: &[u8] =
+        &hex!("04f9012282012a022f2f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc1818083123456f85bf859947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181f842a00000000000000000000000000000000000000000000000000000000000000000a00101010101010101010101010101010101010101010101010101010101010101f85cf85a809400000000000000000000000000000000000003ea0301a0841a7437b6bb45148ad3af9f06cbd7250ee48c0f978fa1b571974764b856ba7ca05779a9d969eddfb7225a4c3147864347edf571be0bc7234f4ce7ecd66d54435380a0ee77111a9d800ee3638d4c50faf980d648a0d760394a7ae39c30cccdb2f855c0a008c39ee6b613b01b8e1cffc4f6598a87f8c432b8fe6fab9bb5e6395ce288e45b");
+
     #[test]
     fn legacy_to_from_bytes() {
         let data = EthereumData::from_bytes(RAW_TX_TYPE_0).unwrap();
@@ -362,4 +1053,173 @@ mod test {
         "#]]
         .assert_debug_eq(&data);
     }
+
+    #[test]
+    fn recover_sender() {
+        const SENDER: EvmAddress = EvmAddress(hex!("a94f5374fce5edbc8e2a8697c15331677e6ebf0b"));
+
+        let legacy = EthereumData::from_bytes(RAW_TX_TYPE_0).unwrap();
+        let eip1559 = EthereumData::from_bytes(RAW_TX_TYPE_2).unwrap();
+
+        assert_eq!(legacy.recover_sender().unwrap(), SENDER);
+        assert_eq!(eip1559.recover_sender().unwrap(), SENDER);
+
+        let EthereumData::Legacy(legacy) = legacy else { unreachable!() };
+        assert_eq!(legacy.chain_id(), Some(298));
+    }
+
+    fn key() -> PrivateKey {
+        PrivateKey::from_bytes_ecdsa(&[0x46; 32]).unwrap()
+    }
+
+    fn access_list() -> Vec<EthereumAccessListItem> {
+        Vec::from([EthereumAccessListItem {
+            address: hex!("7e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181").to_vec(),
+            storage_keys: Vec::from([[0; 32].to_vec(), [1; 32].to_vec()]),
+        }])
+    }
+
+    // splits a recoverable signature into the rlp `(recovery_id, r, s)` triple.
+    fn sign(prehash: &[u8; 32]) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+        let (signature, recovery_id) = key().sign_prehash_recoverable(prehash).unwrap();
+
+        let strip = |it: &[u8]| it[it.iter().take_while(|&&it| it == 0).count()..].to_vec();
+
+        (super::encode_uint(recovery_id.into()), strip(&signature[..32]), strip(&signature[32..]))
+    }
+
+    #[test]
+    fn eip2930_to_from_bytes() {
+        let mut data = Eip2930EthereumData {
+            chain_id: hex!("012a").to_vec(),
+            nonce: hex!("02").to_vec(),
+            gas_price: hex!("2f").to_vec(),
+            gas_limit: hex!("018000").to_vec(),
+            to: hex!("7e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181").to_vec(),
+            value: Vec::new(),
+            call_data: hex!("123456").to_vec(),
+            access_list: access_list(),
+            recovery_id: Vec::new(),
+            r: Vec::new(),
+            s: Vec::new(),
+        };
+
+        (data.recovery_id, data.r, data.s) = sign(&data.signing_hash());
+
+        let bytes = data.to_bytes();
+        assert_eq!(bytes[0], 0x01);
+
+        let data = EthereumData::from_bytes(&bytes).unwrap();
+        assert_eq!(hex::encode(&bytes), hex::encode(data.to_bytes()));
+        assert_eq!(data.recover_sender().unwrap(), key().public_key().to_evm_address().unwrap());
+
+        let EthereumData::Eip2930(data) = data else { panic!("expected an Eip2930 transaction") };
+
+        assert_eq!(data.access_list, access_list());
+        assert_eq!(data.call_data, hex!("123456"));
+    }
+
+    #[test]
+    fn eip7702_to_from_bytes() {
+        let mut authorization = Eip7702Authorization {
+            chain_id: Vec::new(),
+            address: hex!("00000000000000000000000000000000000003ea").to_vec(),
+            nonce: hex!("03").to_vec(),
+            ..Default::default()
+        };
+
+        (authorization.y_parity, authorization.r, authorization.s) =
+            sign(&authorization.signing_hash());
+
+        let mut data = Eip7702EthereumData {
+            chain_id: hex!("012a").to_vec(),
+            nonce: hex!("02").to_vec(),
+            max_priority_gas: hex!("2f").to_vec(),
+            max_gas: hex!("2f").to_vec(),
+            gas_limit: hex!("018000").to_vec(),
+            to: hex!("7e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181").to_vec(),
+            value: Vec::new(),
+            call_data: hex!("123456").to_vec(),
+            access_list: access_list(),
+            authorization_list: Vec::from([authorization.clone()]),
+            recovery_id: Vec::new(),
+            r: Vec::new(),
+            s: Vec::new(),
+        };
+
+        (data.recovery_id, data.r, data.s) = sign(&data.signing_hash());
+
+        let bytes = data.to_bytes();
+        assert_eq!(bytes[0], 0x04);
+
+        let mut data = EthereumData::from_bytes(&bytes).unwrap();
+        assert_eq!(hex::encode(&bytes), hex::encode(data.to_bytes()));
+
+        let sender = key().public_key().to_evm_address().unwrap();
+        assert_eq!(data.recover_sender().unwrap(), sender);
+
+        // the flow moves large call data into a file.
+        assert_eq!(std::mem::take(data.call_data_mut()), hex!("123456"));
+
+        let EthereumData::Eip7702(data) = data else { panic!("expected an Eip7702 transaction") };
+
+        assert!(data.call_data.is_empty());
+        assert_eq!(data.authorization_list, [authorization]);
+        assert_eq!(data.authorization_list[0].recover_authority().unwrap(), sender);
+    }
+
+    #[test]
+    fn eip2930_geth_vector() {
+        let data = EthereumData::from_bytes(RAW_TX_TYPE_1).unwrap();
+        assert_eq!(hex::encode(RAW_TX_TYPE_1), hex::encode(data.to_bytes()));
+
+        let EthereumData::Eip2930(data) = data else { panic!("expected an Eip2930 transaction") };
+
+        assert_eq!(
+            data.signing_hash(),
+            hex!("49b486f0ec0a60dfbbca2d30cb07c9e8ffb2a2ff41f29a1ab6737475f6ff69f3")
+        );
+        assert_eq!(data.chain_id, [1]);
+        assert_eq!(data.call_data, hex!("5544"));
+        assert!(data.access_list.is_empty());
+    }
+
+    #[test]
+    fn access_list_vectors() {
+        const SENDER: EvmAddress = EvmAddress(hex!("9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f"));
+
+        for bytes in [RAW_TX_TYPE_1_ACCESS_LIST, RAW_TX_TYPE_2_ACCESS_LIST, RAW_TX_TYPE_4] {
+            let data = EthereumData::from_bytes(bytes).unwrap();
+
+            assert_eq!(hex::encode(bytes), hex::encode(data.to_bytes()));
+            assert_eq!(data.recover_sender().unwrap(), SENDER);
+
+            let decoded = match &data {
+                EthereumData::Eip2930(it) => &it.access_list,
+                EthereumData::Eip1559(it) => &it.access_list,
+                EthereumData::Eip7702(it) => &it.access_list,
+                EthereumData::Legacy(_) => panic!("expected a typed transaction"),
+            };
+
+            assert_eq!(*decoded, access_list());
+        }
+
+        let EthereumData::Eip7702(data) = EthereumData::from_bytes(RAW_TX_TYPE_4).unwrap() else {
+            panic!("expected an Eip7702 transaction")
+        };
+
+        let [authorization] = data.authorization_list.as_slice() else {
+            panic!("expected one authorization")
+        };
+
+        assert!(authorization.chain_id.is_empty());
+        assert_eq!(authorization.address, hex!("00000000000000000000000000000000000003ea"));
+        assert_eq!(authorization.recover_authority().unwrap(), SENDER);
+    }
+
+    #[test]
+    fn unsupported_type() {
+        assert!(EthereumData::from_bytes(&hex!("03c0")).is_err());
+        assert!(EthereumData::from_bytes(&[]).is_err());
+    }
 }
@@ -0,0 +1,399 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::ethereum_data::encode_uint;
+use crate::{
+    Eip1559EthereumData,
+    EthereumAccessListItem,
+    EvmAddress,
+    LegacyEthereumData,
+    PrivateKey,
+};
+
+/// Builds a signed [`LegacyEthereumData`].
+///
+/// When a chain ID is set the transaction is replay protected with [EIP-155](https://eips.ethereum.org/EIPS/eip-155).
+///
+/// # Examples
+/// ```
+/// # fn main() -> hiero_sdk::Result<()> {
+/// use hiero_sdk::{LegacyEthereumDataBuilder, PrivateKey};
+///
+/// let key = PrivateKey::generate_ecdsa();
+///
+/// let data = LegacyEthereumDataBuilder::new()
+///     .chain_id(296)
+///     .nonce(0)
+///     .gas_price(710_000_000_000)
+///     .gas_limit(100_000)
+///     .to("0x000000000000000000000000000000000000041a".parse()?)
+///     .sign(&key)?;
+///
+/// assert_eq!(data.recover_sender()?, key.public_key().to_evm_address().unwrap());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LegacyEthereumDataBuilder {
+    chain_id: Option<u64>,
+    nonce: u64,
+    gas_price: u128,
+    gas_limit: u64,
+    to: Option<EvmAddress>,
+    value: u128,
+    call_data: Vec<u8>,
+}
+
+impl LegacyEthereumDataBuilder {
+    /// Creates a new builder, with every field set to zero (or empty).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the chain ID to sign the transaction for.
+    pub fn chain_id(&mut self, chain_id: u64) -> &mut Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Sets the transaction's nonce.
+    pub fn nonce(&mut self, nonce: u64) -> &mut Self {
+        self.nonce = nonce;
+        self
+    }
+
+    /// Sets the price for 1 gas, in weibars.
+    pub fn gas_price(&mut self, gas_price: u128) -> &mut Self {
+        self.gas_price = gas_price;
+        self
+    }
+
+    /// Sets the amount of gas available for the transaction.
+    pub fn gas_limit(&mut self, gas_limit: u64) -> &mut Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    /// Sets the receiver of the transaction.
+    ///
+    /// Leave this unset to deploy a contract with `call_data` as its init code.
+    pub fn to(&mut self, to: EvmAddress) -> &mut Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sets the transaction value, in weibars.
+    pub fn value(&mut self, value: u128) -> &mut Self {
+        self.value = value;
+        self
+    }
+
+    /// Sets the raw call data.
+    pub fn call_data(&mut self, call_data: Vec<u8>) -> &mut Self {
+        self.call_data = call_data;
+        self
+    }
+
+    /// Signs the transaction with `key`, filling in `v`, `r`, and `s`.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureCreate`](crate::Error::SignatureCreate) if `key` isn't an ECDSA(secp256k1) key.
+    pub fn sign(&self, key: &PrivateKey) -> crate::Result<LegacyEthereumData> {
+        let mut data = LegacyEthereumData {
+            nonce: encode_uint(self.nonce.into()),
+            gas_price: encode_uint(self.gas_price),
+            gas_limit: encode_uint(self.gas_limit.into()),
+            to: self.to.map(|it| it.to_bytes().to_vec()).unwrap_or_default(),
+            value: encode_uint(self.value),
+            v: Vec::new(),
+            call_data: self.call_data.clone(),
+            r: Vec::new(),
+            s: Vec::new(),
+        };
+
+        let (signature, recovery_id) =
+            key.sign_prehash_recoverable(&data.signing_hash(self.chain_id))?;
+
+        let v = match self.chain_id {
+            Some(chain_id) => u128::from(chain_id) * 2 + 35 + u128::from(recovery_id),
+            None => 27 + u128::from(recovery_id),
+        };
+
+        data.v = encode_uint(v);
+        data.r = strip_leading_zeros(&signature[..32]);
+        data.s = strip_leading_zeros(&signature[32..]);
+
+        Ok(data)
+    }
+}
+
+/// Builds a signed [`Eip1559EthereumData`].
+///
+/// # Examples
+/// ```
+/// # fn main() -> hiero_sdk::Result<()> {
+/// use hiero_sdk::{Eip1559EthereumDataBuilder, PrivateKey};
+///
+/// let key = PrivateKey::generate_ecdsa();
+///
+/// let data = Eip1559EthereumDataBuilder::new()
+///     .chain_id(296)
+///     .nonce(0)
+///     .max_gas(710_000_000_000)
+///     .gas_limit(100_000)
+///     .to("0x000000000000000000000000000000000000041a".parse()?)
+///     .sign(&key)?;
+///
+/// assert_eq!(data.recover_sender()?, key.public_key().to_evm_address().unwrap());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Eip1559EthereumDataBuilder {
+    chain_id: u64,
+    nonce: u64,
+    max_priority_gas: u128,
+    max_gas: u128,
+    gas_limit: u64,
+    to: Option<EvmAddress>,
+    value: u128,
+    call_data: Vec<u8>,
+    access_list: Vec<EthereumAccessListItem>,
+}
+
+impl Eip1559EthereumDataBuilder {
+    /// Creates a new builder, with every field set to zero (or empty).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the ID of the chain.
+    pub fn chain_id(&mut self, chain_id: u64) -> &mut Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    /// Sets the transaction's nonce.
+    pub fn nonce(&mut self, nonce: u64) -> &mut Self {
+        self.nonce = nonce;
+        self
+    }
+
+    /// Sets the additional fee paid directly to miners. Not used in Hiero.
+    pub fn max_priority_gas(&mut self, max_priority_gas: u128) -> &mut Self {
+        self.max_priority_gas = max_priority_gas;
+        self
+    }
+
+    /// Sets the maximum price for 1 gas, in weibars.
+    pub fn max_gas(&mut self, max_gas: u128) -> &mut Self {
+        self.max_gas = max_gas;
+        self
+    }
+
+    /// Sets the amount of gas available for the transaction.
+    pub fn gas_limit(&mut self, gas_limit: u64) -> &mut Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    /// Sets the receiver of the transaction.
+    ///
+    /// Leave this unset to deploy a contract with `call_data` as its init code.
+    pub fn to(&mut self, to: EvmAddress) -> &mut Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sets the transaction value, in weibars.
+    pub fn value(&mut self, value: u128) -> &mut Self {
+        self.value = value;
+        self
+    }
+
+    /// Sets the raw call data.
+    pub fn call_data(&mut self, call_data: Vec<u8>) -> &mut Self {
+        self.call_data = call_data;
+        self
+    }
+
+    /// Sets the addresses and storage keys that the transaction plans to access.
+    pub fn access_list(&mut self, access_list: Vec<EthereumAccessListItem>) -> &mut Self {
+        self.access_list = access_list;
+        self
+    }
+
+    /// Adds `address` and the `storage_keys` in it that the transaction plans to access.
+    pub fn add_access_list_item(
+        &mut self,
+        address: EvmAddress,
+        storage_keys: impl IntoIterator<Item = [u8; 32]>,
+    ) -> &mut Self {
+        self.access_list.push(EthereumAccessListItem {
+            address: address.to_bytes().to_vec(),
+            storage_keys: storage_keys.into_iter().map(Vec::from).collect(),
+        });
+        self
+    }
+
+    /// Signs the transaction with `key`, filling in `recovery_id`, `r`, and `s`.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureCreate`](crate::Error::SignatureCreate) if `key` isn't an ECDSA(secp256k1) key.
+    pub fn sign(&self, key: &PrivateKey) -> crate::Result<Eip1559EthereumData> {
+        let mut data = Eip1559EthereumData {
+            chain_id: encode_uint(self.chain_id.into()),
+            nonce: encode_uint(self.nonce.into()),
+            max_priority_gas: encode_uint(self.max_priority_gas),
+            max_gas: encode_uint(self.max_gas),
+            gas_limit: encode_uint(self.gas_limit.into()),
+            to: self.to.map(|it| it.to_bytes().to_vec()).unwrap_or_default(),
+            value: encode_uint(self.value),
+            call_data: self.call_data.clone(),
+            access_list: self.access_list.clone(),
+            recovery_id: Vec::new(),
+            r: Vec::new(),
+            s: Vec::new(),
+        };
+
+        let (signature, recovery_id) = key.sign_prehash_recoverable(&data.signing_hash())?;
+
+        data.recovery_id = encode_uint(recovery_id.into());
+        data.r = strip_leading_zeros(&signature[..32]);
+        data.s = strip_leading_zeros(&signature[32..]);
+
+        Ok(data)
+    }
+}
+
+// `r` and `s` are RLP integers, so they can't have leading zeros.
+fn strip_leading_zeros(bytes: &[u8]) -> Vec<u8> {
+    let leading_zeros = bytes.iter().take_while(|&&it| it == 0).count();
+
+    bytes[leading_zeros..].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::{
+        Eip1559EthereumDataBuilder,
+        LegacyEthereumDataBuilder,
+    };
+    use crate::{
+        EthereumData,
+        EvmAddress,
+        PrivateKey,
+    };
+
+    // https://eips.ethereum.org/EIPS/eip-155#example
+    #[test]
+    fn legacy_eip155_example() {
+        let key = PrivateKey::from_bytes_ecdsa(&[0x46; 32]).unwrap();
+
+        let data = LegacyEthereumDataBuilder::new()
+            .chain_id(1)
+            .nonce(9)
+            .gas_price(20_000_000_000)
+            .gas_limit(21000)
+            .to(EvmAddress([0x35; 20]))
+            .value(1_000_000_000_000_000_000)
+            .sign(&key)
+            .unwrap();
+
+        assert_eq!(
+            hex::encode(data.to_bytes()),
+            concat!(
+                "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025",
+                "a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276",
+                "a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83",
+            )
+        );
+
+        assert_eq!(data.chain_id(), Some(1));
+        assert_eq!(
+            data.recover_sender().unwrap(),
+            EvmAddress(hex!("9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f"))
+        );
+    }
+
+    #[test]
+    fn legacy_without_chain_id() {
+        let key = PrivateKey::generate_ecdsa();
+
+        let data = LegacyEthereumDataBuilder::new().nonce(1).gas_limit(21000).sign(&key).unwrap();
+
+        assert_eq!(data.chain_id(), None);
+        assert!(matches!(data.v.as_slice(), [27 | 28]));
+        assert_eq!(data.recover_sender().unwrap(), key.public_key().to_evm_address().unwrap());
+    }
+
+    #[test]
+    fn eip1559_round_trip() {
+        let key = PrivateKey::generate_ecdsa();
+
+        let data = Eip1559EthereumDataBuilder::new()
+            .chain_id(298)
+            .nonce(2)
+            .max_priority_gas(47)
+            .max_gas(47)
+            .gas_limit(98304)
+            .to(EvmAddress(hex!("7e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181")))
+            .value(1_000_000_000_000_000_000)
+            .call_data(hex!("123456").to_vec())
+            .sign(&key)
+            .unwrap();
+
+        let data = EthereumData::from_bytes(&data.to_bytes()).unwrap();
+
+        assert_eq!(data.recover_sender().unwrap(), key.public_key().to_evm_address().unwrap());
+    }
+
+    #[test]
+    fn eip1559_access_list() {
+        let key = PrivateKey::from_bytes_ecdsa(&[0x46; 32]).unwrap();
+        let to = EvmAddress(hex!("7e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181"));
+
+        let data = Eip1559EthereumDataBuilder::new()
+            .chain_id(298)
+            .nonce(2)
+            .max_priority_gas(47)
+            .max_gas(47)
+            .gas_limit(98304)
+            .to(to)
+            .call_data(hex!("123456").to_vec())
+            .add_access_list_item(to, [[0; 32], [1; 32]])
+            .sign(&key)
+            .unwrap();
+
+        // signed by a standalone implementation of EIP-1559, see `ethereum_data`'s tests.
+        assert_eq!(
+            hex::encode(data.to_bytes()),
+            concat!(
+                "02f8c482012a022f2f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc1818083123456",
+                "f85bf859947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181f842",
+                "a00000000000000000000000000000000000000000000000000000000000000000",
+                "a00101010101010101010101010101010101010101010101010101010101010101",
+                "80a0824af185ec4e32c2dd8a2dc4ba6af2716fd9985ec18fccdee8e2fc1ce9f3e56d",
+                "a005dad86aa45d445211847e5dff54cca57cc18de73217121a4f4311a5c0f1ec84",
+            )
+        );
+
+        let EthereumData::Eip1559(decoded) = EthereumData::from_bytes(&data.to_bytes()).unwrap()
+        else {
+            panic!("expected an Eip1559 transaction")
+        };
+
+        assert_eq!(decoded.access_list, data.access_list);
+    }
+
+    #[test]
+    fn ed25519_key_fails() {
+        let key = PrivateKey::generate_ed25519();
+
+        assert!(LegacyEthereumDataBuilder::new().sign(&key).is_err());
+        assert!(Eip1559EthereumDataBuilder::new().sign(&key).is_err());
+    }
+}
@@ -28,7 +28,7 @@ pub type EthereumTransaction = Transaction<EthereumTransactionData>;
 
 #[derive(Debug, Default, Clone)]
 pub struct EthereumTransactionData {
-    /// The raw Ethereum transaction (RLP encoded type 0, 1, and 2).
+    /// The raw Ethereum transaction (RLP encoded type 0, 1, 2, and 4).
     ethereum_data: Vec<u8>,
 
     /// For large transactions (for example contract create) this should be used to
@@ -45,25 +45,25 @@ pub struct EthereumTransactionData {
 }
 
 impl EthereumTransaction {
-    /// Returns the raw Ethereum transaction (RLP encoded type 0, 1, and 2).
+    /// Returns the raw Ethereum transaction (RLP encoded type 0, 1, 2, and 4).
     #[must_use]
     pub fn get_ethereum_data(&self) -> &[u8] {
         &self.data().ethereum_data
     }
 
-    /// Sets the raw Ethereum transaction (RLP encoded type 0, 1, and 2).
+    /// Sets the raw Ethereum transaction (RLP encoded type 0, 1, 2, and 4).
     pub fn ethereum_data(&mut self, data: Vec<u8>) -> &mut Self {
         self.data_mut().ethereum_data = data;
         self
     }
 
-    /// Returns the file ID to find the raw Ethereum transaction (RLP encoded type 0, 1, and 2).
+    /// Returns the file ID to find the raw Ethereum transaction (RLP encoded type 0, 1, 2, and 4).
     #[must_use]
     pub fn get_call_data_file_id(&self) -> Option<FileId> {
         self.data().call_data_file_id
     }
 
-    /// Sets a file ID to find the raw Ethereum transaction (RLP encoded type 0, 1, and 2).
+    /// Sets a file ID to find the raw Ethereum transaction (RLP encoded type 0, 1, 2, and 4).
     ///
     /// For large transactions (for example contract create) this should be used to
     /// set the [`FileId`] of an HFS file containing the `call_data`
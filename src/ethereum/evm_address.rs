@@ -4,6 +4,10 @@ use std::fmt;
 use std::str::FromStr;
 
 use hex::FromHexError;
+use sha3::{
+    Digest,
+    Keccak256,
+};
 
 use crate::{
     EntityId,
@@ -27,6 +31,42 @@ impl EvmAddress {
     pub fn to_bytes(self) -> [u8; 20] {
         self.0
     }
+
+    /// Returns the address of the contract `deployer` creates with `CREATE` when its nonce is `nonce`.
+    ///
+    /// This is the last 20 bytes of `keccak256(rlp([deployer, nonce]))`.
+    #[must_use]
+    pub fn create(deployer: EvmAddress, nonce: u64) -> Self {
+        let mut rlp = rlp::RlpStream::new_list(2);
+
+        rlp.append(&deployer.0.as_slice()).append(&nonce);
+
+        Self::from_hash(Keccak256::digest(rlp.out()).into())
+    }
+
+    /// Returns the address of the contract `deployer` creates with `CREATE2`,
+    /// given the `salt` and the keccak256 hash of the init code (the bytecode followed by the constructor parameters).
+    ///
+    /// This is the last 20 bytes of `keccak256(0xff ++ deployer ++ salt ++ init_code_hash)`,
+    /// as defined in [EIP-1014](https://eips.ethereum.org/EIPS/eip-1014).
+    #[must_use]
+    pub fn create2(deployer: EvmAddress, salt: [u8; 32], init_code_hash: [u8; 32]) -> Self {
+        let hash = Keccak256::new()
+            .chain_update([0xff])
+            .chain_update(deployer.0)
+            .chain_update(salt)
+            .chain_update(init_code_hash)
+            .finalize();
+
+        Self::from_hash(hash.into())
+    }
+
+    fn from_hash(hash: [u8; 32]) -> Self {
+        let (_, address) = hash.split_at(12);
+
+        // panic: 32 - 12 = 20.
+        Self(address.try_into().unwrap())
+    }
 }
 
 // potential point of confusion: This type is specifically for the `shard.realm.num` in 20 byte format.
@@ -220,6 +260,11 @@ impl fmt::LowerHex for EvmAddress {
 mod tests {
     use assert_matches::assert_matches;
     use expect_test::expect;
+    use hex_literal::hex;
+    use sha3::{
+        Digest,
+        Keccak256,
+    };
 
     use super::SolidityAddress;
     use crate::{
@@ -291,6 +336,48 @@ mod tests {
         .assert_debug_eq(&EvmAddress([0x0c; 20]));
     }
 
+    #[test]
+    fn create() {
+        let deployer: EvmAddress = "0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0".parse().unwrap();
+
+        expect![[r#"
+            [
+                "0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d",
+                "0x343c43a37d37dff08ae8c4a11544c718abb4fcf8",
+                "0xf778b86fa74e846c4f0a1fbd1335fe81c00a0c91",
+                "0xfffd933a0bc612844eaf0c6fe3e5b8e9b6c1d19c",
+            ]
+        "#]]
+        .assert_debug_eq(
+            &(0..4).map(|nonce| EvmAddress::create(deployer, nonce)).collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn create2() {
+        // examples from EIP-1014.
+        let init_code_hash = |init_code: &[u8]| <[u8; 32]>::from(Keccak256::digest(init_code));
+
+        assert_eq!(
+            EvmAddress::create2(EvmAddress([0; 20]), [0; 32], init_code_hash(&[0x00])),
+            "0x4d1a2e2bb4f88f0250f26ffff098b0b30b26bf38".parse().unwrap()
+        );
+
+        assert_eq!(
+            EvmAddress::create2(
+                "0x00000000000000000000000000000000deadbeef".parse().unwrap(),
+                hex!("00000000000000000000000000000000000000000000000000000000cafebabe"),
+                init_code_hash(&hex!("deadbeef")),
+            ),
+            "0x60f3f640a8508fc6a86d45df051962668e1e8ac7".parse().unwrap()
+        );
+
+        assert_eq!(
+            EvmAddress::create2(EvmAddress([0; 20]), [0; 32], init_code_hash(&[])),
+            "0xe33c0c7f7df4809055c3eba6c09cfe4baf1bd9e0".parse().unwrap()
+        );
+    }
+
     #[test]
     fn to_entity_id() {
         let solidity_address = SolidityAddress(EvmAddress([0x0c; 20]));
@@ -1,15 +1,31 @@
 // SPDX-License-Identifier: Apache-2.0
 
+mod eip712;
 mod ethereum_data;
+mod ethereum_data_builder;
 mod ethereum_flow;
 mod ethereum_transaction;
 mod evm_address;
 
+pub use eip712::{
+    Eip712Domain,
+    Eip712Field,
+    Eip712TypedData,
+    Eip712Value,
+};
 pub use ethereum_data::{
     Eip1559EthereumData,
+    Eip2930EthereumData,
+    Eip7702Authorization,
+    Eip7702EthereumData,
+    EthereumAccessListItem,
     EthereumData,
     LegacyEthereumData,
 };
+pub use ethereum_data_builder::{
+    Eip1559EthereumDataBuilder,
+    LegacyEthereumDataBuilder,
+};
 pub use ethereum_flow::EthereumFlow;
 pub use ethereum_transaction::EthereumTransaction;
 pub(crate) use ethereum_transaction::EthereumTransactionData;
@@ -3,22 +3,7 @@
 use std::time::Duration;
 
 use bytes::Bytes;
-use http_body_util::BodyExt as _;
-use hyper::body::Incoming;
-use hyper::{
-    Method,
-    Request,
-    Response,
-};
-use hyper_openssl::client::legacy::HttpsConnector;
-use hyper_util::client::legacy::connect::HttpConnector;
-use hyper_util::client::legacy::Client as HttpClient;
-use hyper_util::rt::TokioExecutor;
-use openssl::ssl::{
-    SslConnector,
-    SslMethod,
-    SslVerifyMode,
-};
+use hyper::Method;
 
 use crate::fee_estimate_types::{
     FeeEstimate,
@@ -26,6 +11,11 @@ use crate::fee_estimate_types::{
     FeeExtra,
     NetworkFee,
 };
+use crate::mirror_rest::{
+    self,
+    DEFAULT_MAX_ATTEMPTS,
+    DEFAULT_MAX_BACKOFF,
+};
 use crate::transaction::TransactionExecute;
 use crate::{
     Client,
@@ -34,15 +24,6 @@ use crate::{
     Transaction,
 };
 
-/// Default maximum number of retry attempts.
-const DEFAULT_MAX_ATTEMPTS: usize = 10;
-
-/// Default maximum backoff duration.
-const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(8);
-
-/// Initial backoff delay.
-const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
-
 /// Fee estimation query that communicates with the mirror node REST API.
 ///
 /// This query estimates the expected transaction fees without submitting the transaction
@@ -203,55 +184,24 @@ impl FeeEstimateQuery {
             Error::basic_parse("transaction bytes must be set on FeeEstimateQuery")
         })?;
 
-        let base_url = mirror_rest_base_url(client);
-        let url = self.build_url(&base_url);
-
-        let http_client = build_http_client(&base_url);
-
-        let mut attempt = 0;
-        loop {
-            let request = Request::builder()
-                .method(Method::POST)
-                .uri(&url)
-                .header("Content-Type", "application/x-protobuf")
-                .header("Accept", "application/json")
-                .body(http_body_util::Full::new(Bytes::from(transaction_bytes.clone())))
-                .map_err(|e| Error::basic_parse(e.to_string()))?;
-
-            let result = http_client.request(request).await;
-
-            match result {
-                Ok(response) => {
-                    let status = response.status();
-                    if status.is_success() {
-                        return parse_response(response).await;
-                    }
-
-                    if should_retry_status(status) && attempt < self.max_attempts {
-                        attempt += 1;
-                        let delay = compute_backoff(attempt, self.max_backoff);
-                        tokio::time::sleep(delay).await;
-                        continue;
-                    }
-
-                    let body = read_body(response).await.unwrap_or_default();
-                    return Err(Error::basic_parse(format!(
-                        "fee estimate query failed with HTTP {status}: {body}"
-                    )));
-                }
-                Err(e) => {
-                    if attempt < self.max_attempts {
-                        attempt += 1;
-                        let delay = compute_backoff(attempt, self.max_backoff);
-                        tokio::time::sleep(delay).await;
-                        continue;
-                    }
-                    return Err(Error::basic_parse(format!(
-                        "fee estimate query failed after {attempt} attempts: {e}"
-                    )));
-                }
-            }
+        let url = self.build_url(&mirror_rest::base_url(client));
+
+        let (status, body) = mirror_rest::send(
+            Method::POST,
+            &url,
+            Some(("application/x-protobuf", Bytes::from(transaction_bytes.clone()))),
+            self.max_attempts,
+            self.max_backoff,
+        )
+        .await?;
+
+        if !status.is_success() {
+            return Err(Error::basic_parse(format!(
+                "fee estimate query failed with HTTP {status}: {body}"
+            )));
         }
+
+        parse_fee_estimate_response_json(&body)
     }
 
     fn build_url(&self, base_url: &str) -> String {
@@ -263,83 +213,6 @@ impl FeeEstimateQuery {
     }
 }
 
-/// Determines whether a response status code is retryable.
-fn should_retry_status(status: hyper::StatusCode) -> bool {
-    matches!(status.as_u16(), 408 | 429 | 500 | 502 | 503 | 504)
-}
-
-/// Computes exponential backoff delay: `min(500ms * 2^attempt, max_backoff)`.
-fn compute_backoff(attempt: usize, max_backoff: Duration) -> Duration {
-    let delay = INITIAL_BACKOFF.saturating_mul(1u32.wrapping_shl(attempt as u32));
-    delay.min(max_backoff)
-}
-
-/// Constructs the mirror node REST base URL from the client's mirror network configuration.
-fn mirror_rest_base_url(client: &Client) -> String {
-    let addresses = client.mirror_network();
-    let address = addresses.first().expect("mirror network must have at least one address");
-
-    // Parse host and port
-    let (host, port_str) = if let Some(idx) = address.rfind(':') {
-        (&address[..idx], &address[idx + 1..])
-    } else {
-        (address.as_str(), "443")
-    };
-
-    let port: u16 = port_str.parse().unwrap_or(443);
-
-    let is_localhost = host.contains("localhost") || host.contains("127.0.0.1");
-
-    if is_localhost {
-        // For local development, map gRPC port 5600 to REST port 5551
-        let rest_port = if port == 5600 { 5551 } else { port };
-        format!("http://{host}:{rest_port}/api/v1")
-    } else {
-        let scheme = if port == 80 { "http" } else { "https" };
-        if (scheme == "https" && port == 443) || (scheme == "http" && port == 80) {
-            format!("{scheme}://{host}/api/v1")
-        } else {
-            format!("{scheme}://{host}:{port}/api/v1")
-        }
-    }
-}
-
-/// Builds an HTTP client appropriate for the given base URL.
-fn build_http_client(
-    base_url: &str,
-) -> HttpClient<HttpsConnector<HttpConnector>, http_body_util::Full<Bytes>> {
-    let mut http = HttpConnector::new();
-    http.enforce_http(false);
-
-    if base_url.starts_with("https") {
-        let mut ssl_builder = SslConnector::builder(SslMethod::tls()).unwrap();
-        ssl_builder.set_verify(SslVerifyMode::PEER);
-        let https = HttpsConnector::with_connector(http, ssl_builder).unwrap();
-        HttpClient::builder(TokioExecutor::new()).build(https)
-    } else {
-        // For HTTP (localhost), still use HttpsConnector but with permissive settings
-        let mut ssl_builder = SslConnector::builder(SslMethod::tls()).unwrap();
-        ssl_builder.set_verify(SslVerifyMode::NONE);
-        let https = HttpsConnector::with_connector(http, ssl_builder).unwrap();
-        HttpClient::builder(TokioExecutor::new()).build(https)
-    }
-}
-
-/// Reads the full body of an HTTP response as a string.
-async fn read_body(response: Response<Incoming>) -> Result<String, String> {
-    let body_bytes = response.into_body().collect().await.map_err(|e| e.to_string())?.to_bytes();
-    String::from_utf8(body_bytes.to_vec()).map_err(|e| e.to_string())
-}
-
-/// Parses a successful fee estimate response from JSON.
-async fn parse_response(response: Response<Incoming>) -> crate::Result<FeeEstimateResponse> {
-    let body = read_body(response)
-        .await
-        .map_err(|e| Error::basic_parse(format!("failed to read response body: {e}")))?;
-
-    parse_fee_estimate_response_json(&body)
-}
-
 fn parse_fee_estimate_response_json(json: &str) -> crate::Result<FeeEstimateResponse> {
     let value: serde_json::Value = serde_json::from_str(json)
         .map_err(|e| Error::basic_parse(format!("failed to parse fee estimate JSON: {e}")))?;
@@ -515,48 +388,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn mirror_rest_url_mainnet() {
-        let url = mirror_rest_base_url_from_address("mainnet-public.mirrornode.hedera.com:443");
-        assert_eq!(url, "https://mainnet-public.mirrornode.hedera.com/api/v1");
-    }
-
-    #[test]
-    fn mirror_rest_url_localhost() {
-        let url = mirror_rest_base_url_from_address("127.0.0.1:5600");
-        assert_eq!(url, "http://127.0.0.1:5551/api/v1");
-    }
-
-    #[test]
-    fn mirror_rest_url_localhost_custom_port() {
-        let url = mirror_rest_base_url_from_address("localhost:8080");
-        assert_eq!(url, "http://localhost:8080/api/v1");
-    }
-
-    /// Helper for tests that works on a single address string.
-    fn mirror_rest_base_url_from_address(address: &str) -> String {
-        let (host, port_str) = if let Some(idx) = address.rfind(':') {
-            (&address[..idx], &address[idx + 1..])
-        } else {
-            (address, "443")
-        };
-
-        let port: u16 = port_str.parse().unwrap_or(443);
-        let is_localhost = host.contains("localhost") || host.contains("127.0.0.1");
-
-        if is_localhost {
-            let rest_port = if port == 5600 { 5551 } else { port };
-            format!("http://{host}:{rest_port}/api/v1")
-        } else {
-            let scheme = if port == 80 { "http" } else { "https" };
-            if (scheme == "https" && port == 443) || (scheme == "http" && port == 80) {
-                format!("{scheme}://{host}/api/v1")
-            } else {
-                format!("{scheme}://{host}:{port}/api/v1")
-            }
-        }
-    }
-
     #[test]
     fn default_settings() {
         let query = FeeEstimateQuery::new();
@@ -571,14 +402,4 @@ mod tests {
     fn high_volume_throttle_out_of_range() {
         let _ = FeeEstimateQuery::new().set_high_volume_throttle(10001);
     }
-
-    #[test]
-    fn backoff_computation() {
-        assert_eq!(compute_backoff(0, Duration::from_secs(8)), Duration::from_millis(500));
-        assert_eq!(compute_backoff(1, Duration::from_secs(8)), Duration::from_millis(1000));
-        assert_eq!(compute_backoff(2, Duration::from_secs(8)), Duration::from_millis(2000));
-        assert_eq!(compute_backoff(3, Duration::from_secs(8)), Duration::from_millis(4000));
-        assert_eq!(compute_backoff(4, Duration::from_secs(8)), Duration::from_millis(8000));
-        assert_eq!(compute_backoff(5, Duration::from_secs(8)), Duration::from_millis(8000));
-    }
 }
@@ -0,0 +1,580 @@
+use std::collections::HashMap;
+
+use num_bigint::BigUint;
+use serde_json::Value;
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+use crate::hooks::{
+    EvmHookMappingEntries,
+    EvmHookMappingEntry,
+    EvmHookStorageSlot,
+};
+use crate::Error;
+
+/// The storage layout of a Solidity contract, used to compute the keys of lambda EVM hook storage slots.
+///
+/// # Examples
+/// ```
+/// # fn main() -> hiero_sdk::Result<()> {
+/// use hiero_sdk::{EvmHookStorageLayout, EvmHookStorageUpdate};
+///
+/// // `solc --storage-layout`, for `mapping(address => uint256) balances;`
+/// let layout = EvmHookStorageLayout::from_json(r#"{
+///     "storage": [{ "label": "balances", "offset": 0, "slot": "0", "type": "t_mapping(t_address,t_uint256)" }],
+///     "types": {
+///         "t_address": { "encoding": "inplace", "label": "address", "numberOfBytes": "20" },
+///         "t_mapping(t_address,t_uint256)": {
+///             "encoding": "mapping", "key": "t_address", "label": "mapping(address => uint256)",
+///             "numberOfBytes": "32", "value": "t_uint256"
+///         },
+///         "t_uint256": { "encoding": "inplace", "label": "uint256", "numberOfBytes": "32" }
+///     }
+/// }"#)?;
+///
+/// let owner = [0x11; 20];
+/// let entries = layout.variable("balances")?.mapping_entries([(&owner[..], &[0x03, 0xe8][..])])?;
+///
+/// let update = EvmHookStorageUpdate::MappingEntries(entries);
+/// # let _ = update;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct EvmHookStorageLayout {
+    storage: Vec<StorageMember>,
+    types: HashMap<String, StorageType>,
+}
+
+/// A location in the storage of a Solidity contract, see [`EvmHookStorageLayout`].
+#[derive(Debug, Clone)]
+pub struct EvmHookStorageLocation<'a> {
+    layout: &'a EvmHookStorageLayout,
+    slot: [u8; 32],
+    offset: u32,
+    ty: &'a StorageType,
+}
+
+#[derive(Debug, Clone)]
+struct StorageMember {
+    label: String,
+    slot: BigUint,
+    offset: u32,
+    ty: String,
+}
+
+#[derive(Debug, Clone)]
+struct StorageType {
+    encoding: Encoding,
+    label: String,
+    number_of_bytes: u64,
+    key: Option<String>,
+    value: Option<String>,
+    base: Option<String>,
+    members: Vec<StorageMember>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Inplace,
+    Mapping,
+    DynamicArray,
+    Bytes,
+}
+
+impl EvmHookStorageLayout {
+    /// Parses a storage layout from the JSON solc emits for `--storage-layout`
+    /// (or `storageLayout` in its standard JSON output).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `json` isn't a valid storage layout.
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        let value: Value = serde_json::from_str(json).map_err(Error::basic_parse)?;
+
+        let storage = parse_members(value.get("storage"))?;
+
+        let types = match value.get("types") {
+            Some(Value::Object(types)) => types
+                .iter()
+                .map(|(id, ty)| Ok((id.clone(), parse_type(id, ty)?)))
+                .collect::<crate::Result<_>>()?,
+            // a contract without state variables has `"types": null`.
+            Some(Value::Null) | None => HashMap::new(),
+            Some(_) => return Err(Error::basic_parse("storage layout `types` must be an object")),
+        };
+
+        Ok(Self { storage, types })
+    }
+
+    /// Returns the location of the state variable named `label`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the contract has no state variable named `label`.
+    pub fn variable(&self, label: &str) -> crate::Result<EvmHookStorageLocation<'_>> {
+        let member = self.storage.iter().find(|it| it.label == label).ok_or_else(|| {
+            Error::basic_parse(format!("storage layout has no variable `{label}`"))
+        })?;
+
+        Ok(EvmHookStorageLocation {
+            layout: self,
+            slot: to_word(&member.slot),
+            offset: member.offset,
+            ty: self.get_type(&member.ty)?,
+        })
+    }
+
+    fn get_type(&self, id: &str) -> crate::Result<&StorageType> {
+        self.types
+            .get(id)
+            .ok_or_else(|| Error::basic_parse(format!("storage layout has no type `{id}`")))
+    }
+}
+
+impl<'a> EvmHookStorageLocation<'a> {
+    /// Returns the key of the slot this location is in.
+    #[must_use]
+    pub fn slot(&self) -> [u8; 32] {
+        self.slot
+    }
+
+    /// Returns the offset (in bytes, from the least significant end) of this location in its slot.
+    ///
+    /// Nonzero when multiple small variables are packed into one slot.
+    #[must_use]
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Returns the Solidity type of the value at this location, for example `mapping(address => uint256)`.
+    #[must_use]
+    pub fn type_label(&self) -> &'a str {
+        &self.ty.label
+    }
+
+    /// Returns the location of the struct member named `label`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if this isn't a struct with a member named `label`.
+    pub fn member(&self, label: &str) -> crate::Result<Self> {
+        let member = self.ty.members.iter().find(|it| it.label == label).ok_or_else(|| {
+            Error::basic_parse(format!("`{}` has no member `{label}`", self.ty.label))
+        })?;
+
+        Ok(Self {
+            layout: self.layout,
+            slot: add(&self.slot, &member.slot),
+            offset: member.offset,
+            ty: self.layout.get_type(&member.ty)?,
+        })
+    }
+
+    /// Returns the location of the mapping value for `key`.
+    ///
+    /// `key` is the big endian value of the key (for example the 20 bytes of an `address`),
+    /// or the raw bytes of a `string` or `bytes` key.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if this isn't a mapping, or `key` is too long for the mapping's key type.
+    pub fn key(&self, key: &[u8]) -> crate::Result<Self> {
+        let (key_type, value_type) = self.mapping_types()?;
+
+        let mut hasher = Keccak256::new();
+
+        match key_type.encoding {
+            Encoding::Bytes => hasher.update(key),
+            _ => hasher.update(encode_word(key_type, key)?),
+        }
+
+        hasher.update(self.slot);
+
+        Ok(Self { layout: self.layout, slot: hasher.finalize().into(), offset: 0, ty: value_type })
+    }
+
+    /// Returns the location of the array element at `index`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if this isn't an array, or `index` is out of bounds of a fixed size array.
+    pub fn index(&self, index: u64) -> crate::Result<Self> {
+        let base = match (&self.ty.base, self.ty.encoding) {
+            (Some(_), Encoding::DynamicArray) => Keccak256::digest(self.slot).into(),
+            (Some(_), Encoding::Inplace) => {
+                if fixed_array_len(&self.ty.label).is_some_and(|len| index >= len) {
+                    return Err(Error::basic_parse(format!(
+                        "index {index} is out of bounds for `{}`",
+                        self.ty.label
+                    )));
+                }
+
+                self.slot
+            }
+            _ => return Err(Error::basic_parse(format!("`{}` is not an array", self.ty.label))),
+        };
+
+        let element = self.layout.get_type(self.ty.base.as_deref().unwrap_or_default())?;
+        let size = element.number_of_bytes;
+
+        // elements of 16 bytes or less are packed, multiple to a slot.
+        let (slot, offset) = if (1..=16).contains(&size) {
+            let per_slot = 32 / size;
+            (index / per_slot, (index % per_slot) * size)
+        } else {
+            (index * size.div_ceil(32), 0)
+        };
+
+        Ok(Self {
+            layout: self.layout,
+            slot: add(&base, &BigUint::from(slot)),
+            // `offset < 32`.
+            offset: offset as u32,
+            ty: element,
+        })
+    }
+
+    /// Returns a storage slot that sets the value at this location to `value`.
+    ///
+    /// `value` is the big endian value (for example the 20 bytes of an `address`),
+    /// which is placed at this location's offset in the slot.
+    ///
+    /// Any other values packed into the same slot are set to zero.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `value` is too long for the type at this location.
+    pub fn storage_slot(&self, value: &[u8]) -> crate::Result<EvmHookStorageSlot> {
+        let size = self.ty.number_of_bytes.min(32) as usize;
+        let value = encode_word(self.ty, value)?;
+
+        // move the value from the least significant `size` bytes to `offset`.
+        let mut word = [0; 32];
+        let end = 32 - self.offset as usize;
+        word[(end - size)..end].copy_from_slice(&value[(32 - size)..]);
+
+        Ok(EvmHookStorageSlot::new(minimal(&self.slot), minimal(&word)))
+    }
+
+    /// Returns mapping entries that set the value for each key of this mapping.
+    ///
+    /// Keys and values are encoded as for [`key`](Self::key) and [`storage_slot`](Self::storage_slot).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if this isn't a mapping, its values span more than one slot,
+    ///   or a key or value is too long for its type.
+    pub fn mapping_entries<'b>(
+        &self,
+        entries: impl IntoIterator<Item = (&'b [u8], &'b [u8])>,
+    ) -> crate::Result<EvmHookMappingEntries> {
+        let (key_type, value_type) = self.mapping_types()?;
+
+        if value_type.encoding != Encoding::Inplace
+            || value_type.number_of_bytes > 32
+            || !value_type.members.is_empty()
+        {
+            return Err(Error::basic_parse(format!(
+                "`{}` values are not a single slot, use `key` to set them",
+                self.ty.label
+            )));
+        }
+
+        let entries = entries
+            .into_iter()
+            .map(|(key, value)| {
+                let mut entry =
+                    EvmHookMappingEntry::new(None, Some(minimal(&encode_word(value_type, value)?)));
+
+                match key_type.encoding {
+                    Encoding::Bytes => entry.set_preimage(key.to_vec()),
+                    _ => entry.set_key(minimal(&encode_word(key_type, key)?)),
+                };
+
+                Ok(entry)
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(EvmHookMappingEntries::new(minimal(&self.slot), entries))
+    }
+
+    fn mapping_types(&self) -> crate::Result<(&'a StorageType, &'a StorageType)> {
+        match (&self.ty.key, &self.ty.value, self.ty.encoding) {
+            (Some(key), Some(value), Encoding::Mapping) => {
+                Ok((self.layout.get_type(key)?, self.layout.get_type(value)?))
+            }
+            _ => Err(Error::basic_parse(format!("`{}` is not a mapping", self.ty.label))),
+        }
+    }
+}
+
+// encodes `value` as a 32 byte word like `abi.encode` would.
+fn encode_word(ty: &StorageType, value: &[u8]) -> crate::Result<[u8; 32]> {
+    let size = ty.number_of_bytes.min(32) as usize;
+
+    if value.len() > size {
+        return Err(Error::basic_parse(format!(
+            "{} byte value is too long for `{}`",
+            value.len(),
+            ty.label
+        )));
+    }
+
+    let mut word = [0; 32];
+
+    if is_fixed_bytes(&ty.label) {
+        // `bytesN` is left aligned.
+        word[..value.len()].copy_from_slice(value);
+    } else {
+        // signed integers are sign extended.
+        if ty.label.starts_with("int") && value.first().is_some_and(|it| it & 0x80 != 0) {
+            word.fill(0xff);
+        }
+
+        word[(32 - value.len())..].copy_from_slice(value);
+    }
+
+    Ok(word)
+}
+
+fn is_fixed_bytes(label: &str) -> bool {
+    label
+        .strip_prefix("bytes")
+        .is_some_and(|it| !it.is_empty() && it.bytes().all(|it| it.is_ascii_digit()))
+}
+
+// `uint8[3]` -> `3`
+fn fixed_array_len(label: &str) -> Option<u64> {
+    label.strip_suffix(']')?.rsplit_once('[')?.1.parse().ok()
+}
+
+// hook storage keys and values are big endian with leading zeros removed.
+fn minimal(word: &[u8; 32]) -> Vec<u8> {
+    let start = word.iter().position(|it| *it != 0).unwrap_or(word.len());
+
+    word[start..].to_vec()
+}
+
+fn to_word(value: &BigUint) -> [u8; 32] {
+    let bytes = value.to_bytes_be();
+    let bytes = &bytes[bytes.len().saturating_sub(32)..];
+
+    let mut word = [0; 32];
+    word[(32 - bytes.len())..].copy_from_slice(bytes);
+
+    word
+}
+
+// slots wrap around at 2^256.
+fn add(slot: &[u8; 32], value: &BigUint) -> [u8; 32] {
+    to_word(&(BigUint::from_bytes_be(slot) + value))
+}
+
+fn parse_members(value: Option<&Value>) -> crate::Result<Vec<StorageMember>> {
+    let Some(Value::Array(members)) = value else {
+        return Err(Error::basic_parse("storage layout `storage` must be an array"));
+    };
+
+    members
+        .iter()
+        .map(|member| {
+            let label = get_str(member, "label")?.to_owned();
+
+            let slot = get_str(member, "slot")?
+                .parse()
+                .map_err(|_| Error::basic_parse(format!("invalid slot for `{label}`")))?;
+
+            let offset = member
+                .get("offset")
+                .and_then(Value::as_u64)
+                .and_then(|it| u32::try_from(it).ok())
+                .ok_or_else(|| Error::basic_parse(format!("invalid offset for `{label}`")))?;
+
+            let ty = get_str(member, "type")?.to_owned();
+
+            Ok(StorageMember { label, slot, offset, ty })
+        })
+        .collect()
+}
+
+fn parse_type(id: &str, value: &Value) -> crate::Result<StorageType> {
+    let encoding = match get_str(value, "encoding")? {
+        "inplace" => Encoding::Inplace,
+        "mapping" => Encoding::Mapping,
+        "dynamic_array" => Encoding::DynamicArray,
+        "bytes" => Encoding::Bytes,
+        other => {
+            return Err(Error::basic_parse(format!("unknown encoding `{other}` for type `{id}`")))
+        }
+    };
+
+    let number_of_bytes = get_str(value, "numberOfBytes")?
+        .parse()
+        .map_err(|_| Error::basic_parse(format!("invalid `numberOfBytes` for type `{id}`")))?;
+
+    let optional_str = |key| value.get(key).and_then(Value::as_str).map(str::to_owned);
+
+    Ok(StorageType {
+        encoding,
+        label: get_str(value, "label")?.to_owned(),
+        number_of_bytes,
+        key: optional_str("key"),
+        value: optional_str("value"),
+        base: optional_str("base"),
+        members: match value.get("members") {
+            Some(members) => parse_members(Some(members))?,
+            None => Vec::new(),
+        },
+    })
+}
+
+fn get_str<'a>(value: &'a Value, key: &str) -> crate::Result<&'a str> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| Error::basic_parse(format!("storage layout entry is missing `{key}`")))
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use hex_literal::hex;
+
+    use super::EvmHookStorageLayout;
+    use crate::hooks::EvmHookStorageSlot;
+
+    // ```solidity
+    // contract Example {
+    //     uint256 total;
+    //     address owner;
+    //     uint64 nonce;
+    //     mapping(address => uint256) balances;
+    //     mapping(address => mapping(uint256 => bool)) approvals;
+    //     uint64[] values;
+    //     Info info;
+    //     mapping(string => Info) named;
+    //
+    //     struct Info { uint128 a; uint128 b; address c; }
+    // }
+    // ```
+    const LAYOUT: &str = r#"{
+        "storage": [
+            { "astId": 3, "contract": "Example.sol:Example", "label": "total", "offset": 0, "slot": "0", "type": "t_uint256" },
+            { "astId": 5, "contract": "Example.sol:Example", "label": "owner", "offset": 0, "slot": "1", "type": "t_address" },
+            { "astId": 7, "contract": "Example.sol:Example", "label": "nonce", "offset": 20, "slot": "1", "type": "t_uint64" },
+            { "astId": 11, "contract": "Example.sol:Example", "label": "balances", "offset": 0, "slot": "2", "type": "t_mapping(t_address,t_uint256)" },
+            { "astId": 17, "contract": "Example.sol:Example", "label": "approvals", "offset": 0, "slot": "3", "type": "t_mapping(t_address,t_mapping(t_uint256,t_bool))" },
+            { "astId": 20, "contract": "Example.sol:Example", "label": "values", "offset": 0, "slot": "4", "type": "t_array(t_uint64)dyn_storage" },
+            { "astId": 23, "contract": "Example.sol:Example", "label": "info", "offset": 0, "slot": "5", "type": "t_struct(Info)32_storage" },
+            { "astId": 28, "contract": "Example.sol:Example", "label": "named", "offset": 0, "slot": "7", "type": "t_mapping(t_string_memory_ptr,t_struct(Info)32_storage)" }
+        ],
+        "types": {
+            "t_address": { "encoding": "inplace", "label": "address", "numberOfBytes": "20" },
+            "t_array(t_uint64)dyn_storage": { "base": "t_uint64", "encoding": "dynamic_array", "label": "uint64[]", "numberOfBytes": "32" },
+            "t_bool": { "encoding": "inplace", "label": "bool", "numberOfBytes": "1" },
+            "t_mapping(t_address,t_mapping(t_uint256,t_bool))": { "encoding": "mapping", "key": "t_address", "label": "mapping(address => mapping(uint256 => bool))", "numberOfBytes": "32", "value": "t_mapping(t_uint256,t_bool)" },
+            "t_mapping(t_address,t_uint256)": { "encoding": "mapping", "key": "t_address", "label": "mapping(address => uint256)", "numberOfBytes": "32", "value": "t_uint256" },
+            "t_mapping(t_string_memory_ptr,t_struct(Info)32_storage)": { "encoding": "mapping", "key": "t_string_memory_ptr", "label": "mapping(string => struct Example.Info)", "numberOfBytes": "32", "value": "t_struct(Info)32_storage" },
+            "t_mapping(t_uint256,t_bool)": { "encoding": "mapping", "key": "t_uint256", "label": "mapping(uint256 => bool)", "numberOfBytes": "32", "value": "t_bool" },
+            "t_string_memory_ptr": { "encoding": "bytes", "label": "string", "numberOfBytes": "32" },
+            "t_struct(Info)32_storage": {
+                "encoding": "inplace",
+                "label": "struct Example.Info",
+                "members": [
+                    { "astId": 27, "contract": "Example.sol:Example", "label": "a", "offset": 0, "slot": "0", "type": "t_uint128" },
+                    { "astId": 29, "contract": "Example.sol:Example", "label": "b", "offset": 16, "slot": "0", "type": "t_uint128" },
+                    { "astId": 31, "contract": "Example.sol:Example", "label": "c", "offset": 0, "slot": "1", "type": "t_address" }
+                ],
+                "numberOfBytes": "64"
+            },
+            "t_uint128": { "encoding": "inplace", "label": "uint128", "numberOfBytes": "16" },
+            "t_uint256": { "encoding": "inplace", "label": "uint256", "numberOfBytes": "32" },
+            "t_uint64": { "encoding": "inplace", "label": "uint64", "numberOfBytes": "8" }
+        }
+    }"#;
+
+    const OWNER: [u8; 20] = hex!("5b38da6a701c568545dcfcb03fcb875f56beddc4");
+
+    #[test]
+    fn variables() {
+        let layout = EvmHookStorageLayout::from_json(LAYOUT).unwrap();
+
+        assert_eq!(
+            layout.variable("total").unwrap().storage_slot(&[0x03, 0xe8]).unwrap(),
+            EvmHookStorageSlot::new(Vec::new(), vec![0x03, 0xe8])
+        );
+
+        // `nonce` is packed after `owner`.
+        let nonce = layout.variable("nonce").unwrap();
+        assert_eq!(nonce.offset(), 20);
+        assert_eq!(
+            nonce.storage_slot(&[0x01]).unwrap(),
+            EvmHookStorageSlot::new(
+                vec![0x01],
+                hex!("01 0000000000000000000000000000000000000000").to_vec()
+            )
+        );
+
+        assert!(layout.variable("missing").is_err());
+        assert!(nonce.storage_slot(&[0; 9]).is_err());
+    }
+
+    #[test]
+    fn mappings() {
+        let layout = EvmHookStorageLayout::from_json(LAYOUT).unwrap();
+
+        let balances = layout.variable("balances").unwrap();
+
+        expect![[r#"
+            "b314f101a00aa0d8cc6704cc6dd1e9dd7551ec98c9df52079c192c560ba66c4a"
+        "#]]
+        .assert_debug_eq(&hex::encode(balances.key(&OWNER).unwrap().slot()));
+
+        let entries = balances.mapping_entries([(&OWNER[..], &[0x03, 0xe8][..])]).unwrap();
+        assert_eq!(entries.mapping_slot, [0x02]);
+        assert_eq!(entries.entries[0].key.as_deref(), Some(&OWNER[..]));
+        assert_eq!(entries.entries[0].value.as_deref(), Some(&[0x03, 0xe8][..]));
+
+        let approvals = layout.variable("approvals").unwrap().key(&OWNER).unwrap();
+        assert_eq!(approvals.type_label(), "mapping(uint256 => bool)");
+
+        expect![[r#"
+            "6aa82956ec5bb21a2ca9372ae80cd09a880e707f2304218de6762a77b2164c42"
+        "#]]
+        .assert_debug_eq(&hex::encode(approvals.key(&[0x07]).unwrap().slot()));
+
+        // `string` keys are hashed unpadded, and are sent as preimages.
+        let named = layout.variable("named").unwrap();
+
+        expect![[r#"
+            "a478e43b88f0bf0999792486cdb85c44a79ae39db2de1d4da061e8d73f2e6baa"
+        "#]]
+        .assert_debug_eq(&hex::encode(named.key(b"alice").unwrap().member("c").unwrap().slot()));
+
+        assert!(named.mapping_entries([(&b"alice"[..], &[0x01][..])]).is_err());
+        assert!(layout.variable("total").unwrap().key(&OWNER).is_err());
+    }
+
+    #[test]
+    fn arrays_and_structs() {
+        let layout = EvmHookStorageLayout::from_json(LAYOUT).unwrap();
+
+        let values = layout.variable("values").unwrap();
+
+        // 4 `uint64`s per slot, starting at `keccak256(4)`.
+        let element = values.index(5).unwrap();
+        assert_eq!(element.offset(), 8);
+
+        expect![[r#"
+            "8a35acfbc15ff81a39ae7d344fd709f28e8600b4aa8c65c6b64bfe7fe36bd19c"
+        "#]]
+        .assert_debug_eq(&hex::encode(element.slot()));
+
+        let info = layout.variable("info").unwrap();
+
+        let b = info.member("b").unwrap();
+        assert_eq!((b.slot()[31], b.offset()), (5, 16));
+
+        let c = info.member("c").unwrap();
+        assert_eq!((c.slot()[31], c.offset()), (6, 0));
+
+        assert!(info.member("d").is_err());
+        assert!(info.index(0).is_err());
+    }
+}
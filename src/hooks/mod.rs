@@ -1,6 +1,8 @@
 pub mod evm_hook;
 pub mod evm_hook_call;
 pub mod evm_hook_spec;
+#[cfg(feature = "serde")]
+pub mod evm_hook_storage_layout;
 pub mod evm_hook_storage_slot;
 pub mod evm_hook_storage_update;
 pub mod fungible_hook_call;
@@ -18,8 +20,17 @@ pub mod nft_hook_type;
 pub use evm_hook::EvmHook;
 pub use evm_hook_call::EvmHookCall;
 pub use evm_hook_spec::EvmHookSpec;
+#[cfg(feature = "serde")]
+pub use evm_hook_storage_layout::{
+    EvmHookStorageLayout,
+    EvmHookStorageLocation,
+};
 pub use evm_hook_storage_slot::EvmHookStorageSlot;
-pub use evm_hook_storage_update::EvmHookStorageUpdate;
+pub use evm_hook_storage_update::{
+    EvmHookMappingEntries,
+    EvmHookMappingEntry,
+    EvmHookStorageUpdate,
+};
 pub use fungible_hook_call::FungibleHookCall;
 pub use fungible_hook_type::FungibleHookType;
 pub use hook_call::HookCall;
@@ -11,6 +11,21 @@ pub use key_list::KeyList;
 pub use private_key::PrivateKey;
 pub use public_key::PublicKey;
 
+/// Prefix prepended to messages by the Hedera signed-message standard.
+const SIGNED_MESSAGE_PREFIX: &[u8] = b"\x19Hedera Signed Message:\n";
+
+/// Applies the Hedera signed-message prefix (`"\x19Hedera Signed Message:\n" + len`) to `message`.
+pub(crate) fn prefix_signed_message(message: &[u8]) -> Vec<u8> {
+    let len = message.len().to_string();
+
+    let mut buf = Vec::with_capacity(SIGNED_MESSAGE_PREFIX.len() + len.len() + message.len());
+    buf.extend_from_slice(SIGNED_MESSAGE_PREFIX);
+    buf.extend_from_slice(len.as_bytes());
+    buf.extend_from_slice(message);
+
+    buf
+}
+
 #[derive(Copy, Clone, Debug)]
 pub(crate) enum KeyKind {
     Ed25519,
@@ -593,6 +593,51 @@ impl PrivateKey {
         }
     }
 
+    /// Signs the given `message` using the Hedera signed-message standard.
+    ///
+    /// The message is prefixed with `"\x19Hedera Signed Message:\n"` and its length in bytes
+    /// before being signed, which is what wallets do for `hedera_signMessage` (HIP-820).
+    ///
+    /// # Examples
+    /// ```
+    /// use hiero_sdk::PrivateKey;
+    /// let sk = PrivateKey::generate_ed25519();
+    ///
+    /// let signature = sk.sign_message(b"hello, world");
+    ///
+    /// assert!(sk.public_key().verify_message(b"hello, world", &signature).is_ok());
+    /// ```
+    #[must_use]
+    pub fn sign_message(&self, message: &[u8]) -> Vec<u8> {
+        self.sign(&super::prefix_signed_message(message))
+    }
+
+    /// Signs a 32 byte `prehash` with this ECDSA(secp256k1) key, returning `r || s` and the recovery id.
+    ///
+    /// This is the signature format used by Ethereum.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureCreate`] if this is an `Ed25519` key.
+    pub(crate) fn sign_prehash_recoverable(
+        &self,
+        prehash: &[u8; 32],
+    ) -> crate::Result<([u8; 64], u8)> {
+        match &self.0.data {
+            PrivateKeyData::Ecdsa(key) => {
+                let (signature, recovery_id) =
+                    key.sign_prehash_recoverable(prehash).map_err(Error::signature_create)?;
+
+                let mut buf = [0; 64];
+                buf.copy_from_slice(&signature.to_bytes());
+
+                Ok((buf, recovery_id.to_byte()))
+            }
+            PrivateKeyData::Ed25519(_) => Err(Error::signature_create(
+                "Ed25519 private keys can't create recoverable signatures",
+            )),
+        }
+    }
+
     // I question the reason for this function existing.
     /// Signs the given transaction.
     ///
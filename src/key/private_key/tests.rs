@@ -64,6 +64,41 @@ fn ecdsa_sign() {
     .assert_debug_eq(&hex::encode(signature));
 }
 
+#[test]
+fn ed25519_sign_message() {
+    let private_key = PrivateKey::from_str(
+        "302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e10",
+    )
+    .unwrap();
+
+    let signature = private_key.sign_message(b"hello, world");
+    expect![[r#"
+        "700672dae8b1bdefa8688d78250b832ab3e6f7d666fd90bea6eed566feb3eaf940735f3df4a7f1efe0f8242118692724bd1e214d197f6876813a74bd1abd400c"
+    "#]]
+        .assert_debug_eq(&hex::encode(&signature));
+
+    let public_key = private_key.public_key();
+    public_key.verify_message(b"hello, world", &signature).unwrap();
+    assert_matches!(public_key.verify(b"hello, world", &signature), Err(Error::SignatureVerify(_)));
+}
+
+#[test]
+fn ecdsa_sign_message() {
+    let private_key = PrivateKey::from_str(
+        "3030020100300706052b8104000a042204208776c6b831a1b61ac10dac0304a2843de4716f54b1919bb91a2685d0fe3f3048"
+    )
+    .unwrap();
+
+    let signature = private_key.sign_message(b"hello world");
+
+    let public_key = private_key.public_key();
+    public_key.verify_message(b"hello world", &signature).unwrap();
+    assert_matches!(
+        public_key.verify_message(b"hello world!", &signature),
+        Err(Error::SignatureVerify(_))
+    );
+}
+
 #[test]
 fn ed25519_legacy_derive() {
     // private key was lifted from a Mnemonic test.
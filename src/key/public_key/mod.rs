@@ -352,6 +352,76 @@ impl PublicKey {
         }
     }
 
+    /// Verify a `signature` on a `msg` signed with the Hedera signed-message standard.
+    ///
+    /// See [`PrivateKey::sign_message`](crate::PrivateKey::sign_message).
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature algorithm doesn't match this `PublicKey`.
+    /// - [`Error::SignatureVerify`] if the signature is invalid for this `PublicKey`.
+    pub fn verify_message(&self, msg: &[u8], signature: &[u8]) -> crate::Result<()> {
+        self.verify(&super::prefix_signed_message(msg), signature)
+    }
+
+    /// Recovers the ECDSA(secp256k1) `PublicKey` that created `signature` (`r || s`) over `prehash`.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature or recovery id is malformed, or no key can be recovered.
+    pub(crate) fn recover_from_prehash(
+        prehash: &[u8; 32],
+        signature: &[u8; 64],
+        recovery_id: u8,
+    ) -> crate::Result<Self> {
+        let signature = ecdsa::Signature::from_slice(signature).map_err(Error::signature_verify)?;
+        let recovery_id = ecdsa::RecoveryId::from_byte(recovery_id)
+            .ok_or_else(|| Error::signature_verify("invalid recovery id"))?;
+
+        ecdsa::VerifyingKey::recover_from_prehash(prehash, &signature, recovery_id)
+            .map(Self::ecdsa)
+            .map_err(Error::signature_verify)
+    }
+
+    /// Returns the index of the first invalid signature in `items`, if any.
+    ///
+    /// Ed25519 signatures are checked with a single batch verification,
+    /// which only falls back to checking them one at a time to locate the bad signature when the batch fails.
+    ///
+    /// Batch verification is cofactored, while [`verify`](Self::verify) is cofactorless.
+    /// The two only disagree on signatures crafted with small order components (which no honest signer produces),
+    /// and such a signature may pass here even though [`verify`](Self::verify) rejects it.
+    pub(crate) fn find_invalid_signature(items: &[(Self, &[u8], &[u8])]) -> Option<usize> {
+        let mut messages = Vec::new();
+        let mut signatures = Vec::new();
+        let mut keys = Vec::new();
+
+        for (index, (key, msg, signature)) in items.iter().enumerate() {
+            match &key.0 {
+                PublicKeyData::Ed25519(key) => {
+                    let Ok(signature) = ed25519_dalek::Signature::try_from(*signature) else {
+                        return Some(index);
+                    };
+
+                    messages.push(*msg);
+                    signatures.push(signature);
+                    keys.push(*key);
+                }
+
+                // there's no batch verification for ECDSA, so just check it up front.
+                PublicKeyData::Ecdsa(_) => {
+                    if key.verify(msg, signature).is_err() {
+                        return Some(index);
+                    }
+                }
+            }
+        }
+
+        if ed25519_dalek::verify_batch(&messages, &signatures, &keys).is_ok() {
+            return None;
+        }
+
+        items.iter().position(|(key, msg, signature)| key.verify(msg, signature).is_err())
+    }
+
     pub(crate) fn verify_transaction_sources(
         &self,
         sources: &TransactionSources,
@@ -210,3 +210,32 @@ fn k256_uncompressed_pkcs8_ec_spki_der() {
         "03aaac1c3ac1bea0245b8e00ce1e2018f9eab61b6331fbef7266f2287750a65977"
     )
 }
+
+#[test]
+fn find_invalid_signature() {
+    let keys: Vec<_> = (0..8)
+        .map(|it| match it % 2 {
+            0 => PrivateKey::generate_ed25519(),
+            _ => PrivateKey::generate_ecdsa(),
+        })
+        .collect();
+
+    let mut signatures: Vec<_> = keys.iter().map(|it| it.sign(b"hello")).collect();
+
+    let find = |signatures: &[Vec<u8>]| -> Option<usize> {
+        let items: Vec<_> = keys
+            .iter()
+            .zip(signatures)
+            .map(|(key, signature)| (key.public_key(), b"hello".as_slice(), signature.as_slice()))
+            .collect();
+
+        PublicKey::find_invalid_signature(&items)
+    };
+
+    assert_eq!(find(&signatures), None);
+
+    // the batch fails, so the bad signature is found one at a time.
+    signatures[4][0] ^= 1;
+
+    assert_eq!(find(&signatures), Some(4));
+}
@@ -101,6 +101,7 @@ mod protobuf;
 
 mod account;
 mod address_book;
+mod allowance_spender;
 
 mod batch_transaction;
 mod client;
@@ -124,6 +125,8 @@ mod hooks;
 mod key;
 mod ledger_id;
 mod mirror_query;
+#[cfg(feature = "serde")]
+mod mirror_rest;
 #[cfg(feature = "mnemonic")]
 mod mnemonic;
 mod network_version_info;
@@ -131,7 +134,11 @@ mod network_version_info_query;
 mod node_address;
 mod node_address_book;
 mod node_address_book_query;
+#[cfg(feature = "serde")]
+mod pending_airdrop_flow;
 mod pending_airdrop_id;
+#[cfg(feature = "serde")]
+mod pending_airdrop_query;
 mod pending_airdrop_record;
 mod ping_query;
 mod prng_transaction;
@@ -155,8 +162,13 @@ mod transaction_record;
 mod transaction_record_query;
 mod transaction_response;
 mod transfer;
+mod transfer_planner;
 mod transfer_transaction;
+#[cfg(feature = "serde")]
+mod wallet_connect;
 
+#[cfg(feature = "serde")]
+pub use account::AccountAllowanceQuery;
 pub use account::{
     account_info_flow,
     AccountAllowanceApproveTransaction,
@@ -171,19 +183,34 @@ pub use account::{
     AccountRecordsQuery,
     AccountUpdateTransaction,
     AllProxyStakers,
+    HbarAllowance,
+    NftAllowance,
     ProxyStaker,
+    TokenAllowance,
 };
 pub use address_book::{
     NodeCreateTransaction,
     NodeDeleteTransaction,
     NodeUpdateTransaction,
 };
+pub use allowance_spender::AllowanceSpender;
 pub use batch_transaction::BatchTransaction;
 pub use client::Client;
 pub(crate) use client::Operator;
 pub use contract::{
+    AbiError,
+    AbiEvent,
+    AbiEventParam,
+    AbiFunction,
+    AbiParam,
+    AbiStateMutability,
+    AbiType,
+    AbiValue,
+    ContractAbi,
+    ContractBindings,
     ContractBytecodeQuery,
     ContractCallQuery,
+    ContractCreate2Flow,
     ContractCreateFlow,
     ContractCreateTransaction,
     ContractDeleteTransaction,
@@ -195,9 +222,17 @@ pub use contract::{
     ContractInfoQuery,
     ContractLogInfo,
     ContractNonceInfo,
+    ContractRevert,
     ContractUpdateTransaction,
+    DecodedEvent,
+    DecodedOutputs,
     DelegateContractId,
 };
+#[cfg(feature = "serde")]
+pub use contract::{
+    ContractSimulateBlock,
+    ContractSimulateQuery,
+};
 pub use custom_fee_limit::CustomFeeLimit;
 pub use custom_fixed_fee::CustomFixedFee;
 pub use entity_id::{
@@ -215,11 +250,21 @@ pub use error::{
 };
 pub use ethereum::{
     Eip1559EthereumData,
+    Eip1559EthereumDataBuilder,
+    Eip2930EthereumData,
+    Eip712Domain,
+    Eip712Field,
+    Eip712TypedData,
+    Eip712Value,
+    Eip7702Authorization,
+    Eip7702EthereumData,
+    EthereumAccessListItem,
     EthereumData,
     EthereumFlow,
     EthereumTransaction,
     EvmAddress,
     LegacyEthereumData,
+    LegacyEthereumDataBuilder,
 };
 pub use exchange_rates::{
     ExchangeRate,
@@ -263,6 +308,8 @@ pub use hiero_sdk_proto::services::ResponseCodeEnum as Status;
 pub use hooks::{
     EvmHook,
     EvmHookCall,
+    EvmHookMappingEntries,
+    EvmHookMappingEntry,
     EvmHookSpec,
     EvmHookStorageSlot,
     EvmHookStorageUpdate,
@@ -278,6 +325,11 @@ pub use hooks::{
     NftHookCall,
     NftHookType,
 };
+#[cfg(feature = "serde")]
+pub use hooks::{
+    EvmHookStorageLayout,
+    EvmHookStorageLocation,
+};
 pub use key::{
     Key,
     KeyList,
@@ -291,7 +343,11 @@ pub use mirror_query::{
     MirrorQuery,
 };
 #[cfg(feature = "mnemonic")]
-pub use mnemonic::Mnemonic;
+pub use mnemonic::{
+    Mnemonic,
+    MnemonicCorrection,
+    MnemonicLanguage,
+};
 pub use network_version_info::NetworkVersionInfo;
 pub use network_version_info_query::NetworkVersionInfoQuery;
 pub(crate) use network_version_info_query::NetworkVersionInfoQueryData;
@@ -299,7 +355,14 @@ pub use node_address::NodeAddress;
 pub use node_address_book::NodeAddressBook;
 pub use node_address_book_query::NodeAddressBookQuery;
 pub(crate) use node_address_book_query::NodeAddressBookQueryData;
+#[cfg(feature = "serde")]
+pub use pending_airdrop_flow::{
+    PendingAirdropFlow,
+    PendingAirdropReceipts,
+};
 pub use pending_airdrop_id::PendingAirdropId;
+#[cfg(feature = "serde")]
+pub use pending_airdrop_query::PendingAirdropQuery;
 pub use pending_airdrop_record::PendingAirdropRecord;
 pub use prng_transaction::PrngTransaction;
 pub(crate) use protobuf::{
@@ -333,6 +396,7 @@ pub use token::{
     AnyCustomFee,
     AssessedCustomFee,
     CustomFee,
+    CustomFeeSimulator,
     Fee,
     FeeAssessmentMethod,
     FixedFee,
@@ -343,6 +407,7 @@ pub use token::{
     RoyaltyFee,
     RoyaltyFeeData,
     TokenAirdropTransaction,
+    TokenAmount,
     TokenAssociateTransaction,
     TokenAssociation,
     TokenBurnTransaction,
@@ -358,6 +423,8 @@ pub use token::{
     TokenInfo,
     TokenInfoQuery,
     TokenKeyValidation,
+    TokenMintFlow,
+    TokenMintSerials,
     TokenMintTransaction,
     TokenNftInfo,
     TokenNftInfoQuery,
@@ -374,6 +441,29 @@ pub use token::{
     TokenUpdateTransaction,
     TokenWipeTransaction,
 };
+#[cfg(feature = "serde")]
+pub use token::{
+    AccountNftsQuery,
+    NftMetadata,
+    NftMetadataAttribute,
+    NftMetadataBuilder,
+    NftMetadataFile,
+    NftMetadataLocalization,
+    NftTransferHistoryQuery,
+    NftTransferRecord,
+    OnLedgerNftMetadata,
+    TokenComplianceAction,
+    TokenComplianceEntry,
+    TokenComplianceFlow,
+    TokenComplianceOutcome,
+    TokenComplianceReport,
+    TokenDelivery,
+    TokenHolder,
+    TokenHoldersQuery,
+    TokenTransferFlow,
+    TokenTransferFlowReceipts,
+    TokenTransferRecipient,
+};
 pub use topic::{
     TopicCreateTransaction,
     TopicDeleteTransaction,
@@ -398,7 +488,20 @@ pub use transaction_record_query::TransactionRecordQuery;
 pub(crate) use transaction_record_query::TransactionRecordQueryData;
 pub use transaction_response::TransactionResponse;
 pub use transfer::Transfer;
+pub use transfer_planner::{
+    TransferPlanReceipts,
+    TransferPlanner,
+};
 pub use transfer_transaction::TransferTransaction;
+#[cfg(feature = "serde")]
+pub use wallet_connect::{
+    wallet_connect_execute_result_from_json,
+    wallet_connect_execute_result_json,
+    WalletConnectAccountId,
+    WalletConnectMethod,
+    WalletConnectRequest,
+    WalletConnectSignature,
+};
 
 /// Like [`arc_swap::ArcSwapOption`] but with a [`triomphe::Arc`].
 pub(crate) type ArcSwapOption<T> = arc_swap::ArcSwapAny<Option<triomphe::Arc<T>>>;
@@ -0,0 +1,491 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Plumbing shared by requests to the mirror node REST API.
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures_core::stream::BoxStream;
+use futures_util::TryStreamExt;
+use http_body_util::BodyExt as _;
+use hyper::body::Incoming;
+use hyper::{
+    Method,
+    Request,
+    Response,
+    StatusCode,
+};
+use hyper_openssl::client::legacy::HttpsConnector;
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::client::legacy::Client as HttpClient;
+use hyper_util::rt::TokioExecutor;
+use openssl::ssl::{
+    SslConnector,
+    SslMethod,
+    SslVerifyMode,
+};
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::{
+    Client,
+    Error,
+};
+
+/// Default maximum number of retry attempts.
+pub(crate) const DEFAULT_MAX_ATTEMPTS: usize = 10;
+
+/// Default maximum backoff duration.
+pub(crate) const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(8);
+
+/// Initial backoff delay.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Sends a request to the mirror node REST API, retrying on connection errors and retryable statuses.
+///
+/// `body` is the content type and the body of the request.
+///
+/// Returns the final status and body of the response, which may be an error status.
+pub(crate) async fn send(
+    method: Method,
+    url: &str,
+    body: Option<(&str, Bytes)>,
+    max_attempts: usize,
+    max_backoff: Duration,
+) -> crate::Result<(StatusCode, String)> {
+    let http_client = build_http_client(url);
+
+    let mut attempt = 0;
+    loop {
+        let mut request =
+            Request::builder().method(method.clone()).uri(url).header("Accept", "application/json");
+
+        if let Some((content_type, _)) = &body {
+            request = request.header("Content-Type", *content_type);
+        }
+
+        let request = request
+            .body(http_body_util::Full::new(
+                body.as_ref().map(|it| it.1.clone()).unwrap_or_default(),
+            ))
+            .map_err(|e| Error::basic_parse(e.to_string()))?;
+
+        let result = http_client.request(request).await;
+
+        match result {
+            Ok(response) => {
+                let status = response.status();
+
+                if !status.is_success() && should_retry_status(status) && attempt < max_attempts {
+                    attempt += 1;
+                    let delay = compute_backoff(attempt, max_backoff);
+                    tokio::time::sleep(delay).await;
+                    continue;
+                }
+
+                let body = read_body(response).await.map_err(|e| {
+                    Error::basic_parse(format!("failed to read response body: {e}"))
+                })?;
+
+                return Ok((status, body));
+            }
+            Err(e) => {
+                if attempt < max_attempts {
+                    attempt += 1;
+                    let delay = compute_backoff(attempt, max_backoff);
+                    tokio::time::sleep(delay).await;
+                    continue;
+                }
+                return Err(Error::basic_parse(format!(
+                    "mirror node request failed after {attempt} attempts: {e}"
+                )));
+            }
+        }
+    }
+}
+
+/// Fetches a list from the mirror node REST API, following `links.next` through every page.
+///
+/// `path` is relative to `base_url` (see [`base_url`]), and `key` names the array of items in each page.
+///
+/// Stops once `limit` items have been fetched, if it's set.
+pub(crate) async fn get_all(
+    base_url: &str,
+    path: &str,
+    key: &'static str,
+    limit: Option<usize>,
+    max_attempts: usize,
+    max_backoff: Duration,
+) -> crate::Result<Vec<Value>> {
+    stream_all(base_url.to_owned(), path, key, limit, max_attempts, max_backoff).try_collect().await
+}
+
+/// Streams a list from the mirror node REST API, fetching each page (following `links.next`) once it's needed.
+///
+/// Takes the same arguments as [`get_all`], and ends after the first error.
+pub(crate) fn stream_all(
+    base_url: String,
+    path: &str,
+    key: &'static str,
+    limit: Option<usize>,
+    max_attempts: usize,
+    max_backoff: Duration,
+) -> BoxStream<'static, crate::Result<Value>> {
+    stream_pages(base_url, path, limit, move |url| async move {
+        get_page(&url, key, max_attempts, max_backoff).await
+    })
+}
+
+/// Streams the items of a list, fetching each page with `get_page` (following `links.next`) once it's needed.
+fn stream_pages<F, Fut>(
+    base_url: String,
+    path: &str,
+    limit: Option<usize>,
+    get_page: F,
+) -> BoxStream<'static, crate::Result<Value>>
+where
+    F: Fn(String) -> Fut + Send + 'static,
+    Fut: Future<Output = crate::Result<(Vec<Value>, Value)>> + Send,
+{
+    struct State<F> {
+        base_url: String,
+        next_url: Option<String>,
+        items: VecDeque<Value>,
+        remaining: Option<usize>,
+        get_page: F,
+    }
+
+    let state = State {
+        next_url: Some(format!("{base_url}{path}")),
+        base_url,
+        items: VecDeque::new(),
+        remaining: limit,
+        get_page,
+    };
+
+    let stream = futures_util::stream::unfold(state, |mut state| async move {
+        loop {
+            if state.remaining == Some(0) {
+                return None;
+            }
+
+            if let Some(item) = state.items.pop_front() {
+                state.remaining = state.remaining.map(|it| it - 1);
+                return Some((Ok(item), state));
+            }
+
+            let url = state.next_url.take()?;
+
+            match (state.get_page)(url).await {
+                Ok((items, page)) => {
+                    state.items = items.into();
+                    state.next_url = next_url(&state.base_url, &page);
+                }
+
+                Err(error) => return Some((Err(error), state)),
+            }
+        }
+    });
+
+    Box::pin(stream)
+}
+
+/// Fetches a single page of a list, returning its items and the page itself.
+async fn get_page(
+    url: &str,
+    key: &str,
+    max_attempts: usize,
+    max_backoff: Duration,
+) -> crate::Result<(Vec<Value>, Value)> {
+    let (status, body) = send(Method::GET, url, None, max_attempts, max_backoff).await?;
+
+    if !status.is_success() {
+        return Err(Error::basic_parse(format!(
+            "mirror node request `{url}` failed with HTTP {status}: {body}"
+        )));
+    }
+
+    let mut page: Value = serde_json::from_str(&body)
+        .map_err(|e| Error::basic_parse(format!("failed to parse mirror node JSON: {e}")))?;
+
+    let Some(Value::Array(items)) = page.get_mut(key).map(Value::take) else {
+        return Err(Error::basic_parse(format!("mirror node response is missing `{key}`")));
+    };
+
+    Ok((items, page))
+}
+
+/// Parses a mirror node timestamp, like `1651560386.661997287`.
+pub(crate) fn parse_timestamp(timestamp: &str) -> crate::Result<OffsetDateTime> {
+    let (seconds, nanos) = timestamp.split_once('.').unwrap_or((timestamp, "0"));
+
+    let seconds = seconds.parse().map_err(Error::basic_parse)?;
+    let nanos = format!("{nanos:0<9}").parse().map_err(Error::basic_parse)?;
+
+    Ok(OffsetDateTime::from_unix_timestamp(seconds).map_err(Error::basic_parse)?
+        + time::Duration::nanoseconds(nanos))
+}
+
+/// Formats `timestamp` the way the mirror node expects it in query parameters, like `1651560386.661997287`.
+pub(crate) fn format_timestamp(timestamp: OffsetDateTime) -> String {
+    format!("{}.{:09}", timestamp.unix_timestamp(), timestamp.nanosecond())
+}
+
+/// Returns the URL of the page after `page`, if there is one.
+fn next_url(base_url: &str, page: &Value) -> Option<String> {
+    // `next` is a path from the root of the server, like `/api/v1/accounts?limit=25&account.id=gt:0.0.2`.
+    let next = page.pointer("/links/next").and_then(Value::as_str)?;
+    let origin = base_url.strip_suffix("/api/v1").unwrap_or(base_url);
+
+    Some(format!("{origin}{next}"))
+}
+
+/// Determines whether a response status code is retryable.
+fn should_retry_status(status: StatusCode) -> bool {
+    matches!(status.as_u16(), 408 | 429 | 500 | 502 | 503 | 504)
+}
+
+/// Computes exponential backoff delay: `min(500ms * 2^attempt, max_backoff)`.
+fn compute_backoff(attempt: usize, max_backoff: Duration) -> Duration {
+    let delay = INITIAL_BACKOFF.saturating_mul(1u32.wrapping_shl(attempt as u32));
+    delay.min(max_backoff)
+}
+
+/// Constructs the mirror node REST base URL from the client's mirror network configuration.
+pub(crate) fn base_url(client: &Client) -> String {
+    let addresses = client.mirror_network();
+    let address = addresses.first().expect("mirror network must have at least one address");
+
+    base_url_from_address(address)
+}
+
+fn base_url_from_address(address: &str) -> String {
+    // Parse host and port
+    let (host, port_str) = if let Some(idx) = address.rfind(':') {
+        (&address[..idx], &address[idx + 1..])
+    } else {
+        (address, "443")
+    };
+
+    let port: u16 = port_str.parse().unwrap_or(443);
+
+    let is_localhost = host.contains("localhost") || host.contains("127.0.0.1");
+
+    if is_localhost {
+        // For local development, map gRPC port 5600 to REST port 5551
+        let rest_port = if port == 5600 { 5551 } else { port };
+        format!("http://{host}:{rest_port}/api/v1")
+    } else {
+        let scheme = if port == 80 { "http" } else { "https" };
+        if (scheme == "https" && port == 443) || (scheme == "http" && port == 80) {
+            format!("{scheme}://{host}/api/v1")
+        } else {
+            format!("{scheme}://{host}:{port}/api/v1")
+        }
+    }
+}
+
+/// Builds an HTTP client appropriate for the given URL.
+fn build_http_client(
+    url: &str,
+) -> HttpClient<HttpsConnector<HttpConnector>, http_body_util::Full<Bytes>> {
+    let mut http = HttpConnector::new();
+    http.enforce_http(false);
+
+    if url.starts_with("https") {
+        let mut ssl_builder = SslConnector::builder(SslMethod::tls()).unwrap();
+        ssl_builder.set_verify(SslVerifyMode::PEER);
+        let https = HttpsConnector::with_connector(http, ssl_builder).unwrap();
+        HttpClient::builder(TokioExecutor::new()).build(https)
+    } else {
+        // For HTTP (localhost), still use HttpsConnector but with permissive settings
+        let mut ssl_builder = SslConnector::builder(SslMethod::tls()).unwrap();
+        ssl_builder.set_verify(SslVerifyMode::NONE);
+        let https = HttpsConnector::with_connector(http, ssl_builder).unwrap();
+        HttpClient::builder(TokioExecutor::new()).build(https)
+    }
+}
+
+/// Reads the full body of an HTTP response as a string.
+async fn read_body(response: Response<Incoming>) -> Result<String, String> {
+    let body_bytes = response.into_body().collect().await.map_err(|e| e.to_string())?.to_bytes();
+    String::from_utf8(body_bytes.to_vec()).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    use futures_util::StreamExt;
+    use serde_json::{
+        json,
+        Value,
+    };
+
+    use super::{
+        base_url_from_address,
+        compute_backoff,
+        format_timestamp,
+        next_url,
+        parse_timestamp,
+        stream_pages,
+    };
+    use crate::Error;
+
+    const BASE_URL: &str = "https://testnet.mirrornode.hedera.com/api/v1";
+
+    /// Streams a list of 5 accounts in pages of 2 (with an empty page in the middle), up to `limit`.
+    ///
+    /// Returns the account numbers (`None` for an error) and the URLs of the pages fetched.
+    async fn stream(
+        limit: Option<usize>,
+        fail_at: Option<&str>,
+    ) -> (Vec<Option<u64>>, Vec<String>) {
+        let page = |items: &[u64], next: Option<&str>| {
+            let items: Vec<Value> = items.iter().map(|it| json!(it)).collect();
+
+            (items, json!({ "accounts": [], "links": { "next": next } }))
+        };
+
+        let pages = HashMap::from([
+            ("/accounts?limit=2", page(&[1, 2], Some("/api/v1/accounts?limit=2&account.id=gt:2"))),
+            (
+                "/accounts?limit=2&account.id=gt:2",
+                page(&[3, 4], Some("/api/v1/accounts?limit=2&account.id=gt:4")),
+            ),
+            (
+                "/accounts?limit=2&account.id=gt:4",
+                page(&[], Some("/api/v1/accounts?limit=2&account.id=gt:5")),
+            ),
+            ("/accounts?limit=2&account.id=gt:5", page(&[5], None)),
+        ]);
+
+        let (fetched_tx, fetched_rx) = mpsc::channel();
+        let fail_at = fail_at.map(str::to_owned);
+
+        let items =
+            stream_pages(BASE_URL.to_owned(), "/accounts?limit=2", limit, move |url: String| {
+                let path = url.strip_prefix(BASE_URL).unwrap().to_owned();
+                fetched_tx.send(path.clone()).unwrap();
+
+                let page = match fail_at.as_deref() == Some(path.as_str()) {
+                    true => Err(Error::basic_parse("mirror node request failed")),
+                    false => Ok(pages[path.as_str()].clone()),
+                };
+
+                async move { page }
+            });
+
+        let items = items.map(|it| it.ok().map(|it| it.as_u64().unwrap())).collect().await;
+        let fetched = fetched_rx.try_iter().collect();
+
+        (items, fetched)
+    }
+
+    #[tokio::test]
+    async fn stream_follows_pages() {
+        let (items, fetched) = stream(None, None).await;
+
+        assert_eq!(items, [Some(1), Some(2), Some(3), Some(4), Some(5)]);
+        assert_eq!(
+            fetched,
+            [
+                "/accounts?limit=2",
+                "/accounts?limit=2&account.id=gt:2",
+                "/accounts?limit=2&account.id=gt:4",
+                "/accounts?limit=2&account.id=gt:5",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn stream_limit() {
+        // the limit falls in the middle of the second page, so the third is never fetched.
+        let (items, fetched) = stream(Some(3), None).await;
+
+        assert_eq!(items, [Some(1), Some(2), Some(3)]);
+        assert_eq!(fetched, ["/accounts?limit=2", "/accounts?limit=2&account.id=gt:2"]);
+
+        // a limit at the end of a page doesn't fetch the next one either.
+        let (items, fetched) = stream(Some(2), None).await;
+
+        assert_eq!(items, [Some(1), Some(2)]);
+        assert_eq!(fetched, ["/accounts?limit=2"]);
+
+        let (items, fetched) = stream(Some(10), None).await;
+
+        assert_eq!(items.len(), 5);
+        assert_eq!(fetched.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn stream_ends_after_error() {
+        let (items, fetched) = stream(None, Some("/accounts?limit=2&account.id=gt:2")).await;
+
+        assert_eq!(items, [Some(1), Some(2), None]);
+        assert_eq!(fetched, ["/accounts?limit=2", "/accounts?limit=2&account.id=gt:2"]);
+    }
+
+    #[test]
+    fn mirror_rest_url_mainnet() {
+        let url = base_url_from_address("mainnet-public.mirrornode.hedera.com:443");
+        assert_eq!(url, "https://mainnet-public.mirrornode.hedera.com/api/v1");
+    }
+
+    #[test]
+    fn mirror_rest_url_localhost() {
+        let url = base_url_from_address("127.0.0.1:5600");
+        assert_eq!(url, "http://127.0.0.1:5551/api/v1");
+    }
+
+    #[test]
+    fn mirror_rest_url_localhost_custom_port() {
+        let url = base_url_from_address("localhost:8080");
+        assert_eq!(url, "http://localhost:8080/api/v1");
+    }
+
+    #[test]
+    fn backoff_computation() {
+        assert_eq!(compute_backoff(0, Duration::from_secs(8)), Duration::from_millis(500));
+        assert_eq!(compute_backoff(1, Duration::from_secs(8)), Duration::from_millis(1000));
+        assert_eq!(compute_backoff(2, Duration::from_secs(8)), Duration::from_millis(2000));
+        assert_eq!(compute_backoff(3, Duration::from_secs(8)), Duration::from_millis(4000));
+        assert_eq!(compute_backoff(4, Duration::from_secs(8)), Duration::from_millis(8000));
+        assert_eq!(compute_backoff(5, Duration::from_secs(8)), Duration::from_millis(8000));
+    }
+
+    #[test]
+    fn next_page_url() {
+        let page = json!({
+            "accounts": [],
+            "links": { "next": "/api/v1/accounts?limit=25&account.id=gt:0.0.2" }
+        });
+
+        assert_eq!(
+            next_url("https://testnet.mirrornode.hedera.com/api/v1", &page).as_deref(),
+            Some("https://testnet.mirrornode.hedera.com/api/v1/accounts?limit=25&account.id=gt:0.0.2")
+        );
+
+        assert_eq!(
+            next_url("http://127.0.0.1:5551/api/v1", &json!({ "links": { "next": null } })),
+            None
+        );
+    }
+
+    #[test]
+    fn timestamps() {
+        let timestamp = parse_timestamp("1651560386.061997287").unwrap();
+
+        assert_eq!(timestamp.unix_timestamp(), 1651560386);
+        assert_eq!(timestamp.nanosecond(), 61997287);
+        assert_eq!(format_timestamp(timestamp), "1651560386.061997287");
+
+        assert_eq!(parse_timestamp("1651560386").unwrap().unix_timestamp(), 1651560386);
+        assert_eq!(parse_timestamp("1651560386.5").unwrap().nanosecond(), 500_000_000);
+        assert!(parse_timestamp("soon").is_err());
+    }
+}
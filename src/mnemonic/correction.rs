@@ -0,0 +1,274 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use unicode_normalization::UnicodeNormalization;
+
+use super::language::WordList;
+use super::{
+    checksums,
+    Mnemonic,
+    MnemonicData,
+};
+
+/// A single-word replacement that may repair a [`Mnemonic`] that failed to parse.
+///
+/// See [`Mnemonic::suggest_corrections`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MnemonicCorrection {
+    /// The index of the word to replace.
+    pub index: usize,
+
+    /// The suggested replacement word.
+    pub word: String,
+
+    /// `true` if applying *only* this replacement results in a valid mnemonic.
+    pub fixes_checksum: bool,
+}
+
+impl Mnemonic {
+    /// Suggests single-word replacements that may repair this mnemonic.
+    ///
+    /// This is intended to be used with the `mnemonic` returned in an [`Error::MnemonicParse`](crate::Error::MnemonicParse):
+    /// - Words that aren't in the word list get near-miss suggestions (typos, missing accents, unambiguous prefixes).
+    /// - If every word is known but the checksum is wrong,
+    ///   near-miss substitutions for each word that make the checksum valid are suggested.
+    ///
+    /// Suggestions that fix the checksum are ordered first, followed by the closest matches.
+    ///
+    /// Returns an empty list for valid mnemonics, legacy mnemonics, and mnemonics of an unsupported length.
+    #[must_use]
+    pub fn suggest_corrections(&self) -> Vec<MnemonicCorrection> {
+        let MnemonicData::V2V3(mnemonic) = &self.0 else {
+            return Vec::new();
+        };
+
+        let words = &mnemonic.words;
+
+        if !matches!(words.len(), 12 | 24) {
+            return Vec::new();
+        }
+
+        let word_list = mnemonic.language.word_list();
+        let indecies: Vec<_> = words.iter().map(|it| word_list.index_of(it)).collect();
+
+        let unknown_words: Vec<_> =
+            indecies.iter().enumerate().filter_map(|(i, it)| it.is_none().then_some(i)).collect();
+
+        // (index, word index, distance, fixes_checksum)
+        let mut candidates: Vec<(usize, u16, usize, bool)> = Vec::new();
+
+        match *unknown_words {
+            [] => {
+                let indecies: Vec<_> = indecies.into_iter().flatten().collect();
+
+                let (expected, actual) = checksums(&indecies);
+                if expected == actual {
+                    return Vec::new();
+                }
+
+                for (index, word) in words.iter().enumerate() {
+                    for (candidate, distance) in near_misses(word, word_list) {
+                        if candidate == indecies[index] {
+                            continue;
+                        }
+
+                        let mut fixed = indecies.clone();
+                        fixed[index] = candidate;
+
+                        let (expected, actual) = checksums(&fixed);
+                        if expected == actual {
+                            candidates.push((index, candidate, distance, true));
+                        }
+                    }
+                }
+            }
+
+            [index] => {
+                let mut fixed: Vec<_> = indecies.iter().map(|it| it.unwrap_or_default()).collect();
+
+                for (candidate, distance) in near_misses(&words[index], word_list) {
+                    fixed[index] = candidate;
+
+                    let (expected, actual) = checksums(&fixed);
+                    candidates.push((index, candidate, distance, expected == actual));
+                }
+            }
+
+            // with more than one unknown word no single replacement can produce a valid mnemonic.
+            _ => {
+                for &index in &unknown_words {
+                    for (candidate, distance) in near_misses(&words[index], word_list) {
+                        candidates.push((index, candidate, distance, false));
+                    }
+                }
+            }
+        }
+
+        candidates.sort_by_key(|&(index, candidate, distance, fixes_checksum)| {
+            (!fixes_checksum, distance, index, candidate)
+        });
+
+        candidates
+            .into_iter()
+            .map(|(index, candidate, _, fixes_checksum)| MnemonicCorrection {
+                index,
+                word: word_list.word(candidate).to_owned(),
+                fixes_checksum,
+            })
+            .collect()
+    }
+}
+
+/// Finds the words in `word_list` that are "close" to `word`, along with their edit distance.
+///
+/// A word is close if it's within a small edit distance (ignoring case and accents),
+/// or if it shares `word`'s first four letters (`BIP-39` words are unique by their first four letters).
+fn near_misses(word: &str, word_list: &WordList) -> Vec<(u16, usize)> {
+    let word = fold(word);
+    let max_distance = if word.len() <= 4 { 1 } else { 2 };
+    let prefix = (word.len() >= 4).then(|| &word[..4]);
+
+    word_list
+        .words()
+        .iter()
+        .zip(0..)
+        .filter_map(|(candidate, index)| {
+            let candidate = fold(candidate);
+            let distance = edit_distance(&word, &candidate);
+
+            let is_near = distance <= max_distance
+                || prefix.is_some_and(|prefix| candidate.starts_with(prefix));
+
+            is_near.then_some((index, distance))
+        })
+        .collect()
+}
+
+/// Lowercases `word` and strips combining diacritical marks, so that `"eleve"` matches `"élève"`.
+fn fold(word: &str) -> Vec<char> {
+    word.nfkd()
+        .filter(|it| !('\u{300}'..='\u{36f}').contains(it))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Edit distance between `a` and `b`, counting an adjacent transposition as a single edit.
+///
+/// (This is the "optimal string alignment" variant of the Damerau-Levenshtein distance.)
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    // distances[i][j] is the distance between `a[..i]` and `b[..j]`.
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for (j, it) in distances[0].iter_mut().enumerate() {
+        *it = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use assert_matches::assert_matches;
+
+    use super::edit_distance;
+    use crate::{
+        Error,
+        Mnemonic,
+    };
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn edit_distance_works() {
+        assert_eq!(edit_distance(&chars("kitten"), &chars("sitting")), 3);
+        assert_eq!(edit_distance(&chars("acount"), &chars("account")), 1);
+        assert_eq!(edit_distance(&chars("flpi"), &chars("flip")), 1);
+        assert_eq!(edit_distance(&chars(""), &chars("abc")), 3);
+        assert_eq!(edit_distance(&chars("same"), &chars("same")), 0);
+    }
+
+    #[test]
+    fn suggests_typo_fix() {
+        // "flip" -> "flpi"
+        const MNEMONIC: &str = concat!(
+            "inmate flpi alley wear offer often ",
+            "piece magnet surge toddler submit right ",
+            "radio absent pear floor belt raven ",
+            "price stove replace reduce plate home"
+        );
+
+        let mnemonic = assert_matches!(Mnemonic::from_str(MNEMONIC), Err(Error::MnemonicParse { mnemonic, .. }) => mnemonic);
+
+        let corrections = mnemonic.suggest_corrections();
+        let first = &corrections[0];
+
+        assert_eq!(first.index, 1);
+        assert_eq!(first.word, "flip");
+        assert!(first.fixes_checksum);
+    }
+
+    #[test]
+    fn suggests_checksum_fix() {
+        // "wear" -> "near", both of which are in the word list.
+        const MNEMONIC: &str = concat!(
+            "inmate flip alley near offer often ",
+            "piece magnet surge toddler submit right ",
+            "radio absent pear floor belt raven ",
+            "price stove replace reduce plate home"
+        );
+
+        let mnemonic = assert_matches!(Mnemonic::from_str(MNEMONIC), Err(Error::MnemonicParse { mnemonic, .. }) => mnemonic);
+
+        let corrections = mnemonic.suggest_corrections();
+
+        assert!(corrections.iter().all(|it| it.fixes_checksum));
+        assert!(corrections.iter().any(|it| it.index == 3 && it.word == "wear"));
+    }
+
+    #[test]
+    fn suggests_for_multiple_unknown_words() {
+        const MNEMONIC: &str =
+            "evoke rich bicycel fire promote climb zero squeeze little spoil slight damaeg";
+
+        let mnemonic = assert_matches!(Mnemonic::from_str(MNEMONIC), Err(Error::MnemonicParse { mnemonic, .. }) => mnemonic);
+
+        let corrections = mnemonic.suggest_corrections();
+
+        assert!(corrections.iter().all(|it| !it.fixes_checksum));
+        assert!(corrections.iter().any(|it| it.index == 2 && it.word == "bicycle"));
+        assert!(corrections.iter().any(|it| it.index == 11 && it.word == "damage"));
+    }
+
+    #[test]
+    fn valid_mnemonic_has_no_suggestions() {
+        let mnemonic = Mnemonic::from_str(
+            "evoke rich bicycle fire promote climb zero squeeze little spoil slight damage",
+        )
+        .unwrap();
+
+        assert_eq!(mnemonic.suggest_corrections(), Vec::new());
+    }
+}
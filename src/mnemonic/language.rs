@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::fmt;
+
+use once_cell::sync::Lazy;
+
+/// The language of a `BIP-39` word list.
+///
+/// Non-English word lists are the official lists from the `BIP-39` repository,
+/// stored (and compared) in Unicode NFKD form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MnemonicLanguage {
+    /// English.
+    English,
+    /// Spanish.
+    Spanish,
+    /// French.
+    French,
+    /// Italian.
+    Italian,
+    /// Japanese.
+    Japanese,
+    /// Korean.
+    Korean,
+    /// Chinese (Simplified).
+    ChineseSimplified,
+    /// Chinese (Traditional).
+    ChineseTraditional,
+    /// Czech.
+    Czech,
+    /// Portuguese.
+    Portuguese,
+}
+
+impl MnemonicLanguage {
+    /// Every supported language, in the order they're tried during auto-detection.
+    pub const ALL: [Self; 10] = [
+        Self::English,
+        Self::Spanish,
+        Self::French,
+        Self::Italian,
+        Self::Japanese,
+        Self::Korean,
+        Self::ChineseSimplified,
+        Self::ChineseTraditional,
+        Self::Czech,
+        Self::Portuguese,
+    ];
+
+    pub(super) fn word_list(self) -> &'static WordList {
+        static ENGLISH: Lazy<WordList> =
+            Lazy::new(|| WordList::new(include_str!("bip39-english.txt")));
+        static SPANISH: Lazy<WordList> =
+            Lazy::new(|| WordList::new(include_str!("bip39-spanish.txt")));
+        static FRENCH: Lazy<WordList> =
+            Lazy::new(|| WordList::new(include_str!("bip39-french.txt")));
+        static ITALIAN: Lazy<WordList> =
+            Lazy::new(|| WordList::new(include_str!("bip39-italian.txt")));
+        static JAPANESE: Lazy<WordList> =
+            Lazy::new(|| WordList::new(include_str!("bip39-japanese.txt")));
+        static KOREAN: Lazy<WordList> =
+            Lazy::new(|| WordList::new(include_str!("bip39-korean.txt")));
+        static CHINESE_SIMPLIFIED: Lazy<WordList> =
+            Lazy::new(|| WordList::new(include_str!("bip39-chinese-simplified.txt")));
+        static CHINESE_TRADITIONAL: Lazy<WordList> =
+            Lazy::new(|| WordList::new(include_str!("bip39-chinese-traditional.txt")));
+        static CZECH: Lazy<WordList> = Lazy::new(|| WordList::new(include_str!("bip39-czech.txt")));
+        static PORTUGUESE: Lazy<WordList> =
+            Lazy::new(|| WordList::new(include_str!("bip39-portuguese.txt")));
+
+        match self {
+            Self::English => &ENGLISH,
+            Self::Spanish => &SPANISH,
+            Self::French => &FRENCH,
+            Self::Italian => &ITALIAN,
+            Self::Japanese => &JAPANESE,
+            Self::Korean => &KOREAN,
+            Self::ChineseSimplified => &CHINESE_SIMPLIFIED,
+            Self::ChineseTraditional => &CHINESE_TRADITIONAL,
+            Self::Czech => &CZECH,
+            Self::Portuguese => &PORTUGUESE,
+        }
+    }
+
+    /// The separator used between words when displaying a mnemonic in this language.
+    ///
+    /// `BIP-39` uses an ideographic space for Japanese, which NFKD normalizes to a regular space.
+    pub(super) fn separator(self) -> &'static str {
+        match self {
+            Self::Japanese => "\u{3000}",
+            _ => " ",
+        }
+    }
+}
+
+impl fmt::Display for MnemonicLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::English => "English",
+            Self::Spanish => "Spanish",
+            Self::French => "French",
+            Self::Italian => "Italian",
+            Self::Japanese => "Japanese",
+            Self::Korean => "Korean",
+            Self::ChineseSimplified => "Chinese (Simplified)",
+            Self::ChineseTraditional => "Chinese (Traditional)",
+            Self::Czech => "Czech",
+            Self::Portuguese => "Portuguese",
+        })
+    }
+}
+
+pub(super) struct WordList {
+    words: Vec<&'static str>,
+    // most of the lists aren't sorted by byte value, so `binary_search` is out.
+    indecies: HashMap<&'static str, u16>,
+}
+
+impl WordList {
+    fn new(text: &'static str) -> Self {
+        let words: Vec<_> = text.split_whitespace().collect();
+        assert_eq!(words.len(), 2048);
+
+        let indecies =
+            words.iter().enumerate().map(|(index, &word)| (word, index as u16)).collect();
+
+        Self { words, indecies }
+    }
+
+    pub(super) fn words(&self) -> &[&'static str] {
+        &self.words
+    }
+
+    pub(super) fn word(&self, index: u16) -> &'static str {
+        self.words[usize::from(index)]
+    }
+
+    pub(super) fn index_of(&self, word: &str) -> Option<u16> {
+        self.indecies.get(word).copied()
+    }
+}
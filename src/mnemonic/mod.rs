@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use std::cmp::Ordering;
+mod correction;
+mod language;
+
 use std::fmt;
 use std::str::FromStr;
 
@@ -15,7 +17,10 @@ use rand::{
     RngCore,
 };
 use sha2::Digest;
+use unicode_normalization::UnicodeNormalization;
 
+pub use self::correction::MnemonicCorrection;
+pub use self::language::MnemonicLanguage;
 use crate::error::{
     MnemonicEntropyError,
     MnemonicParseError,
@@ -25,24 +30,8 @@ use crate::{
     PrivateKey,
 };
 
-const BIP39: &str = include_str!("bip39-english.txt");
 const LEGACY: &str = include_str!("legacy-english.txt");
 
-// `is_sorted` is unstable.
-// this is just lifted from the stdlib impl.
-fn is_sorted<T: PartialOrd>(vs: &[T]) -> bool {
-    vs.windows(2).all(|w| w[0].partial_cmp(&w[1]).map_or(false, |o| o != Ordering::Greater))
-}
-
-// sadly can't do this with a const.
-static BIP39_WORD_LIST: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    let it: Vec<_> = BIP39.split_whitespace().collect();
-    // if the word list is sorted we can use the power of `binary_search` which turns the `O(n)` search into a `O(log n)`
-    // n is a constant here, but perf is perf.
-    assert!(is_sorted(&it));
-    it
-});
-
 // the legacy word list isn't sorted.
 static LEGACY_WORD_LIST: Lazy<Vec<&'static str>> =
     Lazy::new(|| LEGACY.split_whitespace().collect());
@@ -76,9 +65,21 @@ impl Mnemonic {
         matches!(&self.0, MnemonicData::V1(_))
     }
 
+    /// Returns the language of this mnemonic's word list, or `None` if `self` is a legacy mnemonic.
+    #[must_use]
+    pub fn language(&self) -> Option<MnemonicLanguage> {
+        match &self.0 {
+            MnemonicData::V1(_) => None,
+            MnemonicData::V2V3(it) => Some(it.language),
+        }
+    }
+
     // todo(sr): Not too happy about requiring a `Vec<String>`
     /// Constructs a `Mnemonic` from a 24-word list.
     ///
+    /// The word list language is detected automatically,
+    /// words are compared after Unicode NFKD normalization.
+    ///
     /// # Errors
     /// * if the mnemonic has an invalid length.
     /// * if the mnemonic uses invalid words.
@@ -89,53 +90,59 @@ impl Mnemonic {
             Err(words) => words,
         };
 
-        let mnemonic = Self(MnemonicV2V3 { words }.into());
+        let words: Vec<String> = words.iter().map(|it| it.nfkd().collect()).collect();
 
-        if mnemonic.words().len() != 12 && mnemonic.words().len() != 24 {
+        if words.len() != 12 && words.len() != 24 {
             return Err(Error::MnemonicParse {
-                reason: MnemonicParseError::BadLength(mnemonic.words().len()),
-                mnemonic,
+                reason: MnemonicParseError::BadLength(words.len()),
+                mnemonic: Self(MnemonicV2V3 { words, language: MnemonicLanguage::English }.into()),
             });
         }
 
-        let mut word_indecies = Vec::with_capacity(mnemonic.words().len());
-        let mut unknown_words = Vec::new();
-        for (word_index, word) in mnemonic.words().iter().enumerate() {
-            match BIP39_WORD_LIST.binary_search(&&**word) {
-                Ok(i) => {
-                    word_indecies.push(i as u16);
+        // the lists overlap a little (some words are in both the English and French lists, for instance),
+        // so the first language that knows every word *and* has a matching checksum wins.
+        let mut checksum_mismatch = None;
+        let mut fewest_unknown_words: Option<(MnemonicLanguage, Vec<usize>)> = None;
+        for language in MnemonicLanguage::ALL {
+            match word_indecies(&words, language) {
+                Ok(indecies) => {
+                    let (expected, actual) = checksums(&indecies);
+
+                    if expected == actual {
+                        return Ok(Self(MnemonicV2V3 { words, language }.into()));
+                    }
+
+                    checksum_mismatch.get_or_insert((language, expected, actual));
                 }
-                // error (word not in list)
-                Err(_) => {
-                    unknown_words.push(word_index);
+
+                Err(unknown_words) => {
+                    if fewest_unknown_words
+                        .as_ref()
+                        .is_none_or(|(_, fewest)| unknown_words.len() < fewest.len())
+                    {
+                        fewest_unknown_words = Some((language, unknown_words));
+                    }
                 }
             }
         }
 
-        if !unknown_words.is_empty() {
-            return Err(Error::MnemonicParse {
-                reason: MnemonicParseError::UnknownWords(unknown_words),
-                mnemonic,
-            });
-        }
-
-        let (entropy, actual_checksum) = incecies_to_entropy_and_checksum(&word_indecies);
-        let expected_checksum = checksum(&entropy);
-        let expected_checksum =
-            if mnemonic.words().len() == 12 { expected_checksum & 0xf0 } else { expected_checksum };
-
-        if expected_checksum != actual_checksum {
+        if let Some((language, expected, actual)) = checksum_mismatch {
             // error: checksum mismatch.
             return Err(Error::MnemonicParse {
-                reason: MnemonicParseError::ChecksumMismatch {
-                    expected: expected_checksum,
-                    actual: actual_checksum,
-                },
-                mnemonic,
+                reason: MnemonicParseError::ChecksumMismatch { expected, actual },
+                mnemonic: Self(MnemonicV2V3 { words, language }.into()),
             });
         }
 
-        Ok(mnemonic)
+        // error (word not in list)
+        // if every language knew every word we'd have returned above, so this is always `Some`.
+        let (language, unknown_words) =
+            fewest_unknown_words.unwrap_or((MnemonicLanguage::English, Vec::new()));
+
+        Err(Error::MnemonicParse {
+            reason: MnemonicParseError::UnknownWords(unknown_words),
+            mnemonic: Self(MnemonicV2V3 { words, language }.into()),
+        })
     }
 
     /// Generate a new 12 word `Mnemonic` from the BIP-39 standard English word list.
@@ -167,6 +174,9 @@ impl Mnemonic {
 
     /// Recover a [`PrivateKey`] from this `Mnemonic`.
     ///
+    /// Unlike the words, `passphrase` is used as is, it isn't NFKD normalized,
+    /// so, for example, `"é"` and `"e\u{301}"` recover different keys.
+    ///
     /// # Errors
     /// Under certain circumstances, this function will return a [`Error::MnemonicEntropy`].
     /// - [`MnemonicEntropyError::LegacyWithPassphrase`] if this is a legacy private key, and the passphrase isn't empty.
@@ -188,24 +198,26 @@ impl Mnemonic {
     }
 
     pub(crate) fn to_seed(&self, phrase: &str) -> [u8; 64] {
+        // BIP-39 specifies that the passphrase is NFKD normalized too, but it's used as is,
+        // so that existing passphrases keep recovering the same keys.
         let mut salt = String::from("mnemonic");
         salt.push_str(phrase);
 
-        pbkdf2::pbkdf2_hmac_array::<sha2::Sha512, 64>(
-            self.to_string().as_bytes(),
-            salt.as_bytes(),
-            2048,
-        )
+        let mnemonic: String = self.to_string().nfkd().collect();
+
+        pbkdf2::pbkdf2_hmac_array::<sha2::Sha512, 64>(mnemonic.as_bytes(), salt.as_bytes(), 2048)
     }
 }
 
 impl fmt::Display for Mnemonic {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = self.language().map_or(" ", MnemonicLanguage::separator);
+
         if let Some((first, rest)) = self.words().split_first() {
             f.write_str(first)?;
 
             for word in rest {
-                write!(f, " {word}")?;
+                write!(f, "{separator}{word}")?;
             }
         }
 
@@ -265,6 +277,7 @@ impl MnemonicV1 {
 
 struct MnemonicV2V3 {
     words: Vec<String>,
+    language: MnemonicLanguage,
 }
 
 impl MnemonicV2V3 {
@@ -272,7 +285,7 @@ impl MnemonicV2V3 {
         &self.words
     }
 
-    fn from_entropy(entropy: &[u8]) -> Self {
+    fn from_entropy(entropy: &[u8], language: MnemonicLanguage) -> Self {
         assert!(entropy.len() == 16 || entropy.len() == 32);
 
         let entropy = {
@@ -292,13 +305,13 @@ impl MnemonicV2V3 {
             buffer = (buffer << 8) | u32::from(byte);
             offset += 8;
             if offset >= 11 {
-                let index = (buffer >> (offset - 11) & 0x7ff) as usize;
-                words.push(BIP39_WORD_LIST[index].to_owned());
+                let index = (buffer >> (offset - 11) & 0x7ff) as u16;
+                words.push(language.word_list().word(index).to_owned());
                 offset -= 11;
             }
         }
 
-        Self { words }
+        Self { words, language }
     }
 
     fn generate_12() -> Self {
@@ -306,7 +319,7 @@ impl MnemonicV2V3 {
         let mut entropy = [0; 16];
         rng.fill_bytes(&mut entropy);
 
-        Self::from_entropy(&entropy)
+        Self::from_entropy(&entropy, MnemonicLanguage::English)
     }
 
     fn generate_24() -> Self {
@@ -314,7 +327,7 @@ impl MnemonicV2V3 {
         let mut entropy = [0; 32];
         rng.fill_bytes(&mut entropy);
 
-        Self::from_entropy(&entropy)
+        Self::from_entropy(&entropy, MnemonicLanguage::English)
     }
 
     fn to_legacy_entropy(&self) -> crate::Result<Vec<u8>> {
@@ -327,7 +340,7 @@ impl MnemonicV2V3 {
         }
 
         // technically, this code all works for 12 words, but I'm going to pretend it doesn't.
-        let (entropy, actual_checksum) = words_to_entropy_and_checksum(&self.words);
+        let (entropy, actual_checksum) = words_to_entropy_and_checksum(&self.words, self.language);
 
         let expected_checksum = checksum(&entropy);
         let expected_checksum =
@@ -404,16 +417,49 @@ fn convert_radix<I: IntoIterator<Item = i32>>(
     out
 }
 
-fn words_to_entropy_and_checksum<T: AsRef<str>>(words: &[T]) -> (Vec<u8>, u8) {
-    let indecies: Vec<_> = words
-        .iter()
-        .map(T::as_ref)
-        .map(|it| BIP39_WORD_LIST.binary_search(&it).unwrap() as u16)
-        .collect();
+fn words_to_entropy_and_checksum<T: AsRef<str>>(
+    words: &[T],
+    language: MnemonicLanguage,
+) -> (Vec<u8>, u8) {
+    let indecies: Vec<_> =
+        words.iter().map(|it| language.word_list().index_of(it.as_ref()).unwrap()).collect();
 
     incecies_to_entropy_and_checksum(&indecies)
 }
 
+/// Looks up every word in `language`'s word list, returning the indecies of any unknown words on failure.
+fn word_indecies<T: AsRef<str>>(
+    words: &[T],
+    language: MnemonicLanguage,
+) -> Result<Vec<u16>, Vec<usize>> {
+    let word_list = language.word_list();
+
+    let mut word_indecies = Vec::with_capacity(words.len());
+    let mut unknown_words = Vec::new();
+    for (word_index, word) in words.iter().enumerate() {
+        match word_list.index_of(word.as_ref()) {
+            Some(i) => word_indecies.push(i),
+            None => unknown_words.push(word_index),
+        }
+    }
+
+    if unknown_words.is_empty() {
+        Ok(word_indecies)
+    } else {
+        Err(unknown_words)
+    }
+}
+
+/// Returns the `(expected, actual)` checksums for a 12 or 24 word mnemonic.
+fn checksums(indecies: &[u16]) -> (u8, u8) {
+    let (entropy, actual_checksum) = incecies_to_entropy_and_checksum(indecies);
+    let expected_checksum = checksum(&entropy);
+    let expected_checksum =
+        if indecies.len() == 12 { expected_checksum & 0xf0 } else { expected_checksum };
+
+    (expected_checksum, actual_checksum)
+}
+
 fn incecies_to_entropy_and_checksum(indecies: &[u16]) -> (Vec<u8>, u8) {
     assert!(matches!(indecies.len(), 12 | 24));
 
@@ -456,8 +502,12 @@ mod tests {
     use assert_matches::assert_matches;
     use expect_test::expect;
     use hex_literal::hex;
+    use unicode_normalization::UnicodeNormalization;
 
-    use super::Mnemonic;
+    use super::{
+        Mnemonic,
+        MnemonicLanguage,
+    };
     use crate::error::MnemonicParseError;
     use crate::Error;
 
@@ -584,7 +634,9 @@ mod tests {
         ];
 
         for (entropy, s) in ENTROPY.iter().zip(KNOWN_GOOD_MNEMONICS) {
-            let mnemonic = Mnemonic(super::MnemonicV2V3::from_entropy(entropy).into());
+            let mnemonic = Mnemonic(
+                super::MnemonicV2V3::from_entropy(entropy, super::MnemonicLanguage::English).into(),
+            );
 
             assert_eq!(&mnemonic.to_string(), s);
         }
@@ -662,4 +714,75 @@ mod tests {
         "#]]
         .assert_debug_eq(key.debug_pretty());
     }
+
+    #[test]
+    fn passphrase_not_normalized() {
+        let mnemonic = Mnemonic::from_str(KNOWN_GOOD_MNEMONICS[3]).unwrap();
+
+        let raw_seed = |passphrase: &str| {
+            pbkdf2::pbkdf2_hmac_array::<sha2::Sha512, 64>(
+                KNOWN_GOOD_MNEMONICS[3].as_bytes(),
+                format!("mnemonic{passphrase}").as_bytes(),
+                2048,
+            )
+        };
+
+        for passphrase in ["", "hunter2", "été", "e\u{301}te\u{301}", "ＡＢＣ"] {
+            assert_eq!(mnemonic.to_seed(passphrase), raw_seed(passphrase));
+        }
+
+        assert_ne!(mnemonic.to_seed("été"), mnemonic.to_seed("e\u{301}te\u{301}"));
+    }
+
+    #[test]
+    fn japanese_bip39_vector() {
+        // first Japanese test vector from <https://github.com/bip32JP/bip32JP.github.io/blob/master/test_JP_BIP39.json>
+        const MNEMONIC: &str = concat!(
+            "あいこくしん　あいこくしん　あいこくしん　あいこくしん　",
+            "あいこくしん　あいこくしん　あいこくしん　あいこくしん　",
+            "あいこくしん　あいこくしん　あいこくしん　あおぞら"
+        );
+
+        let mnemonic = Mnemonic::from_str(MNEMONIC).unwrap();
+
+        assert_eq!(mnemonic.language(), Some(MnemonicLanguage::Japanese));
+        // words are stored decomposed, but are still separated with ideographic spaces.
+        assert_eq!(mnemonic.to_string().nfc().collect::<String>(), MNEMONIC);
+
+        // the test vectors assume a normalized passphrase.
+        let passphrase: String = "㍍ガバヴァぱばぐゞちぢ十人十色".nfkd().collect();
+        let seed = mnemonic.to_seed(&passphrase);
+
+        assert_eq!(
+            seed,
+            hex!(
+                "a262d6fb6122ecf45be09c50492b31f92e9beb7d9a845987a02cefda57a15f9c"
+                "467a17872029a9e92299b5cbdf306e3a0ee620245cbd508959b6cb7ca637bd55"
+            )
+        );
+    }
+
+    #[test]
+    fn detects_language() {
+        const ENTROPY: [u8; 16] = hex!("4e172857ab9ac2563fee9c829a4b2e9b");
+
+        for language in MnemonicLanguage::ALL {
+            let expected = Mnemonic(super::MnemonicV2V3::from_entropy(&ENTROPY, language).into());
+
+            let mnemonic = Mnemonic::from_str(&expected.to_string()).unwrap();
+
+            assert_eq!(mnemonic.language(), Some(language));
+            assert_eq!(mnemonic.words(), expected.words());
+        }
+    }
+
+    #[test]
+    fn legacy_has_no_language() {
+        const MNEMONIC: &str = concat!(
+            "jolly kidnap tom lawn drunk chick optic lust mutter mole bride ",
+            "galley dense member sage neural widow decide curb aboard margin manure"
+        );
+
+        assert_eq!(Mnemonic::from_str(MNEMONIC).unwrap().language(), None);
+    }
 }
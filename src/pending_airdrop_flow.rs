@@ -0,0 +1,403 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use crate::signer::AnySigner;
+use crate::transaction::TransactionExecute;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    PendingAirdropId,
+    PendingAirdropQuery,
+    PrivateKey,
+    PublicKey,
+    TokenCancelAirdropTransaction,
+    TokenClaimAirdropTransaction,
+    TokenId,
+    Transaction,
+    TransactionReceipt,
+};
+
+/// The default number of pending airdrops the network allows in a single claim or cancel transaction.
+const DEFAULT_MAX_AIRDROPS_PER_TRANSACTION: usize = 10;
+
+type Filter = Box<dyn Fn(&PendingAirdropId) -> bool + Send + Sync>;
+
+/// Claim or cancel any number of pending airdrops.
+///
+/// The operation of this flow is as follows:
+/// 1. List the pending airdrops of the account with a [`PendingAirdropQuery`],
+///    unless [`pending_airdrop_ids`](Self::pending_airdrop_ids) are set.
+/// 2. Keep only the airdrops that pass the [`token_id`](Self::token_id) and [`filter`](Self::filter).
+/// 3. [`claim`](Self::claim) them with [`TokenClaimAirdropTransaction`]s
+///    or [`cancel`](Self::cancel) them with [`TokenCancelAirdropTransaction`]s,
+///    of at most [`max_airdrops_per_transaction`](Self::max_airdrops_per_transaction) airdrops each.
+///
+/// # Examples
+///
+/// ```no_run
+/// use hiero_sdk::{AccountId, Client, PendingAirdropFlow, PrivateKey, TokenId};
+///
+/// # async fn example(client: &Client, key: PrivateKey) -> hiero_sdk::Result<()> {
+/// let spam = TokenId::new(0, 0, 666);
+///
+/// let receipts = PendingAirdropFlow::new()
+///     .account_id(AccountId::new(0, 0, 1234))
+///     .filter(move |id| id.token_id != Some(spam))
+///     .sign(key)
+///     .claim(client)
+///     .await?;
+///
+/// println!("claimed {} airdrops", receipts.receipts.iter().map(|(ids, _)| ids.len()).sum::<usize>());
+/// # Ok(())
+/// # }
+/// ```
+pub struct PendingAirdropFlow {
+    account_id: Option<AccountId>,
+    pending_airdrop_ids: Option<Vec<PendingAirdropId>>,
+    token_id: Option<TokenId>,
+    filter: Option<Filter>,
+    max_airdrops_per_transaction: usize,
+    signers: Vec<AnySigner>,
+}
+
+/// The result of executing a [`PendingAirdropFlow`].
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct PendingAirdropReceipts {
+    /// The receipts of the transactions that succeeded, along with the airdrops each one claimed or cancelled.
+    pub receipts: Vec<(Vec<PendingAirdropId>, TransactionReceipt)>,
+
+    /// The errors of the transactions that failed, along with the airdrops each one was claiming or cancelling.
+    pub errors: Vec<(Vec<PendingAirdropId>, Error)>,
+}
+
+impl PendingAirdropReceipts {
+    /// Returns `true` if every transaction succeeded.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl fmt::Debug for PendingAirdropFlow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PendingAirdropFlow")
+            .field("account_id", &self.account_id)
+            .field("pending_airdrop_ids", &self.pending_airdrop_ids)
+            .field("token_id", &self.token_id)
+            .field("filter", &self.filter.as_ref().map(|_| "Fn"))
+            .field("max_airdrops_per_transaction", &self.max_airdrops_per_transaction)
+            .field("signers", &self.signers)
+            .finish()
+    }
+}
+
+impl Default for PendingAirdropFlow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PendingAirdropFlow {
+    /// Create a new `PendingAirdropFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            account_id: None,
+            pending_airdrop_ids: None,
+            token_id: None,
+            filter: None,
+            max_airdrops_per_transaction: DEFAULT_MAX_AIRDROPS_PER_TRANSACTION,
+            signers: Vec::new(),
+        }
+    }
+
+    /// Returns the account whose airdrops are claimed or cancelled.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.account_id
+    }
+
+    /// Sets the account whose airdrops are claimed or cancelled.
+    ///
+    /// This is the receiver when claiming, and the sender when cancelling.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.account_id = Some(account_id);
+
+        self
+    }
+
+    /// Returns the pending airdrops to claim or cancel, if they're set explicitly.
+    #[must_use]
+    pub fn get_pending_airdrop_ids(&self) -> Option<&[PendingAirdropId]> {
+        self.pending_airdrop_ids.as_deref()
+    }
+
+    /// Sets the pending airdrops to claim or cancel, instead of listing them with a [`PendingAirdropQuery`].
+    pub fn pending_airdrop_ids(
+        &mut self,
+        pending_airdrop_ids: impl IntoIterator<Item = PendingAirdropId>,
+    ) -> &mut Self {
+        self.pending_airdrop_ids = Some(pending_airdrop_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Returns the token to claim or cancel airdrops of.
+    #[must_use]
+    pub fn get_token_id(&self) -> Option<TokenId> {
+        self.token_id
+    }
+
+    /// Sets the token to claim or cancel airdrops of, by default airdrops of every token are.
+    pub fn token_id(&mut self, token_id: TokenId) -> &mut Self {
+        self.token_id = Some(token_id);
+
+        self
+    }
+
+    /// Sets a filter for the airdrops to claim or cancel, only airdrops it returns `true` for are.
+    pub fn filter<F: Fn(&PendingAirdropId) -> bool + Send + Sync + 'static>(
+        &mut self,
+        filter: F,
+    ) -> &mut Self {
+        self.filter = Some(Box::new(filter));
+
+        self
+    }
+
+    /// Returns the most airdrops claimed or cancelled by a single transaction.
+    #[must_use]
+    pub fn get_max_airdrops_per_transaction(&self) -> usize {
+        self.max_airdrops_per_transaction
+    }
+
+    /// Sets the most airdrops claimed or cancelled by a single transaction.
+    ///
+    /// Defaults to 10, the network's default limit.
+    ///
+    /// # Panics
+    /// - If `max_airdrops_per_transaction` is 0.
+    pub fn max_airdrops_per_transaction(
+        &mut self,
+        max_airdrops_per_transaction: usize,
+    ) -> &mut Self {
+        assert!(
+            max_airdrops_per_transaction > 0,
+            "a transaction must include at least one airdrop"
+        );
+
+        self.max_airdrops_per_transaction = max_airdrops_per_transaction;
+
+        self
+    }
+
+    /// Adds a key to sign every transaction with, such as the key of the account.
+    pub fn sign(&mut self, key: PrivateKey) -> &mut Self {
+        self.signers.push(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Adds a signer to sign every transaction with, such as the signer of the account.
+    pub fn sign_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.signers.push(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Claims the airdrops pending for the account.
+    ///
+    /// A failed transaction doesn't stop the rest of the airdrops from being claimed.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if neither the account nor the pending airdrop IDs are set.
+    /// - If listing the pending airdrops fails.
+    pub async fn claim(&self, client: &Client) -> crate::Result<PendingAirdropReceipts> {
+        let pending_airdrop_ids = self.resolve_pending_airdrop_ids(client, true).await?;
+
+        Ok(self
+            .execute(client, pending_airdrop_ids, |ids| {
+                let mut tx = TokenClaimAirdropTransaction::new();
+                tx.pending_airdrop_ids(ids.iter().copied());
+                tx
+            })
+            .await)
+    }
+
+    /// Cancels the airdrops the account sent, that are still pending.
+    ///
+    /// A failed transaction doesn't stop the rest of the airdrops from being cancelled.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if neither the account nor the pending airdrop IDs are set.
+    /// - If listing the pending airdrops fails.
+    pub async fn cancel(&self, client: &Client) -> crate::Result<PendingAirdropReceipts> {
+        let pending_airdrop_ids = self.resolve_pending_airdrop_ids(client, false).await?;
+
+        Ok(self
+            .execute(client, pending_airdrop_ids, |ids| {
+                let mut tx = TokenCancelAirdropTransaction::new();
+                tx.pending_airdrop_ids(ids.iter().copied());
+                tx
+            })
+            .await)
+    }
+
+    /// Returns the pending airdrops to claim (or cancel), after filtering.
+    async fn resolve_pending_airdrop_ids(
+        &self,
+        client: &Client,
+        is_claim: bool,
+    ) -> crate::Result<Vec<PendingAirdropId>> {
+        let pending_airdrop_ids = match (&self.pending_airdrop_ids, self.account_id) {
+            (Some(pending_airdrop_ids), _) => pending_airdrop_ids.clone(),
+
+            (None, Some(account_id)) => {
+                let mut query = PendingAirdropQuery::new();
+
+                if is_claim {
+                    query.receiver_id(account_id);
+                } else {
+                    query.sender_id(account_id);
+                }
+
+                if let Some(token_id) = self.token_id {
+                    query.token_id(token_id);
+                }
+
+                query.execute(client).await?.into_iter().map(|it| it.pending_airdrop_id).collect()
+            }
+
+            (None, None) => return Err(Error::basic_parse(
+                "account ID or pending airdrop IDs must be set to execute a `PendingAirdropFlow`",
+            )),
+        };
+
+        Ok(self.select(pending_airdrop_ids))
+    }
+
+    /// Keeps only the airdrops that pass the token ID and the filter.
+    fn select(&self, pending_airdrop_ids: Vec<PendingAirdropId>) -> Vec<PendingAirdropId> {
+        pending_airdrop_ids
+            .into_iter()
+            .filter(|id| {
+                let token_id = id.token_id.or(id.nft_id.map(|it| it.token_id));
+
+                self.token_id.is_none_or(|it| token_id == Some(it))
+                    && self.filter.as_ref().is_none_or(|filter| filter(id))
+            })
+            .collect()
+    }
+
+    /// Splits the airdrops into the ones claimed or cancelled by each transaction.
+    fn batches<'a>(
+        &self,
+        pending_airdrop_ids: &'a [PendingAirdropId],
+    ) -> impl Iterator<Item = &'a [PendingAirdropId]> {
+        pending_airdrop_ids.chunks(self.max_airdrops_per_transaction)
+    }
+
+    async fn execute<D>(
+        &self,
+        client: &Client,
+        pending_airdrop_ids: Vec<PendingAirdropId>,
+        make_transaction: impl Fn(&[PendingAirdropId]) -> Transaction<D>,
+    ) -> PendingAirdropReceipts
+    where
+        D: TransactionExecute,
+    {
+        let mut receipts = PendingAirdropReceipts::default();
+
+        for ids in self.batches(&pending_airdrop_ids) {
+            let mut tx = make_transaction(ids);
+
+            match self.execute_transaction(client, &mut tx).await {
+                Ok(receipt) => receipts.receipts.push((ids.to_vec(), receipt)),
+                Err(error) => receipts.errors.push((ids.to_vec(), error)),
+            }
+        }
+
+        receipts
+    }
+
+    async fn execute_transaction<D: TransactionExecute>(
+        &self,
+        client: &Client,
+        tx: &mut Transaction<D>,
+    ) -> crate::Result<TransactionReceipt> {
+        tx.freeze_with(client)?;
+
+        for signer in &self.signers {
+            tx.sign_signer(signer.clone());
+        }
+
+        tx.execute(client).await?.get_receipt(client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PendingAirdropFlow;
+    use crate::{
+        AccountId,
+        PendingAirdropId,
+        TokenId,
+    };
+
+    const SENDER: AccountId = AccountId::new(0, 0, 3);
+    const RECEIVER: AccountId = AccountId::new(0, 0, 2);
+
+    fn fungible(token: u64) -> PendingAirdropId {
+        PendingAirdropId::new_token_id(SENDER, RECEIVER, TokenId::new(0, 0, token))
+    }
+
+    fn nft(token: u64, serial: u64) -> PendingAirdropId {
+        PendingAirdropId::new_nft_id(SENDER, RECEIVER, TokenId::new(0, 0, token).nft(serial))
+    }
+
+    #[test]
+    fn select() {
+        let ids = vec![fungible(4), nft(4, 1), fungible(5), nft(5, 2), nft(4, 3)];
+
+        assert_eq!(PendingAirdropFlow::new().select(ids.clone()), ids);
+
+        let mut flow = PendingAirdropFlow::new();
+        flow.token_id(TokenId::new(0, 0, 4));
+
+        assert_eq!(flow.select(ids.clone()), [fungible(4), nft(4, 1), nft(4, 3)]);
+
+        flow.filter(|id| id.nft_id.is_some());
+
+        assert_eq!(flow.select(ids.clone()), [nft(4, 1), nft(4, 3)]);
+
+        let mut flow = PendingAirdropFlow::new();
+        flow.filter(|id| id.token_id != Some(TokenId::new(0, 0, 5)));
+
+        assert_eq!(flow.select(ids), [fungible(4), nft(4, 1), nft(5, 2), nft(4, 3)]);
+    }
+
+    #[test]
+    fn batches() {
+        let ids: Vec<_> = (1..=23).map(|serial| nft(4, serial)).collect();
+
+        let mut flow = PendingAirdropFlow::new();
+
+        let sizes: Vec<_> = flow.batches(&ids).map(<[_]>::len).collect();
+        assert_eq!(sizes, [10, 10, 3]);
+
+        // every airdrop is in exactly one batch, in order.
+        assert_eq!(flow.batches(&ids).flatten().copied().collect::<Vec<_>>(), ids);
+
+        flow.max_airdrops_per_transaction(23);
+        assert_eq!(flow.batches(&ids).count(), 1);
+
+        assert_eq!(flow.batches(&[]).count(), 0);
+    }
+}
@@ -0,0 +1,313 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::mirror_rest::{
+    self,
+    DEFAULT_MAX_ATTEMPTS,
+    DEFAULT_MAX_BACKOFF,
+};
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    NftId,
+    PendingAirdropId,
+    PendingAirdropRecord,
+    TokenId,
+};
+
+/// The most airdrops the mirror node returns in a single page.
+const PAGE_SIZE: usize = 100;
+
+/// Lists the pending airdrops of an account, using the mirror node.
+///
+/// Set [`receiver_id`](Self::receiver_id) to list the airdrops waiting for an account to claim them,
+/// or only [`sender_id`](Self::sender_id) to list the outstanding airdrops an account has sent.
+///
+/// The returned [`PendingAirdropId`]s can be claimed with a [`TokenClaimAirdropTransaction`](crate::TokenClaimAirdropTransaction)
+/// or cancelled with a [`TokenCancelAirdropTransaction`](crate::TokenCancelAirdropTransaction),
+/// see [`PendingAirdropFlow`](crate::PendingAirdropFlow) to do either in bulk.
+///
+/// # Examples
+/// ```no_run
+/// # async fn example() -> hiero_sdk::Result<()> {
+/// use hiero_sdk::{AccountId, Client, PendingAirdropQuery};
+///
+/// let client = Client::for_testnet();
+///
+/// let pending = PendingAirdropQuery::new()
+///     .receiver_id(AccountId::new(0, 0, 1234))
+///     .execute(&client)
+///     .await?;
+///
+/// for record in pending {
+///     println!("{}", record.pending_airdrop_id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PendingAirdropQuery {
+    receiver_id: Option<AccountId>,
+    sender_id: Option<AccountId>,
+    token_id: Option<TokenId>,
+    limit: Option<usize>,
+    max_attempts: usize,
+    max_backoff: Duration,
+}
+
+impl Default for PendingAirdropQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PendingAirdropQuery {
+    /// Creates a new `PendingAirdropQuery`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            receiver_id: None,
+            sender_id: None,
+            token_id: None,
+            limit: None,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+
+    /// Returns the account the airdrops are pending for.
+    #[must_use]
+    pub fn get_receiver_id(&self) -> Option<AccountId> {
+        self.receiver_id
+    }
+
+    /// Sets the account the airdrops are pending for.
+    pub fn receiver_id(&mut self, receiver_id: AccountId) -> &mut Self {
+        self.receiver_id = Some(receiver_id);
+        self
+    }
+
+    /// Returns the account that sent the airdrops.
+    #[must_use]
+    pub fn get_sender_id(&self) -> Option<AccountId> {
+        self.sender_id
+    }
+
+    /// Sets the account that sent the airdrops.
+    pub fn sender_id(&mut self, sender_id: AccountId) -> &mut Self {
+        self.sender_id = Some(sender_id);
+        self
+    }
+
+    /// Returns the token to list airdrops of.
+    #[must_use]
+    pub fn get_token_id(&self) -> Option<TokenId> {
+        self.token_id
+    }
+
+    /// Sets the token to list airdrops of, by default airdrops of every token are listed.
+    pub fn token_id(&mut self, token_id: TokenId) -> &mut Self {
+        self.token_id = Some(token_id);
+        self
+    }
+
+    /// Returns the most airdrops to list.
+    #[must_use]
+    pub fn get_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Sets the most airdrops to list, by default every airdrop is listed.
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the maximum number of retry attempts.
+    #[must_use]
+    pub fn get_max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    /// Sets the maximum number of retry attempts.
+    pub fn max_attempts(&mut self, max_attempts: usize) -> &mut Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Returns the maximum backoff duration between retries.
+    #[must_use]
+    pub fn get_max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// Sets the maximum backoff duration between retries.
+    pub fn max_backoff(&mut self, max_backoff: Duration) -> &mut Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Lists the pending airdrops.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if neither the receiver nor the sender is set.
+    /// - [`Error::BasicParse`] if the mirror node returns an error, or a response that can't be parsed.
+    pub async fn execute(&self, client: &Client) -> crate::Result<Vec<PendingAirdropRecord>> {
+        let items = mirror_rest::get_all(
+            &mirror_rest::base_url(client),
+            &self.path()?,
+            "airdrops",
+            self.limit,
+            self.max_attempts,
+            self.max_backoff,
+        )
+        .await?;
+
+        items.iter().map(parse_airdrop).collect()
+    }
+
+    fn path(&self) -> crate::Result<String> {
+        let page_size = self.limit.map_or(PAGE_SIZE, |it| it.clamp(1, PAGE_SIZE));
+
+        let mut path = match (self.receiver_id, self.sender_id) {
+            (Some(receiver_id), sender_id) => {
+                let mut path =
+                    format!("/accounts/{receiver_id}/airdrops/pending?limit={page_size}");
+
+                if let Some(sender_id) = sender_id {
+                    path.push_str(&format!("&sender.id={sender_id}"));
+                }
+
+                path
+            }
+
+            (None, Some(sender_id)) => {
+                format!("/accounts/{sender_id}/airdrops/outstanding?limit={page_size}")
+            }
+
+            (None, None) => {
+                return Err(Error::basic_parse(
+                    "receiver or sender must be set on PendingAirdropQuery",
+                ))
+            }
+        };
+
+        if let Some(token_id) = self.token_id {
+            path.push_str(&format!("&token.id={token_id}"));
+        }
+
+        Ok(path)
+    }
+}
+
+// `{"amount": 10, "receiver_id": "0.0.2", "sender_id": "0.0.3", "serial_number": null, "token_id": "0.0.4", ...}`
+fn parse_airdrop(value: &Value) -> crate::Result<PendingAirdropRecord> {
+    let field = |key: &str| {
+        value
+            .get(key)
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::basic_parse(format!("mirror node airdrop is missing `{key}`")))
+    };
+
+    let sender_id = field("sender_id")?.parse()?;
+    let receiver_id = field("receiver_id")?.parse()?;
+    let token_id: TokenId = field("token_id")?.parse()?;
+
+    let record = match value.get("serial_number").and_then(Value::as_u64) {
+        Some(serial) => PendingAirdropRecord {
+            pending_airdrop_id: PendingAirdropId::new_nft_id(
+                sender_id,
+                receiver_id,
+                NftId { token_id, serial },
+            ),
+            pending_airdrop_value: None,
+        },
+
+        None => PendingAirdropRecord {
+            pending_airdrop_id: PendingAirdropId::new_token_id(sender_id, receiver_id, token_id),
+            pending_airdrop_value: value.get("amount").and_then(Value::as_u64),
+        },
+    };
+
+    Ok(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::parse_airdrop;
+    use crate::{
+        AccountId,
+        NftId,
+        PendingAirdropId,
+        PendingAirdropQuery,
+        TokenId,
+    };
+
+    #[test]
+    fn path() {
+        let mut query = PendingAirdropQuery::new();
+
+        assert!(query.path().is_err());
+
+        query.sender_id(AccountId::new(0, 0, 3));
+
+        assert_eq!(query.path().unwrap(), "/accounts/0.0.3/airdrops/outstanding?limit=100");
+
+        query.receiver_id(AccountId::new(0, 0, 2)).token_id(TokenId::new(0, 0, 4)).limit(10);
+
+        assert_eq!(
+            query.path().unwrap(),
+            "/accounts/0.0.2/airdrops/pending?limit=10&sender.id=0.0.3&token.id=0.0.4"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let fungible = parse_airdrop(&json!({
+            "amount": 10,
+            "receiver_id": "0.0.2",
+            "sender_id": "0.0.3",
+            "serial_number": null,
+            "timestamp": { "from": "1651560386.661997287", "to": null },
+            "token_id": "0.0.4"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            fungible.pending_airdrop_id,
+            PendingAirdropId::new_token_id(
+                AccountId::new(0, 0, 3),
+                AccountId::new(0, 0, 2),
+                TokenId::new(0, 0, 4)
+            )
+        );
+        assert_eq!(fungible.pending_airdrop_value, Some(10));
+
+        let nft = parse_airdrop(&json!({
+            "amount": 0,
+            "receiver_id": "0.0.2",
+            "sender_id": "0.0.3",
+            "serial_number": 7,
+            "token_id": "0.0.5"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            nft.pending_airdrop_id,
+            PendingAirdropId::new_nft_id(
+                AccountId::new(0, 0, 3),
+                AccountId::new(0, 0, 2),
+                NftId { token_id: TokenId::new(0, 0, 5), serial: 7 }
+            )
+        );
+        assert_eq!(nft.pending_airdrop_value, None);
+
+        assert!(parse_airdrop(&json!({ "receiver_id": "0.0.2", "token_id": "0.0.4" })).is_err());
+    }
+}
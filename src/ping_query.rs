@@ -126,7 +126,7 @@ impl Execute for PingQuery {
         _transaction_id: Option<&crate::TransactionId>,
         _response: Self::GrpcResponse,
     ) -> crate::Error {
-        crate::Error::QueryNoPaymentPreCheckStatus { status }
+        crate::Error::QueryNoPaymentPreCheckStatus { status, revert: None }
     }
 
     fn response_pre_check_status(response: &Self::GrpcResponse) -> crate::Result<i32> {
@@ -8,7 +8,10 @@ use crate::execute::{
     execute,
     Execute,
 };
-use crate::query::execute::response_header;
+use crate::query::execute::{
+    contract_revert,
+    response_header,
+};
 use crate::query::QueryExecute;
 use crate::{
     AccountId,
@@ -100,17 +103,20 @@ where
         &self,
         status: crate::Status,
         transaction_id: Option<&TransactionId>,
-        _response: Self::GrpcResponse,
+        response: Self::GrpcResponse,
     ) -> crate::Error {
+        let revert = contract_revert(status, response);
+
         if let Some(transaction_id) = self.0.data.transaction_id() {
             crate::Error::QueryPreCheckStatus { status, transaction_id: Box::new(transaction_id) }
         } else if let Some(transaction_id) = transaction_id {
             crate::Error::QueryPaymentPreCheckStatus {
                 status,
                 transaction_id: Box::new(*transaction_id),
+                revert,
             }
         } else {
-            crate::Error::QueryNoPaymentPreCheckStatus { status }
+            crate::Error::QueryNoPaymentPreCheckStatus { status, revert }
         }
     }
 
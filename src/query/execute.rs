@@ -14,6 +14,8 @@ use crate::query::{
 use crate::{
     AccountId,
     BoxGrpcFuture,
+    ContractFunctionResult,
+    ContractRevert,
     Error,
     FromProtobuf,
     Hbar,
@@ -144,17 +146,20 @@ where
         &self,
         status: crate::Status,
         transaction_id: Option<&TransactionId>,
-        _response: Self::GrpcResponse,
+        response: Self::GrpcResponse,
     ) -> crate::Error {
+        let revert = contract_revert(status, response);
+
         if let Some(transaction_id) = self.data.transaction_id() {
             crate::Error::QueryPreCheckStatus { status, transaction_id: Box::new(transaction_id) }
         } else if let Some(transaction_id) = transaction_id {
             crate::Error::QueryPaymentPreCheckStatus {
                 status,
                 transaction_id: Box::new(*transaction_id),
+                revert,
             }
         } else {
-            crate::Error::QueryNoPaymentPreCheckStatus { status }
+            crate::Error::QueryNoPaymentPreCheckStatus { status, revert }
         }
     }
 
@@ -170,6 +175,18 @@ impl<D: QueryExecute + ValidateChecksums> ValidateChecksums for Query<D> {
     }
 }
 
+// a reverted `ContractCallQuery` still responds with the call's result, which says why it reverted.
+pub(crate) fn contract_revert(
+    status: Status,
+    response: services::Response,
+) -> Option<Box<ContractRevert>> {
+    if status != Status::ContractRevertExecuted {
+        return None;
+    }
+
+    ContractFunctionResult::from_protobuf(response.response?).ok()?.revert().map(Box::new)
+}
+
 pub(crate) fn response_header(
     response: &Option<services::response::Response>,
 ) -> crate::Result<&services::ResponseHeader> {
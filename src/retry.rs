@@ -1,6 +1,16 @@
 use futures_core::Future;
 use tokio::time::sleep;
 
+use crate::transaction::TransactionExecute;
+use crate::{
+    Client,
+    Status,
+    Transaction,
+    TransactionId,
+    TransactionReceipt,
+    TransactionReceiptQuery,
+};
+
 #[derive(Debug)]
 pub(crate) enum Error {
     /// An error that may be resolved after backoff is applied (connection issues for example)
@@ -66,3 +76,89 @@ where
     let last_error = last_error.expect("timeout while network had no healthy nodes");
     Err(crate::Error::TimedOut(last_error.into()))
 }
+
+/// Executes `tx` (frozen with `transaction_id`) and returns its receipt,
+/// submitting it again up to `max_attempts` times while it's unknown whether it reached consensus.
+pub(crate) async fn execute_with_retries<D: TransactionExecute>(
+    client: &Client,
+    tx: &mut Transaction<D>,
+    transaction_id: TransactionId,
+    max_attempts: usize,
+) -> crate::Result<TransactionReceipt> {
+    let mut attempt = 1;
+
+    loop {
+        let result = match tx.execute(client).await {
+            // a duplicate means an earlier attempt did reach the network.
+            Ok(_)
+            | Err(crate::Error::TransactionPreCheckStatus {
+                status: Status::DuplicateTransaction,
+                ..
+            }) => {
+                TransactionReceiptQuery::new()
+                    .transaction_id(transaction_id)
+                    .validate_status(true)
+                    .execute(client)
+                    .await
+            }
+
+            Err(error) => Err(error),
+        };
+
+        match result {
+            Ok(receipt) => return Ok(receipt),
+            Err(error) if attempt < max_attempts && is_retryable(&error) => attempt += 1,
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+/// Returns `true` if it's unknown whether the transaction reached consensus,
+/// so submitting it again (with the same transaction ID) is both safe and useful.
+fn is_retryable(error: &crate::Error) -> bool {
+    match error {
+        crate::Error::TimedOut(_) => true,
+        // other gRPC errors (like `InvalidArgument`) fail the same way every time.
+        crate::Error::GrpcStatus(status) => matches!(
+            status.code(),
+            tonic::Code::Unavailable
+                | tonic::Code::DeadlineExceeded
+                | tonic::Code::ResourceExhausted
+                | tonic::Code::Internal
+        ),
+        crate::Error::QueryPreCheckStatus { status, .. }
+        | crate::Error::QueryNoPaymentPreCheckStatus { status, .. } => {
+            *status == Status::ReceiptNotFound
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_retryable;
+    use crate::Status;
+
+    #[test]
+    fn retryable() {
+        assert!(is_retryable(&crate::Error::TimedOut(Box::new(crate::Error::basic_parse(
+            "timed out"
+        )))));
+        assert!(is_retryable(&crate::Error::GrpcStatus(tonic::Status::unavailable(""))));
+        assert!(is_retryable(&crate::Error::GrpcStatus(tonic::Status::deadline_exceeded(""))));
+        assert!(is_retryable(&crate::Error::GrpcStatus(tonic::Status::resource_exhausted(""))));
+        assert!(is_retryable(&crate::Error::GrpcStatus(tonic::Status::internal(""))));
+        assert!(is_retryable(&crate::Error::QueryNoPaymentPreCheckStatus {
+            status: Status::ReceiptNotFound,
+            revert: None,
+        }));
+
+        assert!(!is_retryable(&crate::Error::GrpcStatus(tonic::Status::invalid_argument(""))));
+        assert!(!is_retryable(&crate::Error::GrpcStatus(tonic::Status::permission_denied(""))));
+        assert!(!is_retryable(&crate::Error::QueryNoPaymentPreCheckStatus {
+            status: Status::InvalidTokenId,
+            revert: None,
+        }));
+        assert!(!is_retryable(&crate::Error::basic_parse("invalid")));
+    }
+}
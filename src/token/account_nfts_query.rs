@@ -0,0 +1,279 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use futures_core::stream::BoxStream;
+use futures_util::{
+    StreamExt,
+    TryStreamExt,
+};
+use serde_json::Value;
+
+use super::{
+    NftId,
+    TokenId,
+    TokenNftInfo,
+};
+use crate::mirror_rest::{
+    self,
+    DEFAULT_MAX_ATTEMPTS,
+    DEFAULT_MAX_BACKOFF,
+};
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    LedgerId,
+};
+
+/// The most NFTs the mirror node returns in a single page.
+const PAGE_SIZE: usize = 100;
+
+/// Lists the NFTs an account owns, using the mirror node.
+///
+/// # Examples
+/// ```no_run
+/// # async fn example() -> hiero_sdk::Result<()> {
+/// use futures_util::TryStreamExt;
+/// use hiero_sdk::{AccountId, AccountNftsQuery, Client, TokenId};
+///
+/// let client = Client::for_testnet();
+///
+/// let mut nfts = AccountNftsQuery::new()
+///     .account_id(AccountId::new(0, 0, 1234))
+///     .token_id(TokenId::new(0, 0, 5678))
+///     .subscribe(&client);
+///
+/// while let Some(nft) = nfts.try_next().await? {
+///     println!("{}", nft.nft_id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AccountNftsQuery {
+    account_id: Option<AccountId>,
+    token_id: Option<TokenId>,
+    limit: Option<usize>,
+    max_attempts: usize,
+    max_backoff: Duration,
+}
+
+impl Default for AccountNftsQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AccountNftsQuery {
+    /// Creates a new `AccountNftsQuery`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            account_id: None,
+            token_id: None,
+            limit: None,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+
+    /// Returns the account that owns the NFTs.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.account_id
+    }
+
+    /// Sets the account that owns the NFTs.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    /// Returns the token to list NFTs of.
+    #[must_use]
+    pub fn get_token_id(&self) -> Option<TokenId> {
+        self.token_id
+    }
+
+    /// Sets the token to list NFTs of, by default NFTs of every token are listed.
+    pub fn token_id(&mut self, token_id: TokenId) -> &mut Self {
+        self.token_id = Some(token_id);
+        self
+    }
+
+    /// Returns the most NFTs to list.
+    #[must_use]
+    pub fn get_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Sets the most NFTs to list, by default every NFT is listed.
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the maximum number of retry attempts.
+    #[must_use]
+    pub fn get_max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    /// Sets the maximum number of retry attempts.
+    pub fn max_attempts(&mut self, max_attempts: usize) -> &mut Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Returns the maximum backoff duration between retries.
+    #[must_use]
+    pub fn get_max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// Sets the maximum backoff duration between retries.
+    pub fn max_backoff(&mut self, max_backoff: Duration) -> &mut Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Lists the NFTs.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the account isn't set.
+    /// - [`Error::BasicParse`] if the mirror node returns an error, or a response that can't be parsed.
+    pub async fn execute(&self, client: &Client) -> crate::Result<Vec<TokenNftInfo>> {
+        self.subscribe(client).try_collect().await
+    }
+
+    /// Streams the NFTs, fetching each page from the mirror node once it's needed.
+    ///
+    /// The stream ends after the first error, see [`execute`](Self::execute) for the errors.
+    pub fn subscribe<'a>(&self, client: &'a Client) -> BoxStream<'a, crate::Result<TokenNftInfo>> {
+        let path = match self.path() {
+            Ok(path) => path,
+            Err(error) => return Box::pin(futures_util::stream::once(async { Err(error) })),
+        };
+
+        let ledger_id = client
+            .ledger_id_internal()
+            .as_deref()
+            .cloned()
+            .unwrap_or_else(|| LedgerId::from_bytes(Vec::new()));
+
+        let nfts = mirror_rest::stream_all(
+            mirror_rest::base_url(client),
+            &path,
+            "nfts",
+            self.limit,
+            self.max_attempts,
+            self.max_backoff,
+        );
+
+        Box::pin(nfts.map(move |it| it.and_then(|nft| parse_nft(&nft, &ledger_id))))
+    }
+
+    fn path(&self) -> crate::Result<String> {
+        let account_id = self
+            .account_id
+            .ok_or_else(|| Error::basic_parse("account must be set on AccountNftsQuery"))?;
+
+        let page_size = self.limit.map_or(PAGE_SIZE, |it| it.clamp(1, PAGE_SIZE));
+
+        let mut path = format!("/accounts/{account_id}/nfts?limit={page_size}");
+
+        if let Some(token_id) = self.token_id {
+            path.push_str(&format!("&token.id={token_id}"));
+        }
+
+        Ok(path)
+    }
+}
+
+// `{"account_id": "0.0.2", "created_timestamp": "1610682445.003266000", "metadata": "AQI=", "serial_number": 7, "spender": null, "token_id": "0.0.4", ...}`
+fn parse_nft(value: &Value, ledger_id: &LedgerId) -> crate::Result<TokenNftInfo> {
+    let field = |key: &str| {
+        value
+            .get(key)
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::basic_parse(format!("mirror node NFT is missing `{key}`")))
+    };
+
+    let serial = value
+        .get("serial_number")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| Error::basic_parse("mirror node NFT is missing `serial_number`"))?;
+
+    let metadata = BASE64
+        .decode(value.get("metadata").and_then(Value::as_str).unwrap_or_default())
+        .map_err(Error::basic_parse)?;
+
+    let spender_id = value.get("spender").and_then(Value::as_str).map(str::parse).transpose()?;
+
+    Ok(TokenNftInfo {
+        nft_id: NftId { token_id: field("token_id")?.parse()?, serial },
+        account_id: field("account_id")?.parse()?,
+        creation_time: mirror_rest::parse_timestamp(field("created_timestamp")?)?,
+        metadata,
+        spender_id,
+        ledger_id: ledger_id.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::parse_nft;
+    use crate::{
+        AccountId,
+        AccountNftsQuery,
+        LedgerId,
+        TokenId,
+    };
+
+    #[test]
+    fn path() {
+        let mut query = AccountNftsQuery::new();
+
+        assert!(query.path().is_err());
+
+        query.account_id(AccountId::new(0, 0, 2));
+
+        assert_eq!(query.path().unwrap(), "/accounts/0.0.2/nfts?limit=100");
+
+        query.token_id(TokenId::new(0, 0, 4)).limit(500);
+
+        assert_eq!(query.path().unwrap(), "/accounts/0.0.2/nfts?limit=100&token.id=0.0.4");
+    }
+
+    #[test]
+    fn parse() {
+        let nft = parse_nft(
+            &json!({
+                "account_id": "0.0.2",
+                "created_timestamp": "1610682445.003266000",
+                "delegating_spender": null,
+                "deleted": false,
+                "metadata": "3q2+7w==",
+                "modified_timestamp": "1610682445.003266001",
+                "serial_number": 7,
+                "spender": "0.0.3",
+                "token_id": "0.0.4"
+            }),
+            &LedgerId::testnet(),
+        )
+        .unwrap();
+
+        assert_eq!(nft.nft_id, TokenId::new(0, 0, 4).nft(7));
+        assert_eq!(nft.account_id, AccountId::new(0, 0, 2));
+        assert_eq!(nft.creation_time.unix_timestamp(), 1610682445);
+        assert_eq!(nft.metadata, [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(nft.spender_id, Some(AccountId::new(0, 0, 3)));
+
+        assert!(parse_nft(&json!({ "serial_number": 7 }), &LedgerId::testnet()).is_err());
+    }
+}
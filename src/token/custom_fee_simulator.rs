@@ -0,0 +1,701 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+use super::{
+    AnyCustomFee,
+    AssessedCustomFee,
+    Fee,
+    FeeAssessmentMethod,
+    FixedFeeData,
+    FractionalFeeData,
+    RoyaltyFeeData,
+    TokenAirdropTransaction,
+    TokenId,
+    TokenInfo,
+};
+use crate::transfer_transaction::{
+    TokenTransfer,
+    Transfer,
+};
+use crate::{
+    AccountId,
+    Error,
+    Status,
+    TransferTransaction,
+};
+
+/// The number of levels of custom fees the network assesses by default,
+/// the fees of a transfer, and the fees of those fees.
+const DEFAULT_MAX_DEPTH: u32 = 2;
+
+// a fixed fee denominated in `0.0.0` is denominated in the token the fee is attached to.
+const SENTINEL_TOKEN_ID: TokenId = TokenId::new(0, 0, 0);
+
+/// Computes the custom fees the network would assess for a [`TransferTransaction`]
+/// or a [`TokenAirdropTransaction`], without submitting it.
+///
+/// The fee schedules of every token involved, including the tokens fixed fees are denominated in,
+/// must be added with [`token_info`](Self::token_info) or [`token_custom_fees`](Self::token_custom_fees).
+///
+/// The simulator follows the network's rules:
+/// - Fixed fees are charged once to every sender of the token.
+/// - Fractional fees are a fraction of each sender's debit, kept within the fee's minimum and maximum.
+///   [`Inclusive`](FeeAssessmentMethod::Inclusive) fees are paid by the receivers,
+///   [`Exclusive`](FeeAssessmentMethod::Exclusive) fees by the sender.
+/// - Royalty fees are a fraction of the fungible value the sender of an NFT receives in the same transfer,
+///   when the sender receives nothing the fallback fee is charged to the receiver of the NFT
+///   (or the sender, for airdrops).
+/// - Treasuries and the fee's collector never pay a fee,
+///   nor does any collector of the token when the fee has `all_collectors_are_exempt` set.
+/// - Fixed fees denominated in another token are assessed that token's fees in turn,
+///   up to [`max_depth`](Self::max_depth) levels.
+///
+/// Account balances aren't known to the simulator, so a transfer that would fail for lack of funds still succeeds here.
+#[derive(Debug, Clone)]
+pub struct CustomFeeSimulator {
+    schedules: HashMap<TokenId, TokenFeeSchedule>,
+    max_depth: u32,
+}
+
+#[derive(Debug, Clone)]
+struct TokenFeeSchedule {
+    treasury_account_id: Option<AccountId>,
+    custom_fees: Vec<AnyCustomFee>,
+}
+
+impl TokenFeeSchedule {
+    fn is_exempt(&self, fee: &AnyCustomFee, payer: AccountId) -> bool {
+        self.treasury_account_id == Some(payer)
+            || fee.fee_collector_account_id == Some(payer)
+            || (fee.all_collectors_are_exempt
+                && self.custom_fees.iter().any(|it| it.fee_collector_account_id == Some(payer)))
+    }
+}
+
+/// The balance changes of a single token at one level of assessment.
+#[derive(Debug)]
+struct TokenChanges {
+    token_id: TokenId,
+    adjustments: Vec<(AccountId, i64)>,
+    nft_transfers: Vec<(AccountId, AccountId)>,
+}
+
+impl TokenChanges {
+    fn new(token_id: TokenId) -> Self {
+        Self { token_id, adjustments: Vec::new(), nft_transfers: Vec::new() }
+    }
+
+    fn adjust(&mut self, account_id: AccountId, amount: i64) {
+        match self.adjustments.iter_mut().find(|(it, _)| *it == account_id) {
+            Some((_, it)) => *it = it.saturating_add(amount),
+            None => self.adjustments.push((account_id, amount)),
+        }
+    }
+
+    fn senders(&self) -> impl Iterator<Item = (AccountId, i64)> + '_ {
+        self.adjustments.iter().copied().filter(|(_, amount)| *amount < 0)
+    }
+
+    fn receivers(&self) -> impl Iterator<Item = AccountId> + '_ {
+        self.adjustments.iter().filter(|(_, amount)| *amount > 0).map(|(account_id, _)| *account_id)
+    }
+}
+
+fn token_changes(changes: &mut Vec<TokenChanges>, token_id: TokenId) -> &mut TokenChanges {
+    match changes.iter().position(|it| it.token_id == token_id) {
+        Some(index) => &mut changes[index],
+        None => {
+            changes.push(TokenChanges::new(token_id));
+            changes.last_mut().unwrap()
+        }
+    }
+}
+
+/// State shared by every level of a single simulation.
+struct Assessment<'a> {
+    hbar_transfers: &'a [Transfer],
+    input: &'a [TokenChanges],
+    is_airdrop: bool,
+    assessed: Vec<AssessedCustomFee>,
+}
+
+impl Assessment<'_> {
+    /// Returns the fungible value `account_id` receives in the transaction, by denomination.
+    fn exchanged_value(&self, account_id: AccountId) -> Vec<(Option<TokenId>, i64)> {
+        let hbar = self
+            .hbar_transfers
+            .iter()
+            .filter(|it| it.account_id == account_id)
+            .map(|it| it.amount)
+            .sum::<i64>();
+
+        let tokens = self.input.iter().filter_map(|changes| {
+            changes
+                .adjustments
+                .iter()
+                .find(|(it, _)| *it == account_id)
+                .map(|(_, amount)| (Some(changes.token_id), *amount))
+        });
+
+        std::iter::once((None, hbar)).chain(tokens).filter(|(_, amount)| *amount > 0).collect()
+    }
+
+    fn charge_fixed(
+        &mut self,
+        token_id: TokenId,
+        fee: &FixedFeeData,
+        collector: Option<AccountId>,
+        payer: AccountId,
+        next: &mut Vec<TokenChanges>,
+    ) {
+        let denomination = match fee.denominating_token_id {
+            Some(SENTINEL_TOKEN_ID) => Some(token_id),
+            denomination => denomination,
+        };
+
+        self.assessed.push(AssessedCustomFee {
+            amount: fee.amount,
+            token_id: denomination,
+            fee_collector_account_id: collector,
+            payer_account_id_list: Vec::from([payer]),
+        });
+
+        // charging a fee in the token itself doesn't assess that token's fees again.
+        if let (Some(denomination), Some(collector)) = (denomination, collector) {
+            if denomination != token_id {
+                let changes = token_changes(next, denomination);
+                changes.adjust(payer, -fee.amount);
+                changes.adjust(collector, fee.amount);
+            }
+        }
+    }
+}
+
+impl Default for CustomFeeSimulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CustomFeeSimulator {
+    /// Create a new `CustomFeeSimulator` without any fee schedules.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { schedules: HashMap::new(), max_depth: DEFAULT_MAX_DEPTH }
+    }
+
+    /// Adds the custom fees and treasury of a token, from its [`TokenInfo`].
+    pub fn token_info(&mut self, info: &TokenInfo) -> &mut Self {
+        self.schedules.insert(
+            info.token_id,
+            TokenFeeSchedule {
+                treasury_account_id: Some(info.treasury_account_id),
+                custom_fees: info.custom_fees.clone(),
+            },
+        );
+
+        self
+    }
+
+    /// Adds the custom fees of a token.
+    ///
+    /// Unlike [`token_info`](Self::token_info), the token's treasury isn't known,
+    /// so transfers from it are charged fees as well.
+    pub fn token_custom_fees(
+        &mut self,
+        token_id: TokenId,
+        custom_fees: impl IntoIterator<Item = AnyCustomFee>,
+    ) -> &mut Self {
+        self.schedules.insert(
+            token_id,
+            TokenFeeSchedule {
+                treasury_account_id: None,
+                custom_fees: custom_fees.into_iter().collect(),
+            },
+        );
+
+        self
+    }
+
+    /// Returns the custom fees added for `token_id`.
+    #[must_use]
+    pub fn get_token_custom_fees(&self, token_id: TokenId) -> Option<&[AnyCustomFee]> {
+        self.schedules.get(&token_id).map(|it| it.custom_fees.as_slice())
+    }
+
+    /// Returns the number of levels of custom fees that are assessed.
+    #[must_use]
+    pub fn get_max_depth(&self) -> u32 {
+        self.max_depth
+    }
+
+    /// Sets the number of levels of custom fees that are assessed.
+    ///
+    /// The default is 2, the fees of the transfer and the fees charged for those fees, same as the network.
+    pub fn max_depth(&mut self, max_depth: u32) -> &mut Self {
+        self.max_depth = max_depth;
+
+        self
+    }
+
+    /// Returns the custom fees the network would assess for `transaction`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the fee schedule of a token involved in the transfer is unknown.
+    /// - [`Error::CustomFeeAssessment`] if the network would fail to assess the fees,
+    ///   for example because they nest deeper than [`max_depth`](Self::max_depth).
+    pub fn simulate_transfer(
+        &self,
+        transaction: &TransferTransaction,
+    ) -> crate::Result<Vec<AssessedCustomFee>> {
+        let data = transaction.data();
+
+        self.simulate(data.hbar_transfers(), data.token_transfers(), false)
+    }
+
+    /// Returns the custom fees the network would assess for `transaction`.
+    ///
+    /// The sender of an airdrop pays every fee, including royalty fallback fees.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the fee schedule of a token involved in the airdrop is unknown.
+    /// - [`Error::CustomFeeAssessment`] if the network would fail to assess the fees,
+    ///   for example because they nest deeper than [`max_depth`](Self::max_depth).
+    pub fn simulate_airdrop(
+        &self,
+        transaction: &TokenAirdropTransaction,
+    ) -> crate::Result<Vec<AssessedCustomFee>> {
+        self.simulate(&[], transaction.data().token_transfers(), true)
+    }
+
+    fn simulate(
+        &self,
+        hbar_transfers: &[Transfer],
+        token_transfers: &[TokenTransfer],
+        is_airdrop: bool,
+    ) -> crate::Result<Vec<AssessedCustomFee>> {
+        let mut input = Vec::new();
+
+        for transfer in token_transfers {
+            let changes = token_changes(&mut input, transfer.token_id);
+
+            for it in &transfer.transfers {
+                changes.adjust(it.account_id, it.amount);
+            }
+
+            changes
+                .nft_transfers
+                .extend(transfer.nft_transfers.iter().map(|it| (it.sender, it.receiver)));
+        }
+
+        let mut assessment =
+            Assessment { hbar_transfers, input: &input, is_airdrop, assessed: Vec::new() };
+
+        // each level is made up of the fixed fees the previous level charged in other tokens.
+        let mut next = Vec::new();
+
+        for changes in &input {
+            self.assess(&mut assessment, changes, 0, &mut next)?;
+        }
+
+        let mut depth = 1;
+
+        while !next.is_empty() {
+            let level = std::mem::take(&mut next);
+
+            for changes in &level {
+                self.assess(&mut assessment, changes, depth, &mut next)?;
+            }
+
+            depth += 1;
+        }
+
+        Ok(assessment.assessed)
+    }
+
+    fn assess(
+        &self,
+        assessment: &mut Assessment<'_>,
+        changes: &TokenChanges,
+        depth: u32,
+        next: &mut Vec<TokenChanges>,
+    ) -> crate::Result<()> {
+        let token_id = changes.token_id;
+
+        let schedule = self.schedules.get(&token_id).ok_or_else(|| {
+            Error::basic_parse(format!("custom fees of token `{token_id}` are unknown"))
+        })?;
+
+        if schedule.custom_fees.is_empty() {
+            return Ok(());
+        }
+
+        if depth >= self.max_depth {
+            return Err(Error::CustomFeeAssessment(
+                Status::CustomFeeChargingExceededMaxRecursionDepth,
+            ));
+        }
+
+        let mut nft_senders = Vec::new();
+        for (sender, _) in &changes.nft_transfers {
+            if !nft_senders.contains(sender) {
+                nft_senders.push(*sender);
+            }
+        }
+
+        for fee in &schedule.custom_fees {
+            let collector = fee.fee_collector_account_id;
+
+            match &fee.fee {
+                Fee::Fixed(fixed) => {
+                    let payers = changes
+                        .senders()
+                        .map(|(sender, _)| sender)
+                        .chain(nft_senders.iter().copied());
+
+                    for payer in payers {
+                        if !schedule.is_exempt(fee, payer) {
+                            assessment.charge_fixed(token_id, fixed, collector, payer, next);
+                        }
+                    }
+                }
+
+                Fee::Fractional(fractional) => {
+                    for (sender, debit) in changes.senders() {
+                        if schedule.is_exempt(fee, sender) {
+                            continue;
+                        }
+
+                        let amount = fractional_amount(fractional, debit);
+                        if amount == 0 {
+                            continue;
+                        }
+
+                        let payer_account_id_list = match fractional.assessment_method {
+                            FeeAssessmentMethod::Exclusive => Vec::from([sender]),
+                            FeeAssessmentMethod::Inclusive => {
+                                changes.receivers().filter(|it| Some(*it) != collector).collect()
+                            }
+                        };
+
+                        assessment.assessed.push(AssessedCustomFee {
+                            amount,
+                            token_id: Some(token_id),
+                            fee_collector_account_id: collector,
+                            payer_account_id_list,
+                        });
+                    }
+                }
+
+                Fee::Royalty(royalty) => {
+                    let mut paid = HashSet::new();
+
+                    for &(sender, receiver) in &changes.nft_transfers {
+                        let exchanged = assessment.exchanged_value(sender);
+
+                        if exchanged.is_empty() {
+                            let Some(fallback) = &royalty.fallback_fee else { continue };
+                            let payer = if assessment.is_airdrop { sender } else { receiver };
+
+                            if !schedule.is_exempt(fee, payer) {
+                                assessment.charge_fixed(token_id, fallback, collector, payer, next);
+                            }
+
+                            continue;
+                        }
+
+                        // the royalty is a fraction of everything the sender receives, so it's only charged once.
+                        if schedule.is_exempt(fee, sender) || !paid.insert(sender) {
+                            continue;
+                        }
+
+                        for (denomination, value) in exchanged {
+                            let amount = royalty_amount(royalty, value);
+                            if amount == 0 {
+                                continue;
+                            }
+
+                            assessment.assessed.push(AssessedCustomFee {
+                                amount,
+                                token_id: denomination,
+                                fee_collector_account_id: collector,
+                                payer_account_id_list: Vec::from([sender]),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn fraction_of(units: i64, numerator: u64, denominator: u64) -> i64 {
+    if denominator == 0 {
+        return 0;
+    }
+
+    let amount = u128::from(units.unsigned_abs()) * u128::from(numerator) / u128::from(denominator);
+
+    i64::try_from(amount).unwrap_or(i64::MAX)
+}
+
+fn fractional_amount(fee: &FractionalFeeData, units: i64) -> i64 {
+    let amount = fraction_of(units, fee.numerator, fee.denominator).max(fee.minimum_amount);
+
+    // a maximum of zero means there's no maximum.
+    match fee.maximum_amount {
+        0 => amount,
+        maximum => amount.min(maximum),
+    }
+}
+
+fn royalty_amount(fee: &RoyaltyFeeData, value: i64) -> i64 {
+    fraction_of(value, fee.numerator, fee.denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::CustomFeeSimulator;
+    use crate::{
+        AccountId,
+        AnyCustomFee,
+        AssessedCustomFee,
+        Error,
+        FeeAssessmentMethod,
+        FixedFeeData,
+        FractionalFeeData,
+        Hbar,
+        NftId,
+        RoyaltyFeeData,
+        Status,
+        TokenAirdropTransaction,
+        TokenId,
+        TransferTransaction,
+    };
+
+    const ALICE: AccountId = AccountId::new(0, 0, 1001);
+    const BOB: AccountId = AccountId::new(0, 0, 1002);
+    const COLLECTOR: AccountId = AccountId::new(0, 0, 1003);
+    const OTHER_COLLECTOR: AccountId = AccountId::new(0, 0, 1004);
+
+    const TOKEN: TokenId = TokenId::new(0, 0, 2001);
+    const FEE_TOKEN: TokenId = TokenId::new(0, 0, 2002);
+    const NESTED_FEE_TOKEN: TokenId = TokenId::new(0, 0, 2003);
+    const NFT: TokenId = TokenId::new(0, 0, 2004);
+
+    fn fee(fee: impl Into<crate::Fee>, collector: AccountId) -> AnyCustomFee {
+        AnyCustomFee {
+            fee: fee.into(),
+            fee_collector_account_id: Some(collector),
+            all_collectors_are_exempt: false,
+        }
+    }
+
+    fn fixed(amount: i64, denominating_token_id: Option<TokenId>) -> FixedFeeData {
+        FixedFeeData { amount, denominating_token_id }
+    }
+
+    fn fractional(
+        minimum_amount: i64,
+        maximum_amount: i64,
+        assessment_method: FeeAssessmentMethod,
+    ) -> FractionalFeeData {
+        FractionalFeeData {
+            numerator: 1,
+            denominator: 10,
+            minimum_amount,
+            maximum_amount,
+            assessment_method,
+        }
+    }
+
+    fn assessed(
+        amount: i64,
+        token_id: Option<TokenId>,
+        collector: AccountId,
+        payers: &[AccountId],
+    ) -> AssessedCustomFee {
+        AssessedCustomFee {
+            amount,
+            token_id,
+            fee_collector_account_id: Some(collector),
+            payer_account_id_list: payers.to_vec(),
+        }
+    }
+
+    fn token_transfer(amount: i64) -> TransferTransaction {
+        let mut tx = TransferTransaction::new();
+        tx.token_transfer(TOKEN, ALICE, -amount).token_transfer(TOKEN, BOB, amount);
+
+        tx
+    }
+
+    #[test]
+    fn fixed_fee() {
+        let mut simulator = CustomFeeSimulator::new();
+        simulator.token_custom_fees(TOKEN, [fee(fixed(5, None), COLLECTOR)]);
+
+        assert_eq!(
+            simulator.simulate_transfer(&token_transfer(100)).unwrap(),
+            [assessed(5, None, COLLECTOR, &[ALICE])]
+        );
+
+        // the collector doesn't pay its own fee.
+        let mut tx = TransferTransaction::new();
+        tx.token_transfer(TOKEN, COLLECTOR, -100).token_transfer(TOKEN, BOB, 100);
+
+        assert_eq!(simulator.simulate_transfer(&tx).unwrap(), []);
+    }
+
+    #[test]
+    fn fractional_fee_bounds() {
+        let mut simulator = CustomFeeSimulator::new();
+        simulator.token_custom_fees(
+            TOKEN,
+            [fee(fractional(3, 50, FeeAssessmentMethod::Inclusive), COLLECTOR)],
+        );
+
+        // 10% of 100.
+        assert_eq!(
+            simulator.simulate_transfer(&token_transfer(100)).unwrap(),
+            [assessed(10, Some(TOKEN), COLLECTOR, &[BOB])]
+        );
+
+        // raised to the minimum.
+        assert_eq!(
+            simulator.simulate_transfer(&token_transfer(10)).unwrap(),
+            [assessed(3, Some(TOKEN), COLLECTOR, &[BOB])]
+        );
+
+        // lowered to the maximum.
+        assert_eq!(
+            simulator.simulate_transfer(&token_transfer(1000)).unwrap(),
+            [assessed(50, Some(TOKEN), COLLECTOR, &[BOB])]
+        );
+    }
+
+    #[test]
+    fn fractional_fee_net_of_transfers() {
+        let mut simulator = CustomFeeSimulator::new();
+        simulator.token_custom_fees(
+            TOKEN,
+            [fee(fractional(0, 0, FeeAssessmentMethod::Exclusive), COLLECTOR)],
+        );
+
+        assert_eq!(
+            simulator.simulate_transfer(&token_transfer(1000)).unwrap(),
+            [assessed(100, Some(TOKEN), COLLECTOR, &[ALICE])]
+        );
+    }
+
+    #[test]
+    fn exempt_payers() {
+        let mut simulator = CustomFeeSimulator::new();
+        simulator.token_custom_fees(
+            TOKEN,
+            [
+                AnyCustomFee { all_collectors_are_exempt: true, ..fee(fixed(5, None), COLLECTOR) },
+                fee(fixed(7, None), OTHER_COLLECTOR),
+            ],
+        );
+
+        let mut tx = TransferTransaction::new();
+        tx.token_transfer(TOKEN, OTHER_COLLECTOR, -100).token_transfer(TOKEN, BOB, 100);
+
+        // `OTHER_COLLECTOR` is exempt from the first fee since all collectors are, and from its own fee.
+        assert_eq!(simulator.simulate_transfer(&tx).unwrap(), []);
+
+        let mut tx = TransferTransaction::new();
+        tx.token_transfer(TOKEN, COLLECTOR, -100).token_transfer(TOKEN, BOB, 100);
+
+        assert_eq!(
+            simulator.simulate_transfer(&tx).unwrap(),
+            [assessed(7, None, OTHER_COLLECTOR, &[COLLECTOR])]
+        );
+    }
+
+    #[test]
+    fn royalty_fee() {
+        let royalty =
+            RoyaltyFeeData { numerator: 1, denominator: 20, fallback_fee: Some(fixed(30, None)) };
+
+        let mut simulator = CustomFeeSimulator::new();
+        simulator.token_custom_fees(NFT, [fee(royalty, COLLECTOR)]);
+
+        // Alice sells two NFTs to Bob, the royalty is charged once on everything she receives.
+        let mut tx = TransferTransaction::new();
+        tx.nft_transfer(NftId::from((NFT, 1)), ALICE, BOB)
+            .nft_transfer(NftId::from((NFT, 2)), ALICE, BOB)
+            .hbar_transfer(BOB, Hbar::from_tinybars(-1000))
+            .hbar_transfer(ALICE, Hbar::from_tinybars(1000));
+
+        assert_eq!(
+            simulator.simulate_transfer(&tx).unwrap(),
+            [assessed(50, None, COLLECTOR, &[ALICE])]
+        );
+
+        // no value is exchanged, so Bob pays the fallback fee.
+        let mut tx = TransferTransaction::new();
+        tx.nft_transfer(NftId::from((NFT, 1)), ALICE, BOB);
+
+        assert_eq!(
+            simulator.simulate_transfer(&tx).unwrap(),
+            [assessed(30, None, COLLECTOR, &[BOB])]
+        );
+
+        // airdrop senders pay the fallback fee themselves.
+        let mut tx = TokenAirdropTransaction::new();
+        tx.nft_transfer(NftId::from((NFT, 1)), ALICE, BOB);
+
+        assert_eq!(
+            simulator.simulate_airdrop(&tx).unwrap(),
+            [assessed(30, None, COLLECTOR, &[ALICE])]
+        );
+    }
+
+    #[test]
+    fn nested_fees() {
+        let mut simulator = CustomFeeSimulator::new();
+        simulator
+            .token_custom_fees(TOKEN, [fee(fixed(5, Some(FEE_TOKEN)), COLLECTOR)])
+            .token_custom_fees(FEE_TOKEN, [fee(fixed(2, None), OTHER_COLLECTOR)]);
+
+        assert_eq!(
+            simulator.simulate_transfer(&token_transfer(100)).unwrap(),
+            [
+                assessed(5, Some(FEE_TOKEN), COLLECTOR, &[ALICE]),
+                assessed(2, None, OTHER_COLLECTOR, &[ALICE]),
+            ]
+        );
+
+        // the fee for the fee has a fee of its own, which is one level too deep.
+        simulator
+            .token_custom_fees(FEE_TOKEN, [fee(fixed(2, Some(NESTED_FEE_TOKEN)), OTHER_COLLECTOR)])
+            .token_custom_fees(NESTED_FEE_TOKEN, [fee(fixed(1, None), COLLECTOR)]);
+
+        assert_matches!(
+            simulator.simulate_transfer(&token_transfer(100)),
+            Err(Error::CustomFeeAssessment(Status::CustomFeeChargingExceededMaxRecursionDepth))
+        );
+
+        simulator.max_depth(3);
+
+        assert_eq!(simulator.simulate_transfer(&token_transfer(100)).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn unknown_fee_schedule() {
+        assert_matches!(
+            CustomFeeSimulator::new().simulate_transfer(&token_transfer(100)),
+            Err(Error::BasicParse(_))
+        );
+    }
+}
@@ -1,24 +1,37 @@
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "serde")]
+mod account_nfts_query;
 mod assessed_custom_fee;
+mod custom_fee_simulator;
 mod custom_fees;
 mod nft_id;
+#[cfg(feature = "serde")]
+mod nft_metadata;
+#[cfg(feature = "serde")]
+mod nft_transfer_history_query;
 mod token_airdrop_transaction;
+mod token_amount;
 mod token_associate_transaction;
 mod token_association;
 mod token_burn_transaction;
 mod token_cancel_airdrop_transaction;
 mod token_claim_airdrop_transaction;
+#[cfg(feature = "serde")]
+mod token_compliance_flow;
 mod token_create_transaction;
 mod token_delete_transaction;
 mod token_dissociate_transaction;
 mod token_fee_schedule_update_transaction;
 mod token_freeze_transaction;
 mod token_grant_kyc_transaction;
+#[cfg(feature = "serde")]
+mod token_holders_query;
 mod token_id;
 mod token_info;
 mod token_info_query;
 mod token_key_validation_type;
+mod token_mint_flow;
 mod token_mint_transaction;
 mod token_nft_info;
 mod token_nft_info_query;
@@ -28,6 +41,8 @@ mod token_reject_flow;
 mod token_reject_transaction;
 mod token_revoke_kyc_transaction;
 mod token_supply_type;
+#[cfg(feature = "serde")]
+mod token_transfer_flow;
 mod token_type;
 mod token_unfreeze_transaction;
 mod token_unpause_transaction;
@@ -35,7 +50,10 @@ mod token_update_nfts_transaction;
 mod token_update_transaction;
 mod token_wipe_transaction;
 
+#[cfg(feature = "serde")]
+pub use account_nfts_query::AccountNftsQuery;
 pub use assessed_custom_fee::AssessedCustomFee;
+pub use custom_fee_simulator::CustomFeeSimulator;
 pub use custom_fees::{
     AnyCustomFee,
     CustomFee,
@@ -49,10 +67,25 @@ pub use custom_fees::{
     RoyaltyFeeData,
 };
 pub use nft_id::NftId;
+#[cfg(feature = "serde")]
+pub use nft_metadata::{
+    NftMetadata,
+    NftMetadataAttribute,
+    NftMetadataBuilder,
+    NftMetadataFile,
+    NftMetadataLocalization,
+    OnLedgerNftMetadata,
+};
+#[cfg(feature = "serde")]
+pub use nft_transfer_history_query::{
+    NftTransferHistoryQuery,
+    NftTransferRecord,
+};
 pub use token_airdrop_transaction::{
     TokenAirdropTransaction,
     TokenAirdropTransactionData,
 };
+pub use token_amount::TokenAmount;
 pub use token_associate_transaction::{
     TokenAssociateTransaction,
     TokenAssociateTransactionData,
@@ -70,6 +103,14 @@ pub use token_claim_airdrop_transaction::{
     TokenClaimAirdropTransaction,
     TokenClaimAirdropTransactionData,
 };
+#[cfg(feature = "serde")]
+pub use token_compliance_flow::{
+    TokenComplianceAction,
+    TokenComplianceEntry,
+    TokenComplianceFlow,
+    TokenComplianceOutcome,
+    TokenComplianceReport,
+};
 pub use token_create_transaction::{
     TokenCreateTransaction,
     TokenCreateTransactionData,
@@ -94,6 +135,11 @@ pub use token_grant_kyc_transaction::{
     TokenGrantKycTransaction,
     TokenGrantKycTransactionData,
 };
+#[cfg(feature = "serde")]
+pub use token_holders_query::{
+    TokenHolder,
+    TokenHoldersQuery,
+};
 pub use token_id::TokenId;
 pub use token_info::TokenInfo;
 pub use token_info_query::{
@@ -101,6 +147,10 @@ pub use token_info_query::{
     TokenInfoQueryData,
 };
 pub use token_key_validation_type::TokenKeyValidation;
+pub use token_mint_flow::{
+    TokenMintFlow,
+    TokenMintSerials,
+};
 pub use token_mint_transaction::{
     TokenMintTransaction,
     TokenMintTransactionData,
@@ -125,6 +175,13 @@ pub use token_revoke_kyc_transaction::{
     TokenRevokeKycTransactionData,
 };
 pub use token_supply_type::TokenSupplyType;
+#[cfg(feature = "serde")]
+pub use token_transfer_flow::{
+    TokenDelivery,
+    TokenTransferFlow,
+    TokenTransferFlowReceipts,
+    TokenTransferRecipient,
+};
 pub use token_type::TokenType;
 pub use token_unfreeze_transaction::{
     TokenUnfreezeTransaction,
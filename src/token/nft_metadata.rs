@@ -0,0 +1,704 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde_json::{
+    Map,
+    Value,
+};
+
+use crate::Error;
+
+/// NFT metadata following [HIP-412](https://hips.hedera.com/hip/hip-412).
+///
+/// Use [`NftMetadataBuilder`] to create new metadata,
+/// and [`OnLedgerNftMetadata`] to store it (or a URI pointing to it) on ledger.
+///
+/// # Examples
+/// ```
+/// # fn main() -> hiero_sdk::Result<()> {
+/// use hiero_sdk::{NftMetadata, NftMetadataBuilder};
+///
+/// let metadata = NftMetadataBuilder::new("Cat #1", "ipfs://bafy.../cat.png", "image/png")
+///     .creator("Cats Inc.")
+///     .build()?;
+///
+/// assert_eq!(NftMetadata::from_json(&metadata.to_json())?, metadata);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+#[non_exhaustive]
+pub struct NftMetadata {
+    /// The name of the NFT.
+    pub name: String,
+
+    /// The artist(s), or company who created the NFT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<String>,
+
+    /// The decentralized identifier of the creator.
+    #[serde(rename = "creatorDID", default, skip_serializing_if = "Option::is_none")]
+    pub creator_did: Option<String>,
+
+    /// A human readable description of the NFT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// A URI pointing to the preview image of the NFT.
+    pub image: String,
+
+    /// A cryptographic hash of `image`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+
+    /// The MIME type of `image`, such as `image/png`.
+    #[serde(rename = "type")]
+    pub mime_type: String,
+
+    /// The name and version of the metadata standard, usually [`NftMetadata::FORMAT`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+
+    /// Arbitrary, application specific, properties.
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub properties: Map<String, Value>,
+
+    /// The files that make up the NFT.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<NftMetadataFile>,
+
+    /// The traits of the NFT.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<NftMetadataAttribute>,
+
+    /// Where to find translations of the metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub localization: Option<NftMetadataLocalization>,
+}
+
+impl NftMetadata {
+    /// The `format` of HIP-412 version 2.0.0 metadata.
+    pub const FORMAT: &'static str = "HIP412@2.0.0";
+
+    /// Parses HIP-412 metadata from JSON, validating it against the HIP-412 schema.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `json` isn't valid JSON, or isn't valid HIP-412 metadata.
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        let value: Value = serde_json::from_str(json).map_err(Error::basic_parse)?;
+
+        validate_metadata(&value, "")?;
+
+        serde_json::from_value(value).map_err(Error::basic_parse)
+    }
+
+    /// Returns the metadata as compact JSON, leaving out any unset fields.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        // every map key is a string, so this can't fail.
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Validates the metadata against the HIP-412 schema.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the metadata isn't valid HIP-412 metadata.
+    pub fn validate(&self) -> crate::Result<()> {
+        validate_metadata(&serde_json::to_value(self).map_err(Error::basic_parse)?, "")
+    }
+}
+
+/// A file that makes up part of an NFT, see [`NftMetadata::files`].
+#[derive(Debug, Clone, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+#[non_exhaustive]
+pub struct NftMetadataFile {
+    /// A URI pointing to the file.
+    pub uri: String,
+
+    /// A cryptographic hash of the file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+
+    /// Whether this is the main file of the NFT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_default_file: Option<bool>,
+
+    /// The MIME type of the file.
+    #[serde(rename = "type")]
+    pub mime_type: String,
+
+    /// Metadata describing the file itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<NftMetadata>>,
+
+    /// A URI pointing to metadata describing the file itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_uri: Option<String>,
+}
+
+impl NftMetadataFile {
+    /// Create a new `NftMetadataFile` for the file at `uri`.
+    #[must_use]
+    pub fn new(uri: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        Self {
+            uri: uri.into(),
+            checksum: None,
+            is_default_file: None,
+            mime_type: mime_type.into(),
+            metadata: None,
+            metadata_uri: None,
+        }
+    }
+}
+
+/// A trait of an NFT, see [`NftMetadata::attributes`].
+#[derive(Debug, Clone, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+#[non_exhaustive]
+pub struct NftMetadataAttribute {
+    /// The name of the trait.
+    pub trait_type: String,
+
+    /// How the trait should be displayed,
+    /// one of `text`, `boolean`, `percentage`, `boost`, `datetime`, `date`, or `color`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_type: Option<String>,
+
+    /// The value of the trait, a string, number, or boolean.
+    pub value: Value,
+
+    /// The largest possible value of a numeric trait.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<Value>,
+}
+
+impl NftMetadataAttribute {
+    /// Create a new `NftMetadataAttribute` with the given trait and value.
+    #[must_use]
+    pub fn new(trait_type: impl Into<String>, value: impl Into<Value>) -> Self {
+        Self {
+            trait_type: trait_type.into(),
+            display_type: None,
+            value: value.into(),
+            max_value: None,
+        }
+    }
+}
+
+/// Where to find translations of an NFT's metadata, see [`NftMetadata::localization`].
+#[derive(Debug, Clone, PartialEq, Eq, serde_derive::Serialize, serde_derive::Deserialize)]
+#[non_exhaustive]
+pub struct NftMetadataLocalization {
+    /// A URI template for the translated metadata, containing `{locale}`.
+    pub uri: String,
+
+    /// The two-letter ISO 639-1 code of the locale the metadata itself is in.
+    pub default: String,
+
+    /// The two-letter ISO 639-1 codes of every translation available at `uri`.
+    pub locales: Vec<String>,
+}
+
+impl NftMetadataLocalization {
+    /// Create a new `NftMetadataLocalization`.
+    #[must_use]
+    pub fn new(uri: impl Into<String>, default: impl Into<String>, locales: Vec<String>) -> Self {
+        Self { uri: uri.into(), default: default.into(), locales }
+    }
+}
+
+/// Builds valid [`NftMetadata`].
+#[derive(Debug, Clone)]
+pub struct NftMetadataBuilder {
+    metadata: NftMetadata,
+}
+
+impl NftMetadataBuilder {
+    /// Creates a new builder for an NFT called `name`, previewed by the `mime_type` image at `image`.
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        image: impl Into<String>,
+        mime_type: impl Into<String>,
+    ) -> Self {
+        Self {
+            metadata: NftMetadata {
+                name: name.into(),
+                creator: None,
+                creator_did: None,
+                description: None,
+                image: image.into(),
+                checksum: None,
+                mime_type: mime_type.into(),
+                format: None,
+                properties: Map::new(),
+                files: Vec::new(),
+                attributes: Vec::new(),
+                localization: None,
+            },
+        }
+    }
+
+    /// Sets the artist(s), or company who created the NFT.
+    pub fn creator(&mut self, creator: impl Into<String>) -> &mut Self {
+        self.metadata.creator = Some(creator.into());
+        self
+    }
+
+    /// Sets the decentralized identifier of the creator.
+    pub fn creator_did(&mut self, creator_did: impl Into<String>) -> &mut Self {
+        self.metadata.creator_did = Some(creator_did.into());
+        self
+    }
+
+    /// Sets the human readable description of the NFT.
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        self.metadata.description = Some(description.into());
+        self
+    }
+
+    /// Sets the cryptographic hash of the image.
+    pub fn checksum(&mut self, checksum: impl Into<String>) -> &mut Self {
+        self.metadata.checksum = Some(checksum.into());
+        self
+    }
+
+    /// Sets the name and version of the metadata standard, usually [`NftMetadata::FORMAT`].
+    pub fn format(&mut self, format: impl Into<String>) -> &mut Self {
+        self.metadata.format = Some(format.into());
+        self
+    }
+
+    /// Sets the application specific property `key` to `value`.
+    pub fn property(&mut self, key: impl Into<String>, value: impl Into<Value>) -> &mut Self {
+        self.metadata.properties.insert(key.into(), value.into());
+        self
+    }
+
+    /// Adds a file to the NFT.
+    pub fn file(&mut self, file: NftMetadataFile) -> &mut Self {
+        self.metadata.files.push(file);
+        self
+    }
+
+    /// Adds a trait to the NFT.
+    pub fn attribute(&mut self, attribute: NftMetadataAttribute) -> &mut Self {
+        self.metadata.attributes.push(attribute);
+        self
+    }
+
+    /// Sets where to find translations of the metadata.
+    pub fn localization(&mut self, localization: NftMetadataLocalization) -> &mut Self {
+        self.metadata.localization = Some(localization);
+        self
+    }
+
+    /// Builds the metadata.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the metadata isn't valid HIP-412 metadata.
+    pub fn build(&self) -> crate::Result<NftMetadata> {
+        self.metadata.validate()?;
+
+        Ok(self.metadata.clone())
+    }
+}
+
+/// The metadata of a single NFT, as stored on ledger.
+///
+/// On ledger metadata is limited to [`OnLedgerNftMetadata::MAX_LEN`] bytes,
+/// so it's usually a URI pointing to the HIP-412 JSON,
+/// but small enough metadata can be stored inline as compact JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OnLedgerNftMetadata {
+    /// A URI pointing to HIP-412 JSON, such as `ipfs://bafy.../metadata.json`.
+    Uri(String),
+
+    /// HIP-412 metadata stored directly on ledger.
+    Inline(Box<NftMetadata>),
+}
+
+impl OnLedgerNftMetadata {
+    /// The largest number of bytes of metadata a single NFT can have on ledger.
+    pub const MAX_LEN: usize = 100;
+
+    /// Parses on ledger metadata, such as [`TokenNftInfo::metadata`](crate::TokenNftInfo::metadata).
+    ///
+    /// Metadata starting with `{` is parsed as inline JSON, anything else as a URI.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `bytes` isn't valid inline HIP-412 metadata or a valid URI.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let text = std::str::from_utf8(bytes).map_err(Error::basic_parse)?;
+
+        if text.trim_start().starts_with('{') {
+            return NftMetadata::from_json(text).map(|it| Self::Inline(Box::new(it)));
+        }
+
+        validate_uri(text)?;
+
+        Ok(Self::Uri(text.to_owned()))
+    }
+
+    /// Returns the bytes to store on ledger.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the metadata is invalid, or more than [`MAX_LEN`](Self::MAX_LEN) bytes.
+    pub fn to_bytes(&self) -> crate::Result<Vec<u8>> {
+        let bytes = match self {
+            Self::Uri(uri) => {
+                validate_uri(uri)?;
+                uri.clone().into_bytes()
+            }
+
+            Self::Inline(metadata) => {
+                metadata.validate()?;
+                metadata.to_json().into_bytes()
+            }
+        };
+
+        if bytes.len() > Self::MAX_LEN {
+            return Err(Error::basic_parse(format!(
+                "NFT metadata is {} bytes, but at most {} bytes fit on ledger",
+                bytes.len(),
+                Self::MAX_LEN
+            )));
+        }
+
+        Ok(bytes)
+    }
+}
+
+impl From<NftMetadata> for OnLedgerNftMetadata {
+    fn from(value: NftMetadata) -> Self {
+        Self::Inline(Box::new(value))
+    }
+}
+
+const DISPLAY_TYPES: [&str; 7] =
+    ["text", "boolean", "percentage", "boost", "datetime", "date", "color"];
+
+fn invalid(path: &str, message: &str) -> Error {
+    Error::basic_parse(format!("invalid HIP-412 metadata: `{path}` {message}"))
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+/// Returns the string at `key`, treating `null` as absent.
+fn optional_string<'a>(
+    object: &'a Map<String, Value>,
+    path: &str,
+    key: &str,
+) -> crate::Result<Option<&'a str>> {
+    match object.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(it)) => Ok(Some(it)),
+        Some(_) => Err(invalid(&join(path, key), "must be a string")),
+    }
+}
+
+fn required_string<'a>(
+    object: &'a Map<String, Value>,
+    path: &str,
+    key: &str,
+) -> crate::Result<&'a str> {
+    match optional_string(object, path, key)? {
+        Some(it) if !it.is_empty() => Ok(it),
+        _ => Err(invalid(&join(path, key), "is required")),
+    }
+}
+
+fn required_mime_type(object: &Map<String, Value>, path: &str) -> crate::Result<()> {
+    let mime_type = required_string(object, path, "type")?;
+
+    let valid = mime_type.split_once('/').is_some_and(|(kind, subtype)| {
+        !kind.is_empty()
+            && !subtype.is_empty()
+            && !subtype.contains('/')
+            && mime_type.bytes().all(|it| it.is_ascii_graphic())
+    });
+
+    if !valid {
+        return Err(invalid(&join(path, "type"), "must be a MIME type, such as `image/png`"));
+    }
+
+    Ok(())
+}
+
+fn array<'a>(object: &'a Map<String, Value>, path: &str, key: &str) -> crate::Result<&'a [Value]> {
+    match object.get(key) {
+        None => Ok(&[]),
+        Some(Value::Array(it)) => Ok(it),
+        Some(_) => Err(invalid(&join(path, key), "must be an array")),
+    }
+}
+
+fn is_locale(locale: &str) -> bool {
+    locale.len() == 2 && locale.bytes().all(|it| it.is_ascii_lowercase())
+}
+
+fn validate_metadata(value: &Value, path: &str) -> crate::Result<()> {
+    let Value::Object(object) = value else {
+        return Err(invalid(if path.is_empty() { "." } else { path }, "must be an object"));
+    };
+
+    required_string(object, path, "name")?;
+    required_string(object, path, "image")?;
+    required_mime_type(object, path)?;
+
+    for key in ["creator", "creatorDID", "description", "checksum", "format"] {
+        optional_string(object, path, key)?;
+    }
+
+    if object.get("properties").is_some_and(|it| !it.is_object()) {
+        return Err(invalid(&join(path, "properties"), "must be an object"));
+    }
+
+    let files = join(path, "files");
+    for (index, file) in array(object, path, "files")?.iter().enumerate() {
+        validate_file(file, &format!("{files}[{index}]"))?;
+    }
+
+    let attributes = join(path, "attributes");
+    for (index, attribute) in array(object, path, "attributes")?.iter().enumerate() {
+        validate_attribute(attribute, &format!("{attributes}[{index}]"))?;
+    }
+
+    match object.get("localization") {
+        None | Some(Value::Null) => Ok(()),
+        Some(localization) => validate_localization(localization, &join(path, "localization")),
+    }
+}
+
+fn validate_file(value: &Value, path: &str) -> crate::Result<()> {
+    let Value::Object(object) = value else {
+        return Err(invalid(path, "must be an object"));
+    };
+
+    required_string(object, path, "uri")?;
+    required_mime_type(object, path)?;
+    optional_string(object, path, "checksum")?;
+    optional_string(object, path, "metadata_uri")?;
+
+    match object.get("is_default_file") {
+        None | Some(Value::Null | Value::Bool(_)) => {}
+        Some(_) => return Err(invalid(&join(path, "is_default_file"), "must be a boolean")),
+    }
+
+    match object.get("metadata") {
+        None | Some(Value::Null) => Ok(()),
+        Some(metadata) => validate_metadata(metadata, &join(path, "metadata")),
+    }
+}
+
+fn validate_attribute(value: &Value, path: &str) -> crate::Result<()> {
+    let Value::Object(object) = value else {
+        return Err(invalid(path, "must be an object"));
+    };
+
+    required_string(object, path, "trait_type")?;
+
+    let display_type = optional_string(object, path, "display_type")?;
+
+    if display_type.is_some_and(|it| !DISPLAY_TYPES.contains(&it)) {
+        return Err(invalid(
+            &join(path, "display_type"),
+            &format!("must be one of {}", DISPLAY_TYPES.join(", ")),
+        ));
+    }
+
+    let value = object.get("value").unwrap_or(&Value::Null);
+
+    let (valid, expected) = match display_type {
+        Some("text" | "color") => (value.is_string(), "a string"),
+        Some("boolean") => (value.is_boolean(), "a boolean"),
+        Some(_) => (value.is_number(), "a number"),
+        None => (
+            value.is_string() || value.is_number() || value.is_boolean(),
+            "a string, number, or boolean",
+        ),
+    };
+
+    if !valid {
+        return Err(invalid(&join(path, "value"), &format!("must be {expected}")));
+    }
+
+    match object.get("max_value") {
+        None | Some(Value::Null) => Ok(()),
+        Some(Value::Number(_)) if value.is_number() => Ok(()),
+        Some(_) => Err(invalid(&join(path, "max_value"), "must be a number, for a numeric value")),
+    }
+}
+
+fn validate_localization(value: &Value, path: &str) -> crate::Result<()> {
+    let Value::Object(object) = value else {
+        return Err(invalid(path, "must be an object"));
+    };
+
+    if !required_string(object, path, "uri")?.contains("{locale}") {
+        return Err(invalid(&join(path, "uri"), "must contain `{locale}`"));
+    }
+
+    if !is_locale(required_string(object, path, "default")?) {
+        return Err(invalid(&join(path, "default"), "must be a two-letter ISO 639-1 code"));
+    }
+
+    let Some(Value::Array(locales)) = object.get("locales") else {
+        return Err(invalid(&join(path, "locales"), "is required"));
+    };
+
+    for (index, locale) in locales.iter().enumerate() {
+        if !locale.as_str().is_some_and(is_locale) {
+            return Err(invalid(
+                &format!("{}[{index}]", join(path, "locales")),
+                "must be a two-letter ISO 639-1 code",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_uri(uri: &str) -> crate::Result<()> {
+    let has_scheme = uri.split_once(':').is_some_and(|(scheme, _)| {
+        scheme.starts_with(|it: char| it.is_ascii_alphabetic())
+            && scheme.chars().all(|it| it.is_ascii_alphanumeric() || matches!(it, '+' | '-' | '.'))
+    });
+
+    if !has_scheme || uri.chars().any(|it| it.is_whitespace() || it.is_control()) {
+        return Err(Error::basic_parse(format!("invalid NFT metadata URI `{uri}`")));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        NftMetadata,
+        NftMetadataAttribute,
+        NftMetadataBuilder,
+        NftMetadataFile,
+        NftMetadataLocalization,
+        OnLedgerNftMetadata,
+    };
+
+    #[test]
+    fn from_json() {
+        let json = json!({
+            "name": "Cat #1",
+            "creator": "Cats Inc.",
+            "image": "ipfs://bafy/cat.png",
+            "type": "image/png",
+            "format": "HIP412@2.0.0",
+            "properties": { "edition": 1 },
+            "files": [{
+                "uri": "ipfs://bafy/cat.mp4",
+                "type": "video/mp4",
+                "is_default_file": true,
+                "metadata": { "name": "Cat video", "image": "ipfs://bafy/cat.png", "type": "image/png" }
+            }],
+            "attributes": [
+                { "trait_type": "color", "value": "orange" },
+                { "trait_type": "speed", "display_type": "boost", "value": 5, "max_value": 10 }
+            ],
+            "localization": { "uri": "ipfs://bafy/{locale}.json", "default": "en", "locales": ["es", "fr"] }
+        });
+
+        let metadata = NftMetadata::from_json(&json.to_string()).unwrap();
+
+        let expected = NftMetadataBuilder::new("Cat #1", "ipfs://bafy/cat.png", "image/png")
+            .creator("Cats Inc.")
+            .format(NftMetadata::FORMAT)
+            .property("edition", 1)
+            .file({
+                let mut file = NftMetadataFile::new("ipfs://bafy/cat.mp4", "video/mp4");
+                file.is_default_file = Some(true);
+                file.metadata = Some(Box::new(
+                    NftMetadataBuilder::new("Cat video", "ipfs://bafy/cat.png", "image/png")
+                        .build()
+                        .unwrap(),
+                ));
+                file
+            })
+            .attribute(NftMetadataAttribute::new("color", "orange"))
+            .attribute({
+                let mut attribute = NftMetadataAttribute::new("speed", 5);
+                attribute.display_type = Some("boost".to_owned());
+                attribute.max_value = Some(10.into());
+                attribute
+            })
+            .localization(NftMetadataLocalization::new(
+                "ipfs://bafy/{locale}.json",
+                "en",
+                vec!["es".to_owned(), "fr".to_owned()],
+            ))
+            .build()
+            .unwrap();
+
+        assert_eq!(metadata, expected);
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&metadata.to_json()).unwrap(), json);
+    }
+
+    #[test]
+    fn invalid() {
+        let invalid = [
+            json!([]),
+            json!({ "image": "ipfs://bafy/cat.png", "type": "image/png" }),
+            json!({ "name": "Cat", "image": "ipfs://bafy/cat.png" }),
+            json!({ "name": "Cat", "image": "ipfs://bafy/cat.png", "type": "png" }),
+            json!({ "name": 1, "image": "ipfs://bafy/cat.png", "type": "image/png" }),
+            json!({ "name": "Cat", "image": "i", "type": "image/png", "files": [{ "uri": "ipfs://bafy/cat.mp4" }] }),
+            json!({ "name": "Cat", "image": "i", "type": "image/png", "attributes": [{ "value": 1 }] }),
+            json!({ "name": "Cat", "image": "i", "type": "image/png", "attributes": [{ "trait_type": "t", "value": [] }] }),
+            json!({ "name": "Cat", "image": "i", "type": "image/png", "attributes": [{ "trait_type": "t", "display_type": "boost", "value": "fast" }] }),
+            json!({ "name": "Cat", "image": "i", "type": "image/png", "attributes": [{ "trait_type": "t", "display_type": "sparkly", "value": 1 }] }),
+            json!({ "name": "Cat", "image": "i", "type": "image/png", "localization": { "uri": "ipfs://bafy/en.json", "default": "en", "locales": [] } }),
+            json!({ "name": "Cat", "image": "i", "type": "image/png", "localization": { "uri": "ipfs://bafy/{locale}.json", "default": "english", "locales": [] } }),
+        ];
+
+        for json in invalid {
+            assert!(NftMetadata::from_json(&json.to_string()).is_err(), "{json}");
+        }
+
+        assert!(NftMetadataBuilder::new("", "ipfs://bafy/cat.png", "image/png").build().is_err());
+    }
+
+    #[test]
+    fn on_ledger() {
+        let uri = OnLedgerNftMetadata::Uri("ipfs://bafy/metadata.json".to_owned());
+
+        assert_eq!(uri.to_bytes().unwrap(), b"ipfs://bafy/metadata.json");
+        assert_eq!(OnLedgerNftMetadata::from_bytes(b"ipfs://bafy/metadata.json").unwrap(), uri);
+
+        let inline: OnLedgerNftMetadata =
+            NftMetadataBuilder::new("Cat #1", "ipfs://bafy/cat.png", "image/png")
+                .build()
+                .unwrap()
+                .into();
+
+        let bytes = inline.to_bytes().unwrap();
+
+        assert_eq!(bytes, br#"{"name":"Cat #1","image":"ipfs://bafy/cat.png","type":"image/png"}"#);
+        assert_eq!(OnLedgerNftMetadata::from_bytes(&bytes).unwrap(), inline);
+
+        let too_long = NftMetadataBuilder::new("Cat #1", "ipfs://bafy/cat.png", "image/png")
+            .description("A very orange cat, who likes to sleep in the sun")
+            .build()
+            .unwrap();
+
+        assert!(OnLedgerNftMetadata::from(too_long).to_bytes().is_err());
+        assert!(OnLedgerNftMetadata::Uri(format!("ipfs://{}", "a".repeat(100)))
+            .to_bytes()
+            .is_err());
+        assert!(OnLedgerNftMetadata::Uri("not a uri".to_owned()).to_bytes().is_err());
+        assert!(OnLedgerNftMetadata::from_bytes(b"").is_err());
+        assert!(OnLedgerNftMetadata::from_bytes(b"{\"name\":\"Cat\"}").is_err());
+    }
+}
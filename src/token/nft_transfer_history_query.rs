@@ -0,0 +1,294 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use futures_core::stream::BoxStream;
+use futures_util::{
+    StreamExt,
+    TryStreamExt,
+};
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use super::NftId;
+use crate::mirror_rest::{
+    self,
+    DEFAULT_MAX_ATTEMPTS,
+    DEFAULT_MAX_BACKOFF,
+};
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    TransactionId,
+};
+
+/// The most transfers the mirror node returns in a single page.
+const PAGE_SIZE: usize = 100;
+
+/// A transaction that changed the owner of an NFT, as listed by a [`NftTransferHistoryQuery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct NftTransferRecord {
+    /// The NFT that was transferred.
+    pub nft_id: NftId,
+
+    /// The ID of the transaction.
+    pub transaction_id: TransactionId,
+
+    /// The consensus timestamp of the transaction.
+    pub consensus_timestamp: OffsetDateTime,
+
+    /// The type of the transaction, like `CRYPTOTRANSFER` or `TOKENMINT`.
+    pub transaction_type: String,
+
+    /// The account the NFT was transferred from, or `None` if it was minted.
+    pub sender_account_id: Option<AccountId>,
+
+    /// The account the NFT was transferred to, or `None` if it was burned or wiped.
+    pub receiver_account_id: Option<AccountId>,
+
+    /// Whether the transfer was made with an allowance.
+    pub is_approval: bool,
+}
+
+/// Lists the transactions that changed the owner of an NFT, newest first, using the mirror node.
+///
+/// # Examples
+/// ```no_run
+/// # async fn example() -> hiero_sdk::Result<()> {
+/// use hiero_sdk::{Client, NftTransferHistoryQuery, TokenId};
+///
+/// let client = Client::for_testnet();
+///
+/// let history = NftTransferHistoryQuery::new()
+///     .nft_id(TokenId::new(0, 0, 1234).nft(1))
+///     .execute(&client)
+///     .await?;
+///
+/// for transfer in history {
+///     println!("{:?} -> {:?}", transfer.sender_account_id, transfer.receiver_account_id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NftTransferHistoryQuery {
+    nft_id: Option<NftId>,
+    limit: Option<usize>,
+    max_attempts: usize,
+    max_backoff: Duration,
+}
+
+impl Default for NftTransferHistoryQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NftTransferHistoryQuery {
+    /// Creates a new `NftTransferHistoryQuery`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            nft_id: None,
+            limit: None,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+
+    /// Returns the NFT to list the transfers of.
+    #[must_use]
+    pub fn get_nft_id(&self) -> Option<NftId> {
+        self.nft_id
+    }
+
+    /// Sets the NFT to list the transfers of.
+    pub fn nft_id(&mut self, nft_id: impl Into<NftId>) -> &mut Self {
+        self.nft_id = Some(nft_id.into());
+        self
+    }
+
+    /// Returns the most transfers to list.
+    #[must_use]
+    pub fn get_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Sets the most transfers to list, by default every transfer is listed.
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the maximum number of retry attempts.
+    #[must_use]
+    pub fn get_max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    /// Sets the maximum number of retry attempts.
+    pub fn max_attempts(&mut self, max_attempts: usize) -> &mut Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Returns the maximum backoff duration between retries.
+    #[must_use]
+    pub fn get_max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// Sets the maximum backoff duration between retries.
+    pub fn max_backoff(&mut self, max_backoff: Duration) -> &mut Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Lists the transfers.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the NFT isn't set.
+    /// - [`Error::BasicParse`] if the mirror node returns an error, or a response that can't be parsed.
+    pub async fn execute(&self, client: &Client) -> crate::Result<Vec<NftTransferRecord>> {
+        self.subscribe(client).try_collect().await
+    }
+
+    /// Streams the transfers, fetching each page from the mirror node once it's needed.
+    ///
+    /// The stream ends after the first error, see [`execute`](Self::execute) for the errors.
+    pub fn subscribe<'a>(
+        &self,
+        client: &'a Client,
+    ) -> BoxStream<'a, crate::Result<NftTransferRecord>> {
+        let Some(nft_id) = self.nft_id else {
+            let error = Error::basic_parse("NFT must be set on NftTransferHistoryQuery");
+            return Box::pin(futures_util::stream::once(async { Err(error) }));
+        };
+
+        let transfers = mirror_rest::stream_all(
+            mirror_rest::base_url(client),
+            &self.path(nft_id),
+            "transactions",
+            self.limit,
+            self.max_attempts,
+            self.max_backoff,
+        );
+
+        Box::pin(transfers.map(move |it| it.and_then(|transfer| parse_transfer(&transfer, nft_id))))
+    }
+
+    fn path(&self, nft_id: NftId) -> String {
+        let page_size = self.limit.map_or(PAGE_SIZE, |it| it.clamp(1, PAGE_SIZE));
+
+        format!("/tokens/{}/nfts/{}/transactions?limit={page_size}", nft_id.token_id, nft_id.serial)
+    }
+}
+
+/// Parses a mirror node transaction ID, like `0.0.2-1651560386-661997287`.
+fn parse_transaction_id(transaction_id: &str) -> crate::Result<TransactionId> {
+    let invalid =
+        || Error::basic_parse(format!("invalid mirror node transaction ID `{transaction_id}`"));
+
+    let mut parts = transaction_id.rsplitn(3, '-');
+
+    let nanos: i64 = parts.next().and_then(|it| it.parse().ok()).ok_or_else(invalid)?;
+    let seconds: i64 = parts.next().and_then(|it| it.parse().ok()).ok_or_else(invalid)?;
+    let account_id = parts.next().ok_or_else(invalid)?.parse()?;
+
+    Ok(TransactionId {
+        account_id,
+        valid_start: OffsetDateTime::from_unix_timestamp(seconds).map_err(Error::basic_parse)?
+            + time::Duration::nanoseconds(nanos),
+        nonce: None,
+        scheduled: false,
+    })
+}
+
+// `{"consensus_timestamp": "1618591023.997420021", "is_approval": false, "nonce": 0, "receiver_account_id": "0.0.11",
+//   "sender_account_id": "0.0.10", "transaction_id": "0.0.19789-1618591023-997420021", "type": "CRYPTOTRANSFER"}`
+fn parse_transfer(value: &Value, nft_id: NftId) -> crate::Result<NftTransferRecord> {
+    let field = |key: &str| {
+        value.get(key).and_then(Value::as_str).ok_or_else(|| {
+            Error::basic_parse(format!("mirror node NFT transfer is missing `{key}`"))
+        })
+    };
+
+    let account = |key: &str| value.get(key).and_then(Value::as_str).map(str::parse).transpose();
+
+    let mut transaction_id = parse_transaction_id(field("transaction_id")?)?;
+
+    transaction_id.nonce = value
+        .get("nonce")
+        .and_then(Value::as_i64)
+        .filter(|it| *it != 0)
+        .map(i32::try_from)
+        .transpose()
+        .map_err(Error::basic_parse)?;
+
+    transaction_id.scheduled = value.get("scheduled").and_then(Value::as_bool).unwrap_or_default();
+
+    Ok(NftTransferRecord {
+        nft_id,
+        transaction_id,
+        consensus_timestamp: mirror_rest::parse_timestamp(field("consensus_timestamp")?)?,
+        transaction_type: field("type")?.to_owned(),
+        sender_account_id: account("sender_account_id")?,
+        receiver_account_id: account("receiver_account_id")?,
+        is_approval: value.get("is_approval").and_then(Value::as_bool).unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{
+        parse_transaction_id,
+        parse_transfer,
+    };
+    use crate::{
+        AccountId,
+        NftTransferHistoryQuery,
+        TokenId,
+    };
+
+    #[test]
+    fn path() {
+        let nft_id = TokenId::new(0, 0, 4).nft(7);
+
+        assert_eq!(
+            NftTransferHistoryQuery::new().limit(5).path(nft_id),
+            "/tokens/0.0.4/nfts/7/transactions?limit=5"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let nft_id = TokenId::new(0, 0, 4).nft(7);
+
+        let transfer = parse_transfer(
+            &json!({
+                "consensus_timestamp": "1618591023.997420021",
+                "is_approval": false,
+                "nonce": 0,
+                "receiver_account_id": "0.0.11",
+                "sender_account_id": null,
+                "transaction_id": "0.0.19789-1618591023-997420021",
+                "type": "TOKENMINT"
+            }),
+            nft_id,
+        )
+        .unwrap();
+
+        assert_eq!(transfer.nft_id, nft_id);
+        assert_eq!(transfer.transaction_id, "0.0.19789@1618591023.997420021".parse().unwrap());
+        assert_eq!(transfer.consensus_timestamp.nanosecond(), 997420021);
+        assert_eq!(transfer.transaction_type, "TOKENMINT");
+        assert_eq!(transfer.sender_account_id, None);
+        assert_eq!(transfer.receiver_account_id, Some(AccountId::new(0, 0, 11)));
+
+        assert!(parse_transaction_id("0.0.19789@1618591023.997420021").is_err());
+    }
+}
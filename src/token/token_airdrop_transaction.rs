@@ -8,6 +8,7 @@ use tonic::transport::Channel;
 
 use super::{
     NftId,
+    TokenAmount,
     TokenId,
     TokenNftTransfer,
 };
@@ -92,6 +93,12 @@ pub struct TokenAirdropTransactionData {
     token_transfers: Vec<TokenTransfer>,
 }
 
+impl TokenAirdropTransactionData {
+    pub(crate) fn token_transfers(&self) -> &[TokenTransfer] {
+        &self.token_transfers
+    }
+}
+
 impl TokenAirdropTransaction {
     /// Add a non-approved token transfer.
     pub fn token_transfer(
@@ -191,6 +198,36 @@ impl TokenAirdropTransaction {
         self
     }
 
+    /// Add a non-approved token transfer of `amount`, ensuring that the token has `amount`'s decimals.
+    pub fn token_amount_transfer(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: TokenAmount,
+    ) -> &mut Self {
+        self.token_transfer_with_decimals(
+            token_id,
+            account_id,
+            amount.get_value(),
+            amount.get_decimals(),
+        )
+    }
+
+    /// Add an approved token transfer of `amount`, ensuring that the token has `amount`'s decimals.
+    pub fn approved_token_amount_transfer(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: TokenAmount,
+    ) -> &mut Self {
+        self.approved_token_transfer_with_decimals(
+            token_id,
+            account_id,
+            amount.get_value(),
+            amount.get_decimals(),
+        )
+    }
+
     fn _token_transfer(
         &mut self,
         token_id: TokenId,
@@ -0,0 +1,309 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::cmp::Ordering;
+use std::fmt::{
+    self,
+    Debug,
+    Display,
+    Formatter,
+};
+
+use crate::{
+    Error,
+    TokenInfo,
+};
+
+/// An amount of a fungible token, in the token's smallest unit, along with the token's decimals.
+///
+/// Keeping the decimals with the value means `12.345` of a token with 3 decimals is always `12345` units,
+/// and never silently becomes `12345` units of a token with 8 decimals.
+///
+/// Transfers take it with [`TransferTransaction::token_amount_transfer`](crate::TransferTransaction::token_amount_transfer)
+/// (and [`TokenAirdropTransaction::token_amount_transfer`](crate::TokenAirdropTransaction::token_amount_transfer)),
+/// which also set the token's expected decimals.
+///
+/// The decimals of a token are in its [`TokenInfo`],
+/// use [`check_decimals`](Self::check_decimals) to make sure an amount matches the token.
+///
+/// # Examples
+///
+/// ```
+/// use hiero_sdk::TokenAmount;
+///
+/// let amount = TokenAmount::parse("12.345", 8).unwrap();
+///
+/// assert_eq!(amount.get_value(), 1_234_500_000);
+/// assert_eq!(amount.to_string(), "12.34500000");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TokenAmount {
+    value: i64,
+    decimals: u32,
+}
+
+impl TokenAmount {
+    /// Create a new `TokenAmount` of `value` smallest units, for a token with `decimals` decimals.
+    #[must_use]
+    pub const fn new(value: i64, decimals: u32) -> Self {
+        Self { value, decimals }
+    }
+
+    /// Parse a decimal string such as `"12.345"` as an amount of a token with `decimals` decimals.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `amount` isn't a decimal number,
+    ///   has more decimal places than `decimals`, or doesn't fit in an `i64` of smallest units.
+    pub fn parse(amount: &str, decimals: u32) -> crate::Result<Self> {
+        let invalid = || Error::basic_parse(format!("invalid token amount `{amount}`"));
+        let overflow = || {
+            Error::basic_parse(format!(
+                "token amount `{amount}` with {decimals} decimals overflows"
+            ))
+        };
+
+        let (negative, digits) = match amount.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, amount.strip_prefix('+').unwrap_or(amount)),
+        };
+
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+        if (whole.is_empty() && fraction.is_empty())
+            || !whole.bytes().chain(fraction.bytes()).all(|it| it.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        // trailing zeros don't add any precision.
+        let fraction = fraction.trim_end_matches('0');
+
+        let scale = decimals
+            .checked_sub(fraction.len().try_into().map_err(|_| invalid())?)
+            .ok_or_else(|| {
+                Error::basic_parse(format!(
+                    "token amount `{amount}` has more than {decimals} decimal places"
+                ))
+            })?;
+
+        // `i64::MIN` has no positive counterpart, so the magnitude is built up in an `i128`.
+        let mut value: i128 = 0;
+
+        for digit in whole.bytes().chain(fraction.bytes()) {
+            value = value
+                .checked_mul(10)
+                .and_then(|it| it.checked_add(i128::from(digit - b'0')))
+                .ok_or_else(overflow)?;
+        }
+
+        if value != 0 {
+            value = 10_i128
+                .checked_pow(scale)
+                .and_then(|it| value.checked_mul(it))
+                .ok_or_else(overflow)?;
+        }
+
+        let value = i64::try_from(if negative { -value } else { value }).map_err(|_| overflow())?;
+
+        Ok(Self::new(value, decimals))
+    }
+
+    /// Returns the amount in the token's smallest unit.
+    #[must_use]
+    pub const fn get_value(self) -> i64 {
+        self.value
+    }
+
+    /// Returns the number of decimals of the token.
+    #[must_use]
+    pub const fn get_decimals(self) -> u32 {
+        self.decimals
+    }
+
+    /// Returns the same amount for a token with `decimals` decimals.
+    ///
+    /// Returns `None` if the amount doesn't fit in an `i64` of the new smallest unit,
+    /// or can't be represented exactly with fewer decimals.
+    #[must_use]
+    pub fn rescale(self, decimals: u32) -> Option<Self> {
+        let value = match decimals.cmp(&self.decimals) {
+            Ordering::Equal => self.value,
+            Ordering::Greater => {
+                self.value.checked_mul(10_i64.checked_pow(decimals - self.decimals)?)?
+            }
+            Ordering::Less => {
+                let divisor = 10_i64.checked_pow(self.decimals - decimals);
+
+                match divisor {
+                    Some(divisor) if self.value % divisor == 0 => self.value / divisor,
+                    // anything that isn't a multiple of a divisor larger than `i64::MAX` is only representable as zero.
+                    None if self.value == 0 => 0,
+                    _ => return None,
+                }
+            }
+        };
+
+        Some(Self::new(value, decimals))
+    }
+
+    /// Returns `self + rhs`, or `None` if the decimals differ or the sum overflows.
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        (self.decimals == rhs.decimals)
+            .then(|| self.value.checked_add(rhs.value))
+            .flatten()
+            .map(|value| Self::new(value, self.decimals))
+    }
+
+    /// Returns `self - rhs`, or `None` if the decimals differ or the difference overflows.
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        (self.decimals == rhs.decimals)
+            .then(|| self.value.checked_sub(rhs.value))
+            .flatten()
+            .map(|value| Self::new(value, self.decimals))
+    }
+
+    /// Returns `self * rhs`, or `None` if the product overflows.
+    #[must_use]
+    pub fn checked_mul(self, rhs: i64) -> Option<Self> {
+        self.value.checked_mul(rhs).map(|value| Self::new(value, self.decimals))
+    }
+
+    /// Returns `self / rhs` rounded towards zero, or `None` if `rhs` is zero or the quotient overflows.
+    #[must_use]
+    pub fn checked_div(self, rhs: i64) -> Option<Self> {
+        self.value.checked_div(rhs).map(|value| Self::new(value, self.decimals))
+    }
+
+    /// Returns `-self`, or `None` if the negation overflows.
+    #[must_use]
+    pub fn checked_neg(self) -> Option<Self> {
+        self.value.checked_neg().map(|value| Self::new(value, self.decimals))
+    }
+
+    /// Checks that this amount has the same decimals as the token described by `info`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the decimals differ.
+    pub fn check_decimals(self, info: &TokenInfo) -> crate::Result<()> {
+        if self.decimals != info.decimals {
+            return Err(Error::basic_parse(format!(
+                "token amount has {} decimals, but token `{}` has {} decimals",
+                self.decimals, info.token_id, info.decimals
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl PartialOrd for TokenAmount {
+    /// Amounts with different decimals aren't compared, since that's almost always a mistake.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.decimals == other.decimals).then(|| self.value.cmp(&other.value))
+    }
+}
+
+impl Display for TokenAmount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.value < 0 {
+            f.write_str("-")?;
+        }
+
+        let digits = self.value.unsigned_abs().to_string();
+        let decimals = self.decimals as usize;
+
+        if decimals == 0 {
+            return f.write_str(&digits);
+        }
+
+        let digits = format!("{digits:0>width$}", width = decimals + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - decimals);
+
+        write!(f, "{whole}.{fraction}")
+    }
+}
+
+impl Debug for TokenAmount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{self}\"")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TokenAmount;
+
+    #[test]
+    fn parse() {
+        assert_eq!(TokenAmount::parse("12.345", 3).unwrap(), TokenAmount::new(12_345, 3));
+        assert_eq!(TokenAmount::parse("12.345", 8).unwrap(), TokenAmount::new(1_234_500_000, 8));
+        assert_eq!(TokenAmount::parse("12.3450", 3).unwrap(), TokenAmount::new(12_345, 3));
+        assert_eq!(TokenAmount::parse("-0.5", 1).unwrap(), TokenAmount::new(-5, 1));
+        assert_eq!(TokenAmount::parse("+.5", 1).unwrap(), TokenAmount::new(5, 1));
+        assert_eq!(TokenAmount::parse("7", 0).unwrap(), TokenAmount::new(7, 0));
+        assert_eq!(TokenAmount::parse("0", 100).unwrap(), TokenAmount::new(0, 100));
+
+        assert!(TokenAmount::parse("12.345", 2).is_err());
+        assert!(TokenAmount::parse("", 2).is_err());
+        assert!(TokenAmount::parse(".", 2).is_err());
+        assert!(TokenAmount::parse("1e5", 2).is_err());
+        assert!(TokenAmount::parse("1.2.3", 2).is_err());
+        assert!(TokenAmount::parse("100000000000", 8).is_err());
+        assert!(TokenAmount::parse("1", 19).is_err());
+
+        assert_eq!(
+            TokenAmount::parse("-92233720368547758.08", 2).unwrap(),
+            TokenAmount::new(i64::MIN, 2)
+        );
+        assert_eq!(
+            TokenAmount::parse("92233720368547758.07", 2).unwrap(),
+            TokenAmount::new(i64::MAX, 2)
+        );
+        assert!(TokenAmount::parse("92233720368547758.08", 2).is_err());
+        assert!(TokenAmount::parse("-92233720368547758.09", 2).is_err());
+        assert!(TokenAmount::parse(&"9".repeat(40), 0).is_err());
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(TokenAmount::new(12_345, 3).to_string(), "12.345");
+        assert_eq!(TokenAmount::new(12_345, 8).to_string(), "0.00012345");
+        assert_eq!(TokenAmount::new(-5, 1).to_string(), "-0.5");
+        assert_eq!(TokenAmount::new(7, 0).to_string(), "7");
+        assert_eq!(TokenAmount::new(i64::MIN, 2).to_string(), "-92233720368547758.08");
+    }
+
+    #[test]
+    fn rescale() {
+        let amount = TokenAmount::new(12_345, 3);
+
+        assert_eq!(amount.rescale(8), Some(TokenAmount::new(1_234_500_000, 8)));
+        assert_eq!(amount.rescale(2), None);
+        assert_eq!(TokenAmount::new(12_340, 3).rescale(2), Some(TokenAmount::new(1234, 2)));
+        assert_eq!(amount.rescale(30), None);
+        assert_eq!(TokenAmount::new(0, 30).rescale(0), Some(TokenAmount::new(0, 0)));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let one = TokenAmount::new(100, 2);
+        let half = TokenAmount::new(50, 2);
+
+        assert_eq!(one.checked_add(half), Some(TokenAmount::new(150, 2)));
+        assert_eq!(one.checked_sub(half), Some(half));
+        assert_eq!(half.checked_mul(3), Some(TokenAmount::new(150, 2)));
+        assert_eq!(one.checked_div(3), Some(TokenAmount::new(33, 2)));
+        assert_eq!(one.checked_neg(), Some(TokenAmount::new(-100, 2)));
+        assert!(half < one);
+
+        // different decimals don't mix.
+        assert_eq!(one.checked_add(TokenAmount::new(1, 8)), None);
+        assert_eq!(one.partial_cmp(&TokenAmount::new(1, 8)), None);
+
+        assert_eq!(TokenAmount::new(i64::MAX, 2).checked_add(TokenAmount::new(1, 2)), None);
+        assert_eq!(TokenAmount::new(i64::MAX, 2).checked_mul(2), None);
+        assert_eq!(one.checked_div(0), None);
+        assert_eq!(TokenAmount::new(i64::MIN, 2).checked_neg(), None);
+    }
+}
@@ -0,0 +1,669 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use futures_util::StreamExt;
+use serde_json::Value;
+
+use super::{
+    TokenFreezeTransaction,
+    TokenGrantKycTransaction,
+    TokenId,
+    TokenInfoQuery,
+    TokenRevokeKycTransaction,
+    TokenType,
+    TokenUnfreezeTransaction,
+    TokenWipeTransaction,
+};
+use crate::mirror_rest::{
+    self,
+    DEFAULT_MAX_BACKOFF,
+};
+use crate::retry::execute_with_retries;
+use crate::signer::AnySigner;
+use crate::transaction::TransactionExecute;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    PrivateKey,
+    PublicKey,
+    Status,
+    Transaction,
+    TransactionId,
+    TransactionReceipt,
+};
+
+/// The default number of transactions in flight at once.
+const DEFAULT_MAX_CONCURRENCY: usize = 8;
+
+/// The default number of times each transaction (and mirror node request) is attempted before giving up.
+const DEFAULT_MAX_ATTEMPTS: usize = 3;
+
+/// A compliance action applied by a [`TokenComplianceFlow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TokenComplianceAction {
+    /// Grant KYC to the accounts, with [`TokenGrantKycTransaction`]s.
+    GrantKyc,
+
+    /// Revoke KYC from the accounts, with [`TokenRevokeKycTransaction`]s.
+    RevokeKyc,
+
+    /// Freeze the accounts, with [`TokenFreezeTransaction`]s.
+    Freeze,
+
+    /// Unfreeze the accounts, with [`TokenUnfreezeTransaction`]s.
+    Unfreeze,
+
+    /// Wipe the whole balance of a fungible token from the accounts, with [`TokenWipeTransaction`]s.
+    Wipe,
+}
+
+/// What happened to a single account in a [`TokenComplianceFlow`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TokenComplianceOutcome {
+    /// The action was applied, by the transaction with this receipt.
+    Applied(TransactionReceipt),
+
+    /// The account was already in the state the action would put it in, so nothing was submitted.
+    Skipped,
+
+    /// The account isn't associated with the token, so nothing was submitted.
+    NotAssociated,
+
+    /// The action failed, either before or after a transaction was submitted.
+    Failed(Error),
+}
+
+impl TokenComplianceOutcome {
+    /// Returns the status of the transaction submitted for the account, if one reached the network.
+    #[must_use]
+    pub fn status(&self) -> Option<Status> {
+        match self {
+            Self::Applied(receipt) => Some(receipt.status),
+            Self::Failed(
+                Error::ReceiptStatus { status, .. }
+                | Error::TransactionPreCheckStatus { status, .. },
+            ) => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the action failed for the account.
+    #[must_use]
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Self::Failed(_))
+    }
+}
+
+/// The outcome of a [`TokenComplianceFlow`] for a single account.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TokenComplianceEntry {
+    /// The account the action was applied to.
+    pub account_id: AccountId,
+
+    /// What happened to the account.
+    pub outcome: TokenComplianceOutcome,
+}
+
+/// The result of executing a [`TokenComplianceFlow`], with an entry for every account, in the order they were given.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TokenComplianceReport {
+    /// The token the action was applied for.
+    pub token_id: TokenId,
+
+    /// The action that was applied.
+    pub action: TokenComplianceAction,
+
+    /// The outcome for each account.
+    pub entries: Vec<TokenComplianceEntry>,
+}
+
+impl TokenComplianceReport {
+    /// Returns `true` if the action didn't fail for any account.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        !self.entries.iter().any(|it| it.outcome.is_failed())
+    }
+
+    /// Returns the accounts the action failed for, so they can be tried again.
+    pub fn failed_account_ids(&self) -> impl Iterator<Item = AccountId> + '_ {
+        self.entries.iter().filter(|it| it.outcome.is_failed()).map(|it| it.account_id)
+    }
+}
+
+/// Apply a compliance action (such as granting KYC or freezing) for a token to any number of accounts.
+///
+/// The operation of this flow is as follows:
+/// 1. Look up the relationship of each account with the token on the mirror node.
+/// 2. Skip the accounts that aren't associated with the token, or are already in the state the action would put them in.
+/// 3. Submit a transaction for each of the remaining accounts, up to [`max_concurrency`](Self::max_concurrency) at once.
+///
+/// Every transaction is given its own transaction ID up front and keeps it across retries,
+/// so an action that did reach consensus is never applied twice.
+///
+/// Since the mirror node trails consensus by a few seconds, an account changed just before the flow is executed
+/// may be skipped (or not) based on its previous state.
+/// Likewise, the amount wiped is the balance the mirror node reports,
+/// so a wipe fails if the balance went down since (and leaves the rest if it went up).
+///
+/// # Examples
+///
+/// ```no_run
+/// use hiero_sdk::{AccountId, Client, PrivateKey, TokenComplianceAction, TokenComplianceFlow, TokenId};
+///
+/// # async fn example(client: &Client, kyc_key: PrivateKey, accounts: Vec<AccountId>) -> hiero_sdk::Result<()> {
+/// let report = TokenComplianceFlow::new(TokenComplianceAction::GrantKyc)
+///     .token_id(TokenId::new(0, 0, 1234))
+///     .account_ids(accounts)
+///     .sign(kyc_key)
+///     .execute(client)
+///     .await?;
+///
+/// for entry in &report.entries {
+///     println!("{}: {:?}", entry.account_id, entry.outcome.status());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TokenComplianceFlow {
+    action: TokenComplianceAction,
+    token_id: Option<TokenId>,
+    account_ids: Vec<AccountId>,
+    max_concurrency: usize,
+    max_attempts: usize,
+    signers: Vec<AnySigner>,
+}
+
+/// The relationship of an account with a token, as reported by the mirror node.
+struct Relationship {
+    balance: u64,
+    kyc_status: String,
+    freeze_status: String,
+}
+
+impl TokenComplianceFlow {
+    /// Create a new `TokenComplianceFlow` that applies `action`.
+    #[must_use]
+    pub fn new(action: TokenComplianceAction) -> Self {
+        Self {
+            action,
+            token_id: None,
+            account_ids: Vec::new(),
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            signers: Vec::new(),
+        }
+    }
+
+    /// Returns the action to apply.
+    #[must_use]
+    pub fn get_action(&self) -> TokenComplianceAction {
+        self.action
+    }
+
+    /// Returns the token to apply the action for.
+    #[must_use]
+    pub fn get_token_id(&self) -> Option<TokenId> {
+        self.token_id
+    }
+
+    /// Sets the token to apply the action for.
+    pub fn token_id(&mut self, token_id: impl Into<TokenId>) -> &mut Self {
+        self.token_id = Some(token_id.into());
+
+        self
+    }
+
+    /// Returns the accounts to apply the action to.
+    #[must_use]
+    pub fn get_account_ids(&self) -> &[AccountId] {
+        &self.account_ids
+    }
+
+    /// Sets the accounts to apply the action to.
+    ///
+    /// Repeated accounts are only acted on (and reported) once.
+    pub fn account_ids(&mut self, account_ids: impl IntoIterator<Item = AccountId>) -> &mut Self {
+        self.account_ids = account_ids.into_iter().collect();
+
+        self
+    }
+
+    /// Adds an account to apply the action to.
+    pub fn add_account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.account_ids.push(account_id);
+
+        self
+    }
+
+    /// Returns the most transactions in flight at once.
+    #[must_use]
+    pub fn get_max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+
+    /// Sets the most transactions in flight at once.
+    ///
+    /// Defaults to 8. A value of 0 is treated as 1.
+    pub fn max_concurrency(&mut self, max_concurrency: usize) -> &mut Self {
+        self.max_concurrency = max_concurrency.max(1);
+
+        self
+    }
+
+    /// Returns the number of times each transaction is submitted before giving up.
+    #[must_use]
+    pub fn get_max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    /// Sets the number of times each transaction is submitted before giving up.
+    ///
+    /// Only failures that leave it unknown whether the action was applied (such as timeouts) are retried.
+    ///
+    /// Defaults to 3.
+    pub fn max_attempts(&mut self, max_attempts: usize) -> &mut Self {
+        self.max_attempts = max_attempts;
+
+        self
+    }
+
+    /// Adds a key to sign every transaction with, such as the token's KYC, freeze or wipe key.
+    pub fn sign(&mut self, key: PrivateKey) -> &mut Self {
+        self.signers.push(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Adds a signer to sign every transaction with, such as the signer of the token's KYC, freeze or wipe key.
+    pub fn sign_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.signers.push(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Applies the action to every account, returning what happened to each one.
+    ///
+    /// A failure for one account doesn't stop the action from being applied to the rest.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the token ID is unset, or `client` has no operator to pay for the transactions.
+    /// - [`Error::BasicParse`] if the action is [`Wipe`](TokenComplianceAction::Wipe) and the token is an NFT.
+    /// - If querying the token's info (to wipe it) fails.
+    pub async fn execute(&self, client: &Client) -> crate::Result<TokenComplianceReport> {
+        let token_id = self.token_id.ok_or_else(|| {
+            Error::basic_parse("token ID must be set to execute a `TokenComplianceFlow`")
+        })?;
+
+        let payer_id = client.get_operator_account_id().ok_or_else(|| {
+            Error::basic_parse("client must have an operator to execute a `TokenComplianceFlow`")
+        })?;
+
+        if self.action == TokenComplianceAction::Wipe {
+            let info = TokenInfoQuery::new().token_id(token_id).execute(client).await?;
+
+            check_wipeable(token_id, info.token_type)?;
+        }
+
+        let entries = self.unique_account_ids().enumerate().map(|(index, account_id)| async move {
+            let outcome = self.apply(client, token_id, payer_id, account_id).await;
+
+            (index, TokenComplianceEntry { account_id, outcome })
+        });
+
+        let mut entries: Vec<_> = futures_util::stream::iter(entries)
+            .buffer_unordered(self.max_concurrency)
+            .collect()
+            .await;
+
+        entries.sort_by_key(|(index, _)| *index);
+
+        Ok(TokenComplianceReport {
+            token_id,
+            action: self.action,
+            entries: entries.into_iter().map(|(_, entry)| entry).collect(),
+        })
+    }
+
+    /// Returns the accounts to apply the action to, without repeats.
+    fn unique_account_ids(&self) -> impl Iterator<Item = AccountId> + '_ {
+        let mut seen = HashSet::new();
+
+        self.account_ids.iter().copied().filter(move |it| seen.insert(*it))
+    }
+
+    /// Applies the action to a single account.
+    async fn apply(
+        &self,
+        client: &Client,
+        token_id: TokenId,
+        payer_id: AccountId,
+        account_id: AccountId,
+    ) -> TokenComplianceOutcome {
+        let relationship = match self.relationship(client, token_id, account_id).await {
+            Ok(Some(relationship)) => relationship,
+            Ok(None) => return TokenComplianceOutcome::NotAssociated,
+            Err(error) => return TokenComplianceOutcome::Failed(error),
+        };
+
+        let transaction_id = TransactionId::generate(payer_id);
+
+        let result = match step(self.action, token_id, &relationship) {
+            Step::Done(outcome) => return outcome,
+
+            Step::GrantKyc => {
+                let mut tx = TokenGrantKycTransaction::new();
+                tx.token_id(token_id).account_id(account_id);
+                self.execute_transaction(client, tx, transaction_id).await
+            }
+
+            Step::RevokeKyc => {
+                let mut tx = TokenRevokeKycTransaction::new();
+                tx.token_id(token_id).account_id(account_id);
+                self.execute_transaction(client, tx, transaction_id).await
+            }
+
+            Step::Freeze => {
+                let mut tx = TokenFreezeTransaction::new();
+                tx.token_id(token_id).account_id(account_id);
+                self.execute_transaction(client, tx, transaction_id).await
+            }
+
+            Step::Unfreeze => {
+                let mut tx = TokenUnfreezeTransaction::new();
+                tx.token_id(token_id).account_id(account_id);
+                self.execute_transaction(client, tx, transaction_id).await
+            }
+
+            Step::Wipe { amount } => {
+                let mut tx = TokenWipeTransaction::new();
+                tx.token_id(token_id).account_id(account_id).amount(amount);
+                self.execute_transaction(client, tx, transaction_id).await
+            }
+        };
+
+        match result {
+            Ok(receipt) => TokenComplianceOutcome::Applied(receipt),
+            Err(error) => TokenComplianceOutcome::Failed(error),
+        }
+    }
+
+    /// Returns the relationship of `account_id` with `token_id`, or `None` if they aren't associated.
+    async fn relationship(
+        &self,
+        client: &Client,
+        token_id: TokenId,
+        account_id: AccountId,
+    ) -> crate::Result<Option<Relationship>> {
+        let tokens = mirror_rest::get_all(
+            &mirror_rest::base_url(client),
+            &format!("/accounts/{account_id}/tokens?token.id={token_id}"),
+            "tokens",
+            Some(1),
+            self.max_attempts,
+            DEFAULT_MAX_BACKOFF,
+        )
+        .await?;
+
+        tokens.first().map(parse_relationship).transpose()
+    }
+
+    async fn execute_transaction<D: TransactionExecute>(
+        &self,
+        client: &Client,
+        mut tx: Transaction<D>,
+        transaction_id: TransactionId,
+    ) -> crate::Result<TransactionReceipt> {
+        tx.transaction_id(transaction_id).freeze_with(client)?;
+
+        for signer in &self.signers {
+            tx.sign_signer(signer.clone());
+        }
+
+        execute_with_retries(client, &mut tx, transaction_id, self.max_attempts).await
+    }
+}
+
+/// What the flow does for a single account.
+#[derive(Debug)]
+enum Step {
+    GrantKyc,
+    RevokeKyc,
+    Freeze,
+    Unfreeze,
+    Wipe {
+        amount: u64,
+    },
+
+    /// Nothing is submitted for the account, and this is its outcome.
+    Done(TokenComplianceOutcome),
+}
+
+/// Decides what to do for an account, given its relationship with the token.
+fn step(action: TokenComplianceAction, token_id: TokenId, relationship: &Relationship) -> Step {
+    // the state the action puts the account in, the state it changes, and the transaction that changes it.
+    let (status, key, [applied, unapplied], step) = match action {
+        TokenComplianceAction::GrantKyc => {
+            (&relationship.kyc_status, "KYC", ["GRANTED", "REVOKED"], Step::GrantKyc)
+        }
+        TokenComplianceAction::RevokeKyc => {
+            (&relationship.kyc_status, "KYC", ["REVOKED", "GRANTED"], Step::RevokeKyc)
+        }
+        TokenComplianceAction::Freeze => {
+            (&relationship.freeze_status, "freeze", ["FROZEN", "UNFROZEN"], Step::Freeze)
+        }
+        TokenComplianceAction::Unfreeze => {
+            (&relationship.freeze_status, "freeze", ["UNFROZEN", "FROZEN"], Step::Unfreeze)
+        }
+        TokenComplianceAction::Wipe => {
+            return match relationship.balance {
+                0 => Step::Done(TokenComplianceOutcome::Skipped),
+                amount => Step::Wipe { amount },
+            };
+        }
+    };
+
+    if status == applied {
+        Step::Done(TokenComplianceOutcome::Skipped)
+    } else if status == unapplied {
+        step
+    } else {
+        // `NOT_APPLICABLE`
+        Step::Done(no_key_outcome(token_id, key))
+    }
+}
+
+/// Returns an error if a token of `token_type` can't be wiped by the flow.
+fn check_wipeable(token_id: TokenId, token_type: TokenType) -> crate::Result<()> {
+    match token_type {
+        TokenType::FungibleCommon => Ok(()),
+        TokenType::NonFungibleUnique => Err(Error::basic_parse(format!(
+            "token {token_id} is an NFT, `TokenComplianceFlow` can only wipe fungible tokens"
+        ))),
+    }
+}
+
+/// The outcome for an account when the token lacks the key the action needs.
+fn no_key_outcome(token_id: TokenId, key: &str) -> TokenComplianceOutcome {
+    TokenComplianceOutcome::Failed(Error::basic_parse(format!("token {token_id} has no {key} key")))
+}
+
+// `{"automatic_association": false, "balance": 5, "freeze_status": "UNFROZEN", "kyc_status": "GRANTED", "token_id": "0.0.4", ...}`
+fn parse_relationship(value: &Value) -> crate::Result<Relationship> {
+    let field = |key: &str| {
+        value.get(key).and_then(Value::as_str).map(str::to_owned).ok_or_else(|| {
+            Error::basic_parse(format!("mirror node token relationship is missing `{key}`"))
+        })
+    };
+
+    Ok(Relationship {
+        balance: value.get("balance").and_then(Value::as_u64).ok_or_else(|| {
+            Error::basic_parse("mirror node token relationship is missing `balance`")
+        })?,
+        kyc_status: field("kyc_status")?,
+        freeze_status: field("freeze_status")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{
+        check_wipeable,
+        parse_relationship,
+        step,
+        Relationship,
+        Step,
+    };
+    use crate::{
+        AccountId,
+        Error,
+        TokenComplianceAction,
+        TokenComplianceEntry,
+        TokenComplianceFlow,
+        TokenComplianceOutcome,
+        TokenComplianceReport,
+        TokenId,
+        TokenType,
+    };
+
+    const TOKEN: TokenId = TokenId::new(0, 0, 4);
+
+    fn relationship(kyc_status: &str, freeze_status: &str) -> Relationship {
+        Relationship {
+            balance: 0,
+            kyc_status: kyc_status.to_owned(),
+            freeze_status: freeze_status.to_owned(),
+        }
+    }
+
+    /// Returns `"submit"`, `"skip"` or `"fail"` for `action` on an account with `relationship`.
+    fn decide(action: TokenComplianceAction, relationship: &Relationship) -> &'static str {
+        match step(action, TOKEN, relationship) {
+            Step::Done(TokenComplianceOutcome::Skipped) => "skip",
+            Step::Done(TokenComplianceOutcome::Failed(_)) => "fail",
+            Step::Done(outcome) => panic!("unexpected outcome {outcome:?}"),
+            _ => "submit",
+        }
+    }
+
+    #[test]
+    fn kyc() {
+        let granted = relationship("GRANTED", "NOT_APPLICABLE");
+        let revoked = relationship("REVOKED", "NOT_APPLICABLE");
+        let no_key = relationship("NOT_APPLICABLE", "UNFROZEN");
+
+        assert_eq!(decide(TokenComplianceAction::GrantKyc, &granted), "skip");
+        assert_eq!(decide(TokenComplianceAction::GrantKyc, &revoked), "submit");
+        assert_eq!(decide(TokenComplianceAction::GrantKyc, &no_key), "fail");
+
+        assert_eq!(decide(TokenComplianceAction::RevokeKyc, &granted), "submit");
+        assert_eq!(decide(TokenComplianceAction::RevokeKyc, &revoked), "skip");
+        assert_eq!(decide(TokenComplianceAction::RevokeKyc, &no_key), "fail");
+    }
+
+    #[test]
+    fn freeze() {
+        let frozen = relationship("NOT_APPLICABLE", "FROZEN");
+        let unfrozen = relationship("NOT_APPLICABLE", "UNFROZEN");
+        let no_key = relationship("GRANTED", "NOT_APPLICABLE");
+
+        assert_eq!(decide(TokenComplianceAction::Freeze, &frozen), "skip");
+        assert_eq!(decide(TokenComplianceAction::Freeze, &unfrozen), "submit");
+        assert_eq!(decide(TokenComplianceAction::Freeze, &no_key), "fail");
+
+        assert_eq!(decide(TokenComplianceAction::Unfreeze, &frozen), "submit");
+        assert_eq!(decide(TokenComplianceAction::Unfreeze, &unfrozen), "skip");
+        assert_eq!(decide(TokenComplianceAction::Unfreeze, &no_key), "fail");
+    }
+
+    #[test]
+    fn wipe() {
+        let mut relationship = relationship("NOT_APPLICABLE", "NOT_APPLICABLE");
+
+        assert_eq!(decide(TokenComplianceAction::Wipe, &relationship), "skip");
+
+        // the whole balance the mirror node reports is wiped.
+        relationship.balance = 25;
+
+        assert!(matches!(
+            step(TokenComplianceAction::Wipe, TOKEN, &relationship),
+            Step::Wipe { amount: 25 }
+        ));
+
+        assert!(check_wipeable(TOKEN, TokenType::FungibleCommon).is_ok());
+        assert!(check_wipeable(TOKEN, TokenType::NonFungibleUnique).is_err());
+    }
+
+    #[test]
+    fn unique_account_ids() {
+        let (alice, bob) = (AccountId::new(0, 0, 5), AccountId::new(0, 0, 6));
+
+        let mut flow = TokenComplianceFlow::new(TokenComplianceAction::Freeze);
+        flow.account_ids([bob, alice, bob]).add_account_id(alice);
+
+        assert_eq!(flow.unique_account_ids().collect::<Vec<_>>(), [bob, alice]);
+    }
+
+    #[test]
+    fn report() {
+        let (alice, bob, carol) =
+            (AccountId::new(0, 0, 5), AccountId::new(0, 0, 6), AccountId::new(0, 0, 7));
+
+        let mut report = TokenComplianceReport {
+            token_id: TOKEN,
+            action: TokenComplianceAction::Freeze,
+            entries: vec![
+                TokenComplianceEntry {
+                    account_id: alice,
+                    outcome: TokenComplianceOutcome::Skipped,
+                },
+                TokenComplianceEntry {
+                    account_id: bob,
+                    outcome: TokenComplianceOutcome::NotAssociated,
+                },
+            ],
+        };
+
+        assert!(report.is_success());
+
+        report.entries.push(TokenComplianceEntry {
+            account_id: carol,
+            outcome: TokenComplianceOutcome::Failed(Error::basic_parse("failed")),
+        });
+
+        assert!(!report.is_success());
+        assert_eq!(report.failed_account_ids().collect::<Vec<_>>(), [carol]);
+    }
+
+    #[test]
+    fn parse() {
+        let relationship = parse_relationship(&json!({
+            "automatic_association": false,
+            "balance": 5,
+            "created_timestamp": "1651560386.661997287",
+            "decimals": 2,
+            "freeze_status": "UNFROZEN",
+            "kyc_status": "GRANTED",
+            "token_id": "0.0.4"
+        }))
+        .unwrap();
+
+        assert_eq!(relationship.balance, 5);
+        assert_eq!(relationship.kyc_status, "GRANTED");
+        assert_eq!(relationship.freeze_status, "UNFROZEN");
+
+        assert!(parse_relationship(&json!({ "balance": 5, "token_id": "0.0.4" })).is_err());
+    }
+}
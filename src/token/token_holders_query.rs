@@ -0,0 +1,278 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use futures_core::stream::BoxStream;
+use futures_util::{
+    StreamExt,
+    TryStreamExt,
+};
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use super::TokenId;
+use crate::mirror_rest::{
+    self,
+    DEFAULT_MAX_ATTEMPTS,
+    DEFAULT_MAX_BACKOFF,
+};
+use crate::{
+    AccountId,
+    Client,
+    Error,
+};
+
+/// The most balances the mirror node returns in a single page.
+const PAGE_SIZE: usize = 100;
+
+/// An account's balance of a token, as listed by a [`TokenHoldersQuery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TokenHolder {
+    /// The account holding the token.
+    pub account_id: AccountId,
+
+    /// The account's balance of the token.
+    ///
+    /// In the lowest denomination for a fungible token, or the number of NFTs held for a non-fungible token.
+    pub balance: u64,
+}
+
+/// Lists the holders of a token and their balances, using the mirror node.
+///
+/// # Examples
+/// ```no_run
+/// # async fn example() -> hiero_sdk::Result<()> {
+/// use hiero_sdk::{Client, TokenHoldersQuery, TokenId};
+///
+/// let client = Client::for_testnet();
+///
+/// let holders = TokenHoldersQuery::new()
+///     .token_id(TokenId::new(0, 0, 1234))
+///     .execute(&client)
+///     .await?;
+///
+/// for holder in holders {
+///     println!("{} holds {}", holder.account_id, holder.balance);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TokenHoldersQuery {
+    token_id: Option<TokenId>,
+    timestamp: Option<OffsetDateTime>,
+    include_zero_balances: bool,
+    limit: Option<usize>,
+    max_attempts: usize,
+    max_backoff: Duration,
+}
+
+impl Default for TokenHoldersQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TokenHoldersQuery {
+    /// Creates a new `TokenHoldersQuery`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            token_id: None,
+            timestamp: None,
+            include_zero_balances: false,
+            limit: None,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+
+    /// Returns the token to list the holders of.
+    #[must_use]
+    pub fn get_token_id(&self) -> Option<TokenId> {
+        self.token_id
+    }
+
+    /// Sets the token to list the holders of.
+    pub fn token_id(&mut self, token_id: TokenId) -> &mut Self {
+        self.token_id = Some(token_id);
+        self
+    }
+
+    /// Returns the time to list the balances at.
+    #[must_use]
+    pub fn get_timestamp(&self) -> Option<OffsetDateTime> {
+        self.timestamp
+    }
+
+    /// Sets the time to list the balances at, by default the latest balances are listed.
+    ///
+    /// The mirror node takes balance snapshots periodically,
+    /// so the balances are those of the last snapshot at or before `timestamp`.
+    pub fn timestamp(&mut self, timestamp: OffsetDateTime) -> &mut Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Returns whether accounts associated with the token that hold none of it are listed.
+    #[must_use]
+    pub fn get_include_zero_balances(&self) -> bool {
+        self.include_zero_balances
+    }
+
+    /// Sets whether accounts associated with the token that hold none of it are listed.
+    ///
+    /// Defaults to `false`.
+    pub fn include_zero_balances(&mut self, include_zero_balances: bool) -> &mut Self {
+        self.include_zero_balances = include_zero_balances;
+        self
+    }
+
+    /// Returns the most holders to list.
+    #[must_use]
+    pub fn get_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Sets the most holders to list, by default every holder is listed.
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the maximum number of retry attempts.
+    #[must_use]
+    pub fn get_max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    /// Sets the maximum number of retry attempts.
+    pub fn max_attempts(&mut self, max_attempts: usize) -> &mut Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Returns the maximum backoff duration between retries.
+    #[must_use]
+    pub fn get_max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// Sets the maximum backoff duration between retries.
+    pub fn max_backoff(&mut self, max_backoff: Duration) -> &mut Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Lists the holders.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the token isn't set.
+    /// - [`Error::BasicParse`] if the mirror node returns an error, or a response that can't be parsed.
+    pub async fn execute(&self, client: &Client) -> crate::Result<Vec<TokenHolder>> {
+        self.subscribe(client).try_collect().await
+    }
+
+    /// Streams the holders, fetching each page from the mirror node once it's needed.
+    ///
+    /// The stream ends after the first error, see [`execute`](Self::execute) for the errors.
+    pub fn subscribe<'a>(&self, client: &'a Client) -> BoxStream<'a, crate::Result<TokenHolder>> {
+        let path = match self.path() {
+            Ok(path) => path,
+            Err(error) => return Box::pin(futures_util::stream::once(async { Err(error) })),
+        };
+
+        let balances = mirror_rest::stream_all(
+            mirror_rest::base_url(client),
+            &path,
+            "balances",
+            self.limit,
+            self.max_attempts,
+            self.max_backoff,
+        );
+
+        Box::pin(balances.map(|it| it.and_then(|balance| parse_holder(&balance))))
+    }
+
+    fn path(&self) -> crate::Result<String> {
+        let token_id = self
+            .token_id
+            .ok_or_else(|| Error::basic_parse("token must be set on TokenHoldersQuery"))?;
+
+        let page_size = self.limit.map_or(PAGE_SIZE, |it| it.clamp(1, PAGE_SIZE));
+
+        let mut path = format!("/tokens/{token_id}/balances?limit={page_size}");
+
+        if !self.include_zero_balances {
+            path.push_str("&account.balance=gt:0");
+        }
+
+        if let Some(timestamp) = self.timestamp {
+            path.push_str(&format!("&timestamp=lte:{}", mirror_rest::format_timestamp(timestamp)));
+        }
+
+        Ok(path)
+    }
+}
+
+// `{"account": "0.0.2", "balance": 1000, "decimals": 2}`
+fn parse_holder(value: &Value) -> crate::Result<TokenHolder> {
+    let account_id = value
+        .get("account")
+        .and_then(Value::as_str)
+        .ok_or_else(|| Error::basic_parse("mirror node balance is missing `account`"))?
+        .parse()?;
+
+    let balance = value
+        .get("balance")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| Error::basic_parse("mirror node balance is missing `balance`"))?;
+
+    Ok(TokenHolder { account_id, balance })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use time::OffsetDateTime;
+
+    use super::parse_holder;
+    use crate::{
+        AccountId,
+        TokenHolder,
+        TokenHoldersQuery,
+        TokenId,
+    };
+
+    #[test]
+    fn path() {
+        let mut query = TokenHoldersQuery::new();
+
+        assert!(query.path().is_err());
+
+        query.token_id(TokenId::new(0, 0, 4));
+
+        assert_eq!(query.path().unwrap(), "/tokens/0.0.4/balances?limit=100&account.balance=gt:0");
+
+        query
+            .include_zero_balances(true)
+            .timestamp(OffsetDateTime::from_unix_timestamp(1651560386).unwrap())
+            .limit(10);
+
+        assert_eq!(
+            query.path().unwrap(),
+            "/tokens/0.0.4/balances?limit=10&timestamp=lte:1651560386.000000000"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            parse_holder(&json!({ "account": "0.0.2", "balance": 1000, "decimals": 2 })).unwrap(),
+            TokenHolder { account_id: AccountId::new(0, 0, 2), balance: 1000 }
+        );
+
+        assert!(parse_holder(&json!({ "balance": 1000 })).is_err());
+    }
+}
@@ -0,0 +1,391 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use futures_util::StreamExt;
+
+use super::{
+    TokenId,
+    TokenMintTransaction,
+};
+use crate::retry::execute_with_retries;
+use crate::signer::AnySigner;
+#[cfg(feature = "serde")]
+use crate::OnLedgerNftMetadata;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    PrivateKey,
+    PublicKey,
+    TransactionId,
+};
+
+/// The default number of metadata entries the network allows in a single [`TokenMintTransaction`].
+const DEFAULT_MAX_METADATA_PER_TRANSACTION: usize = 10;
+
+/// The default number of [`TokenMintTransaction`]s in flight at once.
+const DEFAULT_MAX_CONCURRENCY: usize = 4;
+
+/// The default number of times each [`TokenMintTransaction`] is submitted before giving up.
+const DEFAULT_MAX_ATTEMPTS: usize = 3;
+
+/// Mint any number of NFTs, in as many [`TokenMintTransaction`]s as it takes.
+///
+/// The operation of this flow is as follows:
+/// 1. Split the metadata into [`TokenMintTransaction`]s of at most
+///    [`max_metadata_per_transaction`](Self::max_metadata_per_transaction) entries each.
+/// 2. Execute up to [`max_concurrency`](Self::max_concurrency) of them at once.
+/// 3. Collect the serial number minted for each metadata entry from the receipts.
+///
+/// Every transaction is given its own transaction ID up front and keeps it across retries,
+/// so a transaction that did reach consensus is never minted twice.
+///
+/// # Examples
+///
+/// ```no_run
+/// use hiero_sdk::{Client, PrivateKey, TokenId, TokenMintFlow};
+///
+/// # async fn example(client: &Client, supply_key: PrivateKey, uris: Vec<String>) -> hiero_sdk::Result<()> {
+/// let minted = TokenMintFlow::new()
+///     .token_id(TokenId::new(0, 0, 1234))
+///     .metadata(uris)
+///     .sign(supply_key)
+///     .execute(client)
+///     .await?;
+///
+/// for (index, serial) in &minted.serials {
+///     println!("metadata {index} was minted as serial {serial}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TokenMintFlow {
+    token_id: Option<TokenId>,
+    metadata: Vec<Vec<u8>>,
+    max_metadata_per_transaction: usize,
+    max_concurrency: usize,
+    max_attempts: usize,
+    node_account_ids: Option<Vec<AccountId>>,
+    signers: Vec<AnySigner>,
+}
+
+/// The result of executing a [`TokenMintFlow`].
+///
+/// Indices are positions in [`TokenMintFlow::get_metadata`],
+/// so the metadata that failed to mint can be minted again on its own.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct TokenMintSerials {
+    /// The serial number minted for each metadata entry, by index.
+    pub serials: BTreeMap<usize, i64>,
+
+    /// The errors of the transactions that failed, along with the indices of the metadata they were minting.
+    pub errors: Vec<(Vec<usize>, Error)>,
+}
+
+impl TokenMintSerials {
+    /// Returns `true` if every metadata entry was minted.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    // adds the result of the transaction minting the metadata at `indices`.
+    fn add(&mut self, indices: Range<usize>, result: crate::Result<Vec<i64>>) {
+        match result {
+            Ok(serials) if serials.len() == indices.len() => {
+                self.serials.extend(indices.zip(serials));
+            }
+
+            Ok(serials) => {
+                let error = Error::basic_parse(format!(
+                    "receipt has {} serial numbers for {} metadata entries",
+                    serials.len(),
+                    indices.len()
+                ));
+
+                self.errors.push((indices.collect(), error));
+            }
+
+            Err(error) => self.errors.push((indices.collect(), error)),
+        }
+    }
+}
+
+impl Default for TokenMintFlow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TokenMintFlow {
+    /// Create a new `TokenMintFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            token_id: None,
+            metadata: Vec::new(),
+            max_metadata_per_transaction: DEFAULT_MAX_METADATA_PER_TRANSACTION,
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            node_account_ids: None,
+            signers: Vec::new(),
+        }
+    }
+
+    /// Returns the token for which to mint NFTs.
+    #[must_use]
+    pub fn get_token_id(&self) -> Option<TokenId> {
+        self.token_id
+    }
+
+    /// Sets the token for which to mint NFTs.
+    pub fn token_id(&mut self, token_id: impl Into<TokenId>) -> &mut Self {
+        self.token_id = Some(token_id.into());
+
+        self
+    }
+
+    /// Returns the metadata of the NFTs to mint, one entry per NFT.
+    #[must_use]
+    pub fn get_metadata(&self) -> &[Vec<u8>] {
+        &self.metadata
+    }
+
+    /// Sets the metadata of the NFTs to mint, one entry per NFT.
+    pub fn metadata<Bytes>(&mut self, metadata: impl IntoIterator<Item = Bytes>) -> &mut Self
+    where
+        Bytes: AsRef<[u8]>,
+    {
+        self.metadata = metadata.into_iter().map(|bytes| bytes.as_ref().to_vec()).collect();
+
+        self
+    }
+
+    /// Adds the metadata of another NFT to mint.
+    pub fn add_metadata(&mut self, metadata: impl AsRef<[u8]>) -> &mut Self {
+        self.metadata.push(metadata.as_ref().to_vec());
+
+        self
+    }
+
+    /// Sets the metadata of the NFTs to mint, from HIP-412 metadata or URIs pointing to it.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if any of the metadata is invalid, or too large to fit on ledger.
+    #[cfg(feature = "serde")]
+    pub fn nft_metadata(
+        &mut self,
+        metadata: impl IntoIterator<Item = OnLedgerNftMetadata>,
+    ) -> crate::Result<&mut Self> {
+        let metadata =
+            metadata.into_iter().map(|it| it.to_bytes()).collect::<crate::Result<Vec<_>>>()?;
+
+        Ok(self.metadata(metadata))
+    }
+
+    /// Returns the most metadata entries minted by a single transaction.
+    #[must_use]
+    pub fn get_max_metadata_per_transaction(&self) -> usize {
+        self.max_metadata_per_transaction
+    }
+
+    /// Sets the most metadata entries minted by a single transaction.
+    ///
+    /// Defaults to 10, the network's default limit. A value of 0 is treated as 1.
+    pub fn max_metadata_per_transaction(
+        &mut self,
+        max_metadata_per_transaction: usize,
+    ) -> &mut Self {
+        self.max_metadata_per_transaction = max_metadata_per_transaction.max(1);
+
+        self
+    }
+
+    /// Returns the most transactions in flight at once.
+    #[must_use]
+    pub fn get_max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+
+    /// Sets the most transactions in flight at once.
+    ///
+    /// Defaults to 4. A value of 0 is treated as 1.
+    pub fn max_concurrency(&mut self, max_concurrency: usize) -> &mut Self {
+        self.max_concurrency = max_concurrency.max(1);
+
+        self
+    }
+
+    /// Returns the number of times each transaction is submitted before giving up.
+    #[must_use]
+    pub fn get_max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    /// Sets the number of times each transaction is submitted before giving up.
+    ///
+    /// Only failures that leave it unknown whether the NFTs were minted (such as timeouts) are retried.
+    ///
+    /// Defaults to 3.
+    pub fn max_attempts(&mut self, max_attempts: usize) -> &mut Self {
+        self.max_attempts = max_attempts;
+
+        self
+    }
+
+    /// Returns the account IDs of the nodes the transactions may be submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the account IDs of the nodes the transactions may be submitted to.
+    pub fn node_account_ids(
+        &mut self,
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> &mut Self {
+        self.node_account_ids = Some(node_account_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Adds a key to sign every transaction with, such as the token's supply key.
+    pub fn sign(&mut self, key: PrivateKey) -> &mut Self {
+        self.signers.push(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Adds a signer to sign every transaction with, such as the signer of the token's supply key.
+    pub fn sign_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.signers.push(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Generates the required transactions and executes them all, returning the serial number minted for each metadata entry.
+    ///
+    /// A failed transaction doesn't stop the rest of the metadata from being minted.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the token ID is unset, or `client` has no operator to pay for the transactions.
+    pub async fn execute(&self, client: &Client) -> crate::Result<TokenMintSerials> {
+        let token_id = self.token_id.ok_or_else(|| {
+            Error::basic_parse("token ID must be set to execute a `TokenMintFlow`")
+        })?;
+
+        let payer_id = client.get_operator_account_id().ok_or_else(|| {
+            Error::basic_parse("client must have an operator to execute a `TokenMintFlow`")
+        })?;
+
+        let chunks = self.chunks().map(|indices| async move {
+            let result =
+                self.mint(client, token_id, payer_id, &self.metadata[indices.clone()]).await;
+
+            (indices, result)
+        });
+
+        let mut results = futures_util::stream::iter(chunks).buffer_unordered(self.max_concurrency);
+        let mut minted = TokenMintSerials::default();
+
+        while let Some((indices, result)) = results.next().await {
+            minted.add(indices, result);
+        }
+
+        minted.errors.sort_by_key(|(indices, _)| indices.first().copied());
+
+        Ok(minted)
+    }
+
+    /// The indices of the metadata minted by each transaction.
+    fn chunks(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        let len = self.metadata.len();
+
+        (0..len)
+            .step_by(self.max_metadata_per_transaction)
+            .map(move |start| start..len.min(start + self.max_metadata_per_transaction))
+    }
+
+    /// Mints `metadata` in a single transaction, returning the minted serial numbers.
+    async fn mint(
+        &self,
+        client: &Client,
+        token_id: TokenId,
+        payer_id: AccountId,
+        metadata: &[Vec<u8>],
+    ) -> crate::Result<Vec<i64>> {
+        let transaction_id = TransactionId::generate(payer_id);
+
+        let mut tx = TokenMintTransaction::new();
+
+        tx.token_id(token_id).metadata(metadata).transaction_id(transaction_id);
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            tx.node_account_ids(node_account_ids.clone());
+        }
+
+        tx.freeze_with(client)?;
+
+        for signer in &self.signers {
+            tx.sign_signer(signer.clone());
+        }
+
+        execute_with_retries(client, &mut tx, transaction_id, self.max_attempts)
+            .await
+            .map(|receipt| receipt.serials)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        TokenMintFlow,
+        TokenMintSerials,
+    };
+    use crate::Error;
+
+    #[test]
+    fn chunks() {
+        let mut flow = TokenMintFlow::new();
+        flow.metadata((0..23_u8).map(|it| [it]));
+
+        assert_eq!(flow.chunks().collect::<Vec<_>>(), [0..10, 10..20, 20..23]);
+
+        flow.max_metadata_per_transaction(23);
+        assert_eq!(flow.chunks().collect::<Vec<_>>(), [0..23]);
+
+        flow.max_metadata_per_transaction(1);
+        assert_eq!(flow.chunks().count(), 23);
+
+        assert_eq!(TokenMintFlow::new().chunks().count(), 0);
+    }
+
+    #[test]
+    fn serials_in_input_order() {
+        let mut minted = TokenMintSerials::default();
+
+        // transactions finish in any order.
+        minted.add(10..13, Ok(vec![21, 22, 23]));
+        minted.add(3..6, Err(Error::basic_parse("failed")));
+        minted.add(0..3, Ok(vec![11, 12, 13]));
+        minted.add(6..10, Ok(vec![31, 32, 33]));
+
+        assert_eq!(
+            minted.serials.into_iter().collect::<Vec<_>>(),
+            [(0, 11), (1, 12), (2, 13), (10, 21), (11, 22), (12, 23)]
+        );
+
+        let indices: Vec<_> = minted.errors.iter().map(|(indices, _)| indices.clone()).collect();
+
+        // a receipt with too few serials doesn't guess which metadata they belong to.
+        assert_eq!(indices, [vec![3, 4, 5], vec![6, 7, 8, 9]]);
+    }
+}
@@ -17,9 +17,12 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+#[cfg(feature = "serde")]
+use crate::OnLedgerNftMetadata;
 use crate::{
     BoxGrpcFuture,
     Error,
+    TokenAmount,
     TokenId,
     Transaction,
     ValidateChecksums,
@@ -82,6 +85,20 @@ impl TokenMintTransaction {
         self
     }
 
+    /// Sets the amount of a fungible token to mint to the treasury account from a [`TokenAmount`].
+    ///
+    /// The network doesn't check the decimals of a mint,
+    /// use [`TokenAmount::check_decimals`] to make sure they match the token.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `amount` is negative.
+    pub fn token_amount(&mut self, amount: TokenAmount) -> crate::Result<&mut Self> {
+        let amount = u64::try_from(amount.get_value())
+            .map_err(|_| Error::basic_parse(format!("cannot mint a negative amount `{amount}`")))?;
+
+        Ok(self.amount(amount))
+    }
+
     /// Returns the list of metadata for a non-fungible token to mint to the treasury account.
     #[must_use]
     pub fn get_metadata(&self) -> &[Vec<u8>] {
@@ -98,6 +115,22 @@ impl TokenMintTransaction {
 
         self
     }
+
+    /// Sets the list of metadata for a non-fungible token to mint to the treasury account,
+    /// from HIP-412 metadata or URIs pointing to it.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if any of the metadata is invalid, or too large to fit on ledger.
+    #[cfg(feature = "serde")]
+    pub fn nft_metadata(
+        &mut self,
+        metadata: impl IntoIterator<Item = OnLedgerNftMetadata>,
+    ) -> crate::Result<&mut Self> {
+        let metadata =
+            metadata.into_iter().map(|it| it.to_bytes()).collect::<crate::Result<Vec<_>>>()?;
+
+        Ok(self.metadata(metadata))
+    }
 }
 
 impl TransactionData for TokenMintTransactionData {}
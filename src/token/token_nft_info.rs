@@ -58,6 +58,15 @@ impl TokenNftInfo {
         }
         .encode_to_vec()
     }
+
+    /// Parses [`metadata`](Self::metadata) as HIP-412 metadata, or a URI pointing to it.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if the metadata isn't inline HIP-412 metadata or a URI.
+    #[cfg(feature = "serde")]
+    pub fn parse_metadata(&self) -> crate::Result<crate::OnLedgerNftMetadata> {
+        crate::OnLedgerNftMetadata::from_bytes(&self.metadata)
+    }
 }
 
 impl FromProtobuf<services::response::Response> for TokenNftInfo {
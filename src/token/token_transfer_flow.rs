@@ -0,0 +1,624 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::hash_map::Entry;
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+use serde_json::Value;
+
+use super::{
+    NftId,
+    TokenAmount,
+    TokenAssociateTransaction,
+    TokenId,
+};
+use crate::mirror_rest::{
+    self,
+    DEFAULT_MAX_ATTEMPTS,
+    DEFAULT_MAX_BACKOFF,
+};
+use crate::signer::AnySigner;
+use crate::{
+    AccountId,
+    AccountInfoQuery,
+    Client,
+    Error,
+    PrivateKey,
+    PublicKey,
+    TransactionReceipt,
+    TransferPlanReceipts,
+    TransferPlanner,
+};
+
+/// Transfer tokens to receivers that may not be associated with them yet.
+///
+/// The operation of this flow is as follows:
+/// 1. Query the [`AccountInfo`](crate::AccountInfo) of every receiver, to find how many automatic association slots it has,
+///    and look up the tokens it's associated with on the mirror node.
+/// 2. Execute a [`TokenAssociateTransaction`] for every receiver that needs one and whose key was provided.
+/// 3. Transfer the tokens to every receiver that can take them directly.
+/// 4. Airdrop the tokens to every other receiver, which leaves them as pending airdrops for the receiver to claim.
+///
+/// Since the mirror node trails consensus by a few seconds, a receiver associated just before the flow is executed
+/// may be associated again (which fails, stopping the flow) or have its token airdropped instead.
+///
+/// Errors before any tokens move (in steps 1 and 2) stop the flow.
+/// Failed transfers and airdrops are reported in [`TokenTransferFlowReceipts`] instead.
+///
+/// # Examples
+///
+/// ```no_run
+/// use hiero_sdk::{AccountId, Client, PrivateKey, TokenId, TokenTransferFlow};
+///
+/// # async fn example(client: &Client, treasury_key: PrivateKey, holders: Vec<AccountId>) -> hiero_sdk::Result<()> {
+/// let token_id = TokenId::new(0, 0, 1234);
+///
+/// let mut flow = TokenTransferFlow::new();
+///
+/// flow.sender(AccountId::new(0, 0, 5678)).sign(treasury_key);
+///
+/// for holder in holders {
+///     flow.token_transfer(token_id, holder, 100);
+/// }
+///
+/// let receipts = flow.execute(client).await?;
+///
+/// for recipient in &receipts.recipients {
+///     println!("{} got {} by {:?}", recipient.account_id, recipient.token_id, recipient.delivery);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct TokenTransferFlow {
+    sender: Option<AccountId>,
+    movements: Vec<Movement>,
+    signers: Vec<AnySigner>,
+    receiver_signers: HashMap<AccountId, AnySigner>,
+}
+
+/// What the flow knows about a receiver.
+struct Receiver {
+    is_receiver_signature_required: bool,
+    max_automatic_token_associations: u32,
+    /// The tokens the receiver is associated with, and whether each one was associated automatically.
+    relationships: Vec<(TokenId, bool)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Movement {
+    Token { token_id: TokenId, receiver: AccountId, amount: i64, decimals: Option<u32> },
+    Nft { nft_id: NftId, receiver: AccountId },
+}
+
+impl Movement {
+    fn token_id(&self) -> TokenId {
+        match self {
+            Self::Token { token_id, .. } => *token_id,
+            Self::Nft { nft_id, .. } => nft_id.token_id,
+        }
+    }
+
+    fn receiver(&self) -> AccountId {
+        match self {
+            Self::Token { receiver, .. } | Self::Nft { receiver, .. } => *receiver,
+        }
+    }
+}
+
+/// How a [`TokenTransferFlow`] gets a token to a receiver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TokenDelivery {
+    /// The receiver was already associated with the token, so it's transferred.
+    Transferred,
+
+    /// The receiver had a free automatic association slot, so it's transferred and associated automatically.
+    AutoAssociated,
+
+    /// The receiver was associated with the token by the flow, so it's transferred.
+    Associated,
+
+    /// The token couldn't be transferred directly, so it's airdropped and pending until the receiver claims it.
+    PendingAirdrop,
+}
+
+/// How a [`TokenTransferFlow`] gets a single token to a single receiver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TokenTransferRecipient {
+    /// The receiver.
+    pub account_id: AccountId,
+
+    /// The token the receiver gets.
+    pub token_id: TokenId,
+
+    /// How the token gets to the receiver.
+    pub delivery: TokenDelivery,
+}
+
+/// The result of executing a [`TokenTransferFlow`].
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct TokenTransferFlowReceipts {
+    /// How each token gets to each receiver, in the order the receivers were added.
+    pub recipients: Vec<TokenTransferRecipient>,
+
+    /// The receipts of the [`TokenAssociateTransaction`]s, for each receiver the flow associated.
+    pub associations: Vec<(AccountId, TransactionReceipt)>,
+
+    /// The receipts of the transfers, see [`TransferPlanner::execute_transfers`].
+    pub transfers: TransferPlanReceipts,
+
+    /// The receipts of the airdrops, see [`TransferPlanner::execute_airdrops`].
+    pub airdrops: TransferPlanReceipts,
+}
+
+impl TokenTransferFlowReceipts {
+    /// Returns `true` if every transfer and airdrop succeeded.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.transfers.is_success() && self.airdrops.is_success()
+    }
+}
+
+impl TokenTransferFlow {
+    /// Create a new `TokenTransferFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the account the tokens are transferred from.
+    #[must_use]
+    pub fn get_sender(&self) -> Option<AccountId> {
+        self.sender
+    }
+
+    /// Sets the account the tokens are transferred from.
+    pub fn sender(&mut self, sender: AccountId) -> &mut Self {
+        self.sender = Some(sender);
+
+        self
+    }
+
+    /// Adds a transfer of `amount` of a fungible token to `receiver`.
+    ///
+    /// `amount` is in the lowest denomination for the token.
+    pub fn token_transfer(
+        &mut self,
+        token_id: TokenId,
+        receiver: AccountId,
+        amount: i64,
+    ) -> &mut Self {
+        self.movements.push(Movement::Token { token_id, receiver, amount, decimals: None });
+
+        self
+    }
+
+    /// Adds a transfer of `amount` of a fungible token to `receiver`,
+    /// ensuring that the token has `amount`'s decimals.
+    pub fn token_amount_transfer(
+        &mut self,
+        token_id: TokenId,
+        receiver: AccountId,
+        amount: TokenAmount,
+    ) -> &mut Self {
+        self.movements.push(Movement::Token {
+            token_id,
+            receiver,
+            amount: amount.get_value(),
+            decimals: Some(amount.get_decimals()),
+        });
+
+        self
+    }
+
+    /// Adds a transfer of an NFT to `receiver`.
+    pub fn nft_transfer(&mut self, nft_id: impl Into<NftId>, receiver: AccountId) -> &mut Self {
+        self.movements.push(Movement::Nft { nft_id: nft_id.into(), receiver });
+
+        self
+    }
+
+    /// Adds a key to sign the transfers and airdrops with, such as the key of the sender.
+    pub fn sign(&mut self, key: PrivateKey) -> &mut Self {
+        self.signers.push(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Adds a signer to sign the transfers and airdrops with, such as the signer of the sender.
+    pub fn sign_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.signers.push(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Sets the key of `receiver`, allowing the flow to associate it with tokens,
+    /// and to transfer to it if it requires a receiver signature.
+    pub fn receiver_key(&mut self, receiver: AccountId, key: PrivateKey) -> &mut Self {
+        self.receiver_signers.insert(receiver, AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Sets the signer of `receiver`, allowing the flow to associate it with tokens,
+    /// and to transfer to it if it requires a receiver signature.
+    pub fn receiver_signer<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        receiver: AccountId,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.receiver_signers.insert(receiver, AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Generates the required transactions and executes them all.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the sender is unset.
+    /// - If querying a receiver's account info fails.
+    /// - [`Error::BasicParse`] if the mirror node returns an error, or a response that can't be parsed.
+    /// - If associating a receiver with its tokens fails.
+    pub async fn execute(&self, client: &Client) -> crate::Result<TokenTransferFlowReceipts> {
+        let sender = self.sender.ok_or_else(|| {
+            Error::basic_parse("sender must be set to execute a `TokenTransferFlow`")
+        })?;
+
+        let mut receipts = TokenTransferFlowReceipts::default();
+        let mut receivers = HashMap::new();
+
+        for movement in &self.movements {
+            if let Entry::Vacant(entry) = receivers.entry(movement.receiver()) {
+                entry.insert(receiver(client, movement.receiver()).await?);
+            }
+        }
+
+        receipts.recipients = plan_deliveries(&self.movements, &receivers, |receiver| {
+            self.receiver_signers.contains_key(&receiver)
+        });
+
+        for (receiver, token_ids) in associations(&receipts.recipients) {
+            let mut tx = TokenAssociateTransaction::new();
+
+            tx.account_id(receiver).token_ids(token_ids).freeze_with(client)?;
+
+            if let Some(signer) = self.receiver_signers.get(&receiver) {
+                tx.sign_signer(signer.clone());
+            }
+
+            let receipt = tx.execute(client).await?.get_receipt(client).await?;
+
+            receipts.associations.push((receiver, receipt));
+        }
+
+        let deliveries: HashMap<_, _> = receipts
+            .recipients
+            .iter()
+            .map(|it| ((it.account_id, it.token_id), it.delivery))
+            .collect();
+
+        let mut transfers = TransferPlanner::new();
+        let mut airdrops = TransferPlanner::new();
+
+        for signer in &self.signers {
+            transfers.sign_signer(signer.clone());
+            airdrops.sign_signer(signer.clone());
+        }
+
+        let mut receiver_signed = HashSet::new();
+
+        for movement in &self.movements {
+            let receiver = movement.receiver();
+
+            let planner = match deliveries[&(receiver, movement.token_id())] {
+                TokenDelivery::PendingAirdrop => &mut airdrops,
+                _ => {
+                    // accounts that require a receiver signature only get direct transfers if we can sign for them.
+                    if receivers[&receiver].is_receiver_signature_required
+                        && receiver_signed.insert(receiver)
+                    {
+                        transfers.sign_signer(self.receiver_signers[&receiver].clone());
+                    }
+
+                    &mut transfers
+                }
+            };
+
+            match *movement {
+                Movement::Token { token_id, receiver, amount, decimals: None } => {
+                    planner.token_transfer(token_id, sender, receiver, amount)
+                }
+
+                Movement::Token { token_id, receiver, amount, decimals: Some(decimals) } => planner
+                    .token_amount_transfer(
+                        token_id,
+                        sender,
+                        receiver,
+                        TokenAmount::new(amount, decimals),
+                    ),
+
+                Movement::Nft { nft_id, receiver } => {
+                    planner.nft_transfer(nft_id, sender, receiver)
+                }
+            };
+        }
+
+        receipts.transfers = transfers.execute_transfers(client).await?;
+        receipts.airdrops = airdrops.execute_airdrops(client).await?;
+
+        Ok(receipts)
+    }
+}
+
+/// Returns what the flow needs to know about `account_id`.
+async fn receiver(client: &Client, account_id: AccountId) -> crate::Result<Receiver> {
+    let info = AccountInfoQuery::new().account_id(account_id).execute(client).await?;
+
+    // `AccountInfo::token_relationships` is no longer populated by the network.
+    let relationships = mirror_rest::get_all(
+        &mirror_rest::base_url(client),
+        &format!("/accounts/{account_id}/tokens?limit=100"),
+        "tokens",
+        None,
+        DEFAULT_MAX_ATTEMPTS,
+        DEFAULT_MAX_BACKOFF,
+    )
+    .await?;
+
+    Ok(Receiver {
+        is_receiver_signature_required: info.is_receiver_signature_required,
+        max_automatic_token_associations: info.max_automatic_token_associations,
+        relationships: relationships.iter().map(parse_relationship).collect::<Result<_, _>>()?,
+    })
+}
+
+// `{"automatic_association": true, "balance": 5, "token_id": "0.0.4", ...}`
+fn parse_relationship(value: &Value) -> crate::Result<(TokenId, bool)> {
+    let token_id = value
+        .get("token_id")
+        .and_then(Value::as_str)
+        .ok_or_else(|| Error::basic_parse("mirror node token relationship is missing `token_id`"))?
+        .parse()?;
+
+    let automatic = value.get("automatic_association").and_then(Value::as_bool).unwrap_or(false);
+
+    Ok((token_id, automatic))
+}
+
+/// Decides how each token gets to each receiver.
+fn plan_deliveries(
+    movements: &[Movement],
+    receivers: &HashMap<AccountId, Receiver>,
+    has_signer: impl Fn(AccountId) -> bool,
+) -> Vec<TokenTransferRecipient> {
+    let mut recipients: Vec<TokenTransferRecipient> = Vec::new();
+    let mut free_slots = HashMap::new();
+
+    for movement in movements {
+        let (account_id, token_id) = (movement.receiver(), movement.token_id());
+
+        if recipients.iter().any(|it| it.account_id == account_id && it.token_id == token_id) {
+            continue;
+        }
+
+        let receiver = &receivers[&account_id];
+
+        let free_slots = free_slots.entry(account_id).or_insert_with(|| {
+            // `-1` (`u32::MAX` here) means unlimited automatic associations.
+            (receiver.max_automatic_token_associations != u32::MAX).then(|| {
+                let used =
+                    receiver.relationships.iter().filter(|(_, automatic)| *automatic).count();
+
+                (receiver.max_automatic_token_associations as usize).saturating_sub(used)
+            })
+        });
+
+        let delivery = if receiver.is_receiver_signature_required && !has_signer(account_id) {
+            TokenDelivery::PendingAirdrop
+        } else if receiver.relationships.iter().any(|(it, _)| *it == token_id) {
+            TokenDelivery::Transferred
+        } else if free_slots.is_none_or(|it| it > 0) {
+            if let Some(free_slots) = free_slots {
+                *free_slots -= 1;
+            }
+
+            TokenDelivery::AutoAssociated
+        } else if has_signer(account_id) {
+            TokenDelivery::Associated
+        } else {
+            TokenDelivery::PendingAirdrop
+        };
+
+        recipients.push(TokenTransferRecipient { account_id, token_id, delivery });
+    }
+
+    recipients
+}
+
+/// Returns the tokens each receiver needs to be associated with, in order.
+fn associations(recipients: &[TokenTransferRecipient]) -> Vec<(AccountId, Vec<TokenId>)> {
+    let mut associations: Vec<(AccountId, Vec<TokenId>)> = Vec::new();
+
+    for recipient in recipients.iter().filter(|it| it.delivery == TokenDelivery::Associated) {
+        match associations.iter_mut().find(|(account_id, _)| *account_id == recipient.account_id) {
+            Some((_, token_ids)) => token_ids.push(recipient.token_id),
+            None => associations.push((recipient.account_id, Vec::from([recipient.token_id]))),
+        }
+    }
+
+    associations
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use super::{
+        associations,
+        parse_relationship,
+        plan_deliveries,
+        Movement,
+        Receiver,
+    };
+    use crate::{
+        AccountId,
+        TokenDelivery,
+        TokenId,
+    };
+
+    const TOKEN: TokenId = TokenId::new(0, 0, 100);
+    const OTHER_TOKEN: TokenId = TokenId::new(0, 0, 101);
+
+    fn receiver(
+        max_automatic_token_associations: u32,
+        relationships: &[(TokenId, bool)],
+    ) -> Receiver {
+        Receiver {
+            is_receiver_signature_required: false,
+            max_automatic_token_associations,
+            relationships: relationships.to_vec(),
+        }
+    }
+
+    fn transfer(token_id: TokenId, receiver: AccountId) -> Movement {
+        Movement::Token { token_id, receiver, amount: 1, decimals: None }
+    }
+
+    fn deliveries(
+        movements: &[Movement],
+        receivers: &HashMap<AccountId, Receiver>,
+        signers: &[AccountId],
+    ) -> Vec<TokenDelivery> {
+        plan_deliveries(movements, receivers, |it| signers.contains(&it))
+            .into_iter()
+            .map(|it| it.delivery)
+            .collect()
+    }
+
+    #[test]
+    fn already_associated() {
+        let alice = AccountId::new(0, 0, 5);
+        let receivers = HashMap::from([(alice, receiver(0, &[(TOKEN, false)]))]);
+
+        assert_eq!(
+            deliveries(&[transfer(TOKEN, alice), transfer(TOKEN, alice)], &receivers, &[]),
+            [TokenDelivery::Transferred]
+        );
+    }
+
+    #[test]
+    fn unlimited_slots() {
+        let alice = AccountId::new(0, 0, 5);
+        let receivers = HashMap::from([(alice, receiver(u32::MAX, &[(OTHER_TOKEN, true)]))]);
+
+        assert_eq!(
+            deliveries(&[transfer(TOKEN, alice), transfer(OTHER_TOKEN, alice)], &receivers, &[]),
+            [TokenDelivery::AutoAssociated, TokenDelivery::Transferred]
+        );
+    }
+
+    #[test]
+    fn free_slots_run_out() {
+        let alice = AccountId::new(0, 0, 5);
+        let bob = AccountId::new(0, 0, 6);
+
+        // alice has used one of two slots, and the flow uses the other.
+        let receivers = HashMap::from([
+            (alice, receiver(2, &[(TokenId::new(0, 0, 99), true)])),
+            (bob, receiver(1, &[(TokenId::new(0, 0, 99), true)])),
+        ]);
+
+        let movements = [
+            transfer(TOKEN, alice),
+            transfer(OTHER_TOKEN, alice),
+            transfer(TOKEN, bob),
+            Movement::Nft { nft_id: OTHER_TOKEN.nft(1), receiver: bob },
+        ];
+
+        assert_eq!(
+            deliveries(&movements, &receivers, &[alice]),
+            [
+                TokenDelivery::AutoAssociated,
+                // exhausted, with a receiver key.
+                TokenDelivery::Associated,
+                // exhausted, without one.
+                TokenDelivery::PendingAirdrop,
+                TokenDelivery::PendingAirdrop,
+            ]
+        );
+    }
+
+    #[test]
+    fn receiver_signature_required() {
+        let alice = AccountId::new(0, 0, 5);
+        let bob = AccountId::new(0, 0, 6);
+
+        let mut receivers =
+            HashMap::from([(alice, receiver(0, &[(TOKEN, false)])), (bob, receiver(0, &[]))]);
+
+        for receiver in receivers.values_mut() {
+            receiver.is_receiver_signature_required = true;
+        }
+
+        assert_eq!(
+            deliveries(
+                &[transfer(TOKEN, alice), transfer(TOKEN, bob), transfer(OTHER_TOKEN, bob)],
+                &receivers,
+                &[bob]
+            ),
+            [
+                // even though alice is associated, nobody can sign for alice.
+                TokenDelivery::PendingAirdrop,
+                TokenDelivery::Associated,
+                TokenDelivery::Associated,
+            ]
+        );
+    }
+
+    #[test]
+    fn associations_by_receiver() {
+        let alice = AccountId::new(0, 0, 5);
+        let bob = AccountId::new(0, 0, 6);
+
+        let receivers = HashMap::from([(alice, receiver(0, &[])), (bob, receiver(0, &[]))]);
+
+        let movements =
+            [transfer(TOKEN, alice), transfer(TOKEN, bob), transfer(OTHER_TOKEN, alice)];
+
+        let recipients = plan_deliveries(&movements, &receivers, |_| true);
+
+        assert_eq!(
+            associations(&recipients),
+            [(alice, vec![TOKEN, OTHER_TOKEN]), (bob, vec![TOKEN])]
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let relationship = parse_relationship(&json!({
+            "automatic_association": true,
+            "balance": 5,
+            "created_timestamp": "1651560386.661997287",
+            "decimals": 2,
+            "freeze_status": "UNFROZEN",
+            "kyc_status": "GRANTED",
+            "token_id": "0.0.100"
+        }))
+        .unwrap();
+
+        assert_eq!(relationship, (TOKEN, true));
+
+        assert!(parse_relationship(&json!({ "balance": 5 })).is_err());
+    }
+}
@@ -17,6 +17,8 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
 };
+#[cfg(feature = "serde")]
+use crate::OnLedgerNftMetadata;
 use crate::{
     BoxGrpcFuture,
     Error,
@@ -83,6 +85,15 @@ impl TokenUpdateNftsTransaction {
         self.data_mut().metadata = metadata;
         self
     }
+
+    /// Sets the new metadata of the NFT(s), from HIP-412 metadata or a URI pointing to it.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the metadata is invalid, or too large to fit on ledger.
+    #[cfg(feature = "serde")]
+    pub fn nft_metadata(&mut self, metadata: &OnLedgerNftMetadata) -> crate::Result<&mut Self> {
+        Ok(self.metadata(metadata.to_bytes()?))
+    }
 }
 
 impl TransactionData for TokenUpdateNftsTransactionData {}
@@ -845,6 +845,86 @@ impl<D: TransactionExecute> Transaction<D> {
         Ok(iter.collect())
     }
 
+    /// Verifies every signature in this transaction's per-node signature maps
+    /// against the public key it's paired with.
+    ///
+    /// Ed25519 signatures are checked with a single batch verification,
+    /// falling back to checking them one at a time only to locate an invalid signature.
+    ///
+    /// Batch verification is cofactored, unlike [`PublicKey::verify`],
+    /// so a maliciously crafted Ed25519 signature with small order components may pass here even though
+    /// [`PublicKey::verify`] rejects it. Honestly produced signatures are judged the same by both.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if this transaction isn't frozen.
+    /// - [`Error::SignatureVerify`] if any signature is invalid.
+    /// - [`Error::SignatureVerify`] if a signature's public key prefix isn't a full public key,
+    ///   or its signature type isn't Ed25519 or ECDSA(secp256k1).
+    pub fn verify_signatures(&self) -> crate::Result<()> {
+        use services::signature_pair::Signature;
+
+        if !self.is_frozen() {
+            return Err(Error::basic_parse("Transaction must be frozen to verify its signatures"));
+        }
+
+        let sources = self.make_sources()?;
+
+        let mut items = Vec::new();
+        let mut node_ids = Vec::new();
+
+        for chunk in sources.chunks() {
+            for (index, signed_transaction) in chunk.signed_transactions().iter().enumerate() {
+                let sig_pairs = signed_transaction
+                    .sig_map
+                    .as_ref()
+                    .map_or_else(|| [].as_slice(), |it| &it.sig_pair);
+
+                for sig_pair in sig_pairs {
+                    let (key, signature) = match &sig_pair.signature {
+                        Some(Signature::Ed25519(signature)) => {
+                            (PublicKey::from_bytes_ed25519(&sig_pair.pub_key_prefix), signature)
+                        }
+                        Some(Signature::EcdsaSecp256k1(signature)) => {
+                            (PublicKey::from_bytes_ecdsa(&sig_pair.pub_key_prefix), signature)
+                        }
+                        _ => {
+                            return Err(Error::signature_verify(
+                                "Unsupported transaction signature type",
+                            ))
+                        }
+                    };
+
+                    let key = key.map_err(|_| {
+                        Error::signature_verify(format!(
+                            "cannot verify signature for public key prefix `{}`",
+                            hex::encode(&sig_pair.pub_key_prefix)
+                        ))
+                    })?;
+
+                    items.push((
+                        key,
+                        signed_transaction.body_bytes.as_slice(),
+                        signature.as_slice(),
+                    ));
+                    node_ids.push(chunk.node_ids().get(index).copied());
+                }
+            }
+        }
+
+        match PublicKey::find_invalid_signature(&items) {
+            Some(index) => {
+                let key = items[index].0;
+                let message = match node_ids[index] {
+                    Some(node_id) => format!("invalid signature by `{key}` for node `{node_id}`"),
+                    None => format!("invalid signature by `{key}`"),
+                };
+
+                Err(Error::signature_verify(message))
+            }
+            None => Ok(()),
+        }
+    }
+
     #[allow(deprecated)]
     fn make_transaction_list_chunked(&self) -> crate::Result<Vec<services::Transaction>> {
         // todo: fix this with chunked transactions.
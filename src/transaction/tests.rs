@@ -338,3 +338,83 @@ fn test_offline_transaction_signing_with_chunks() -> crate::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn verify_signatures() -> crate::Result<()> {
+    let mut tx = FileAppendTransaction::new();
+
+    tx.node_account_ids(TEST_NODE_ACCOUNT_IDS)
+        .transaction_id(TEST_TX_ID)
+        .max_transaction_fee(Hbar::new(2))
+        .contents(vec![0; 8000]) // contents that will require chunking
+        .sign(unused_private_key())
+        .sign(PrivateKey::generate_ed25519())
+        .sign(PrivateKey::generate_ecdsa())
+        .freeze()?;
+
+    tx.verify_signatures()?;
+
+    let tx = Transaction::from_bytes(&tx.to_bytes()?)?;
+    tx.verify_signatures()?;
+
+    Ok(())
+}
+
+#[test]
+fn verify_signatures_not_frozen() {
+    let mut tx = TransferTransaction::new();
+
+    tx.node_account_ids([TEST_NODE_ACCOUNT_IDS[0]]).transaction_id(TEST_TX_ID);
+
+    assert_matches!(tx.verify_signatures(), Err(crate::Error::BasicParse(_)));
+    assert!(!tx.is_frozen());
+}
+
+#[test]
+fn verify_signatures_invalid() -> crate::Result<()> {
+    let mut tx = TransferTransaction::new();
+
+    tx.node_account_ids([TEST_NODE_ACCOUNT_IDS[0]])
+        .transaction_id(TEST_TX_ID)
+        .max_transaction_fee(Hbar::new(2))
+        .sign(unused_private_key())
+        .freeze()?;
+
+    tx.add_signature(PrivateKey::generate_ed25519().public_key(), vec![0; 64]);
+
+    let error =
+        assert_matches!(tx.verify_signatures(), Err(crate::Error::SignatureVerify(error)) => error);
+    assert!(error.to_string().contains("0.0.5005"));
+
+    // the key that *did* sign is still valid.
+    unused_private_key().public_key().verify_transaction(&mut tx)?;
+
+    Ok(())
+}
+
+#[test]
+fn verify_signatures_tampered() -> crate::Result<()> {
+    let mut tx = TransferTransaction::new();
+
+    tx.node_account_ids([TEST_NODE_ACCOUNT_IDS[0]])
+        .transaction_id(TEST_TX_ID)
+        .max_transaction_fee(Hbar::new(2));
+
+    for _ in 0..8 {
+        tx.sign(PrivateKey::generate_ed25519()).sign(PrivateKey::generate_ecdsa());
+    }
+
+    tx.freeze()?;
+
+    let key = PrivateKey::generate_ed25519();
+    let mut signature = key.sign_transaction(&mut tx.clone())?;
+    signature[0] ^= 1;
+
+    tx.add_signature(key.public_key(), signature);
+
+    let error =
+        assert_matches!(tx.verify_signatures(), Err(crate::Error::SignatureVerify(error)) => error);
+    assert!(error.to_string().contains(&key.public_key().to_string()));
+
+    Ok(())
+}
@@ -120,6 +120,7 @@ impl TransactionReceipt {
             Err(Error::ReceiptStatus {
                 status: self.status,
                 transaction_id: self.transaction_id.map(Box::new),
+                revert: None,
             })
         } else {
             Ok(self)
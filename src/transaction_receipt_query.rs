@@ -165,6 +165,7 @@ impl QueryExecute for TransactionReceiptQueryData {
             return Err(Error::ReceiptStatus {
                 transaction_id: self.transaction_id.map(Box::new),
                 status: receipt.status,
+                revert: None,
             });
         }
 
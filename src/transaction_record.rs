@@ -7,9 +7,11 @@ use time::OffsetDateTime;
 
 use crate::protobuf::ToProtobuf;
 use crate::{
+    AbiEvent,
     AccountId,
     AssessedCustomFee,
     ContractFunctionResult,
+    DecodedEvent,
     EvmAddress,
     FromProtobuf,
     Hbar,
@@ -131,6 +133,18 @@ impl TransactionRecord {
         ToProtobuf::to_bytes(self)
     }
 
+    /// Decode every log emitted for `event` by the contract call this record is for.
+    ///
+    /// Returns an empty list if this record isn't for a contract call.
+    ///
+    /// # Errors
+    /// - See [`ContractFunctionResult::decode_events`](crate::ContractFunctionResult::decode_events).
+    pub fn decode_events(&self, event: &AbiEvent) -> crate::Result<Vec<DecodedEvent>> {
+        self.contract_function_result
+            .as_ref()
+            .map_or_else(|| Ok(Vec::new()), |it| it.decode_events(event))
+    }
+
     fn from_protobuf(
         record: services::TransactionRecord,
         duplicates: Vec<Self>,
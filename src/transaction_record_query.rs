@@ -13,6 +13,7 @@ use crate::query::{
 };
 use crate::{
     BoxGrpcFuture,
+    ContractFunctionResult,
     Error,
     FromProtobuf,
     Query,
@@ -137,6 +138,11 @@ impl QueryExecute for TransactionRecordQueryData {
             return Err(Error::ReceiptStatus {
                 transaction_id: self.transaction_id.map(Box::new),
                 status: record.receipt.status,
+                revert: record
+                    .contract_function_result
+                    .as_ref()
+                    .and_then(ContractFunctionResult::revert)
+                    .map(Box::new),
             });
         }
 
@@ -0,0 +1,805 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crate::signer::AnySigner;
+use crate::transaction::TransactionExecute;
+use crate::{
+    AccountId,
+    AnyTransaction,
+    BatchTransaction,
+    Client,
+    Error,
+    Hbar,
+    NftId,
+    PrivateKey,
+    PublicKey,
+    TokenAirdropTransaction,
+    TokenAmount,
+    TokenId,
+    Transaction,
+    TransactionReceipt,
+    TransactionReceiptQuery,
+    TransferTransaction,
+};
+
+/// The default number of hbar, token, and NFT transfers the network allows in a single transaction, for each kind.
+const DEFAULT_MAX_TRANSFERS: usize = 10;
+
+/// The maximum number of inner transactions in a [`BatchTransaction`].
+const DEFAULT_MAX_BATCH_SIZE: usize = 25;
+
+/// Splits an arbitrarily large set of hbar, token, and NFT movements into transactions the network accepts.
+///
+/// Each movement is balanced on its own (the sender is debited what the receiver is credited),
+/// so every planned transaction is balanced too.
+/// Movements are grouped by token and sender (in the order each group first appears), since movements in a group
+/// share the sender's balance adjustment. The groups are then packed in order, a new transaction is started
+/// as soon as the next movement would exceed one of the limits.
+///
+/// The plan can be executed as individual transactions, or wrapped in [`BatchTransaction`]s
+/// (see [`batch_size`](Self::batch_size)).
+///
+/// # Examples
+///
+/// ```no_run
+/// use hiero_sdk::{AccountId, Client, TokenId, TransferPlanner};
+///
+/// # async fn example(client: &Client, holders: Vec<AccountId>) -> hiero_sdk::Result<()> {
+/// let token_id = TokenId::new(0, 0, 1234);
+/// let treasury = AccountId::new(0, 0, 5678);
+///
+/// let mut planner = TransferPlanner::new();
+///
+/// for holder in holders {
+///     planner.token_transfer(token_id, treasury, holder, 100);
+/// }
+///
+/// let receipts = planner.execute_airdrops(client).await?;
+///
+/// for (indices, error) in &receipts.errors {
+///     eprintln!("transactions {indices:?} failed: {error}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransferPlanner {
+    movements: Vec<Movement>,
+    max_hbar_transfers: usize,
+    max_token_transfers: usize,
+    max_nft_transfers: usize,
+    max_airdrop_transfers: usize,
+    batch_size: Option<usize>,
+    signers: Vec<AnySigner>,
+}
+
+#[derive(Debug, Clone)]
+enum Movement {
+    Hbar { from: AccountId, to: AccountId, amount: i64 },
+    Token { token_id: TokenId, from: AccountId, to: AccountId, amount: i64, decimals: Option<u32> },
+    Nft { nft_id: NftId, from: AccountId, to: AccountId },
+}
+
+impl Movement {
+    // the token (`None` for hbar) and sender, movements with the same group share the sender's adjustment.
+    fn group(&self) -> (Option<TokenId>, AccountId) {
+        match *self {
+            Self::Hbar { from, .. } => (None, from),
+            Self::Token { token_id, from, .. } => (Some(token_id), from),
+            Self::Nft { nft_id, from, .. } => (Some(nft_id.token_id), from),
+        }
+    }
+}
+
+/// The receipts of executing a [`TransferPlanner`]'s plan.
+///
+/// Transactions are identified by their index in the plan
+/// (the same as in [`plan_transfers`](TransferPlanner::plan_transfers) or [`plan_airdrops`](TransferPlanner::plan_airdrops)),
+/// so failed transactions can be planned and executed again on their own.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct TransferPlanReceipts {
+    /// The receipts of the transactions that succeeded, along with their index in the plan.
+    pub receipts: Vec<(usize, TransactionReceipt)>,
+
+    /// The errors of the transactions that failed, along with the indices of the transactions the error applies to.
+    ///
+    /// When executing in batches, a failed batch fails every transaction in it.
+    pub errors: Vec<(Vec<usize>, Error)>,
+}
+
+impl TransferPlanReceipts {
+    /// Returns `true` if every transaction in the plan succeeded.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// The balance changes of one planned transaction.
+#[derive(Default)]
+struct Part {
+    hbar: Vec<(AccountId, i64)>,
+    tokens: Vec<(TokenId, Option<u32>, Vec<(AccountId, i64)>)>,
+    nfts: Vec<(NftId, AccountId, AccountId)>,
+}
+
+impl Part {
+    fn token_adjustments(&self) -> usize {
+        self.tokens.iter().map(|(_, _, adjustments)| adjustments.len()).sum()
+    }
+
+    fn new_token_adjustments(&self, token_id: TokenId, accounts: [AccountId; 2]) -> usize {
+        let existing = self.tokens.iter().find(|(it, _, _)| *it == token_id);
+
+        count_new(existing.map_or(&[][..], |(_, _, adjustments)| adjustments), accounts)
+    }
+
+    fn fits(&self, planner: &TransferPlanner, movement: &Movement, is_airdrop: bool) -> bool {
+        match *movement {
+            Movement::Hbar { from, to, .. } => {
+                self.hbar.len() + count_new(&self.hbar, [from, to]) <= planner.max_hbar_transfers
+            }
+
+            Movement::Token { token_id, from, to, .. } => {
+                let token_adjustments =
+                    self.token_adjustments() + self.new_token_adjustments(token_id, [from, to]);
+
+                if is_airdrop {
+                    token_adjustments + self.nfts.len() <= planner.max_airdrop_transfers
+                } else {
+                    token_adjustments <= planner.max_token_transfers
+                }
+            }
+
+            Movement::Nft { .. } => {
+                if is_airdrop {
+                    self.token_adjustments() + self.nfts.len() < planner.max_airdrop_transfers
+                } else {
+                    self.nfts.len() < planner.max_nft_transfers
+                }
+            }
+        }
+    }
+
+    fn push(&mut self, movement: &Movement) -> crate::Result<()> {
+        match *movement {
+            Movement::Hbar { from, to, amount } => {
+                adjust(&mut self.hbar, from, negate(amount)?)?;
+                adjust(&mut self.hbar, to, amount)?;
+            }
+
+            Movement::Token { token_id, from, to, amount, decimals } => {
+                let index = match self.tokens.iter().position(|(it, _, _)| *it == token_id) {
+                    Some(index) => index,
+                    None => {
+                        self.tokens.push((token_id, None, Vec::new()));
+                        self.tokens.len() - 1
+                    }
+                };
+
+                let (_, expected_decimals, adjustments) = &mut self.tokens[index];
+
+                *expected_decimals = expected_decimals.or(decimals);
+                adjust(adjustments, from, negate(amount)?)?;
+                adjust(adjustments, to, amount)?;
+            }
+
+            Movement::Nft { nft_id, from, to } => self.nfts.push((nft_id, from, to)),
+        }
+
+        Ok(())
+    }
+
+    fn to_transfer_transaction(&self) -> TransferTransaction {
+        let mut tx = TransferTransaction::new();
+
+        for &(account_id, amount) in &self.hbar {
+            tx.hbar_transfer(account_id, Hbar::from_tinybars(amount));
+        }
+
+        for (token_id, decimals, adjustments) in &self.tokens {
+            for &(account_id, amount) in adjustments {
+                match decimals {
+                    Some(decimals) => {
+                        tx.token_transfer_with_decimals(*token_id, account_id, amount, *decimals)
+                    }
+                    None => tx.token_transfer(*token_id, account_id, amount),
+                };
+            }
+        }
+
+        for &(nft_id, from, to) in &self.nfts {
+            tx.nft_transfer(nft_id, from, to);
+        }
+
+        tx
+    }
+
+    fn to_airdrop_transaction(&self) -> TokenAirdropTransaction {
+        let mut tx = TokenAirdropTransaction::new();
+
+        for (token_id, decimals, adjustments) in &self.tokens {
+            for &(account_id, amount) in adjustments {
+                match decimals {
+                    Some(decimals) => {
+                        tx.token_transfer_with_decimals(*token_id, account_id, amount, *decimals)
+                    }
+                    None => tx.token_transfer(*token_id, account_id, amount),
+                };
+            }
+        }
+
+        for &(nft_id, from, to) in &self.nfts {
+            tx.nft_transfer(nft_id, from, to);
+        }
+
+        tx
+    }
+}
+
+fn count_new(adjustments: &[(AccountId, i64)], accounts: [AccountId; 2]) -> usize {
+    let [from, to] = accounts;
+    let is_new = |account_id: AccountId| !adjustments.iter().any(|(it, _)| *it == account_id);
+
+    usize::from(is_new(from)) + usize::from(from != to && is_new(to))
+}
+
+fn adjust(
+    adjustments: &mut Vec<(AccountId, i64)>,
+    account_id: AccountId,
+    amount: i64,
+) -> crate::Result<()> {
+    match adjustments.iter_mut().find(|(it, _)| *it == account_id) {
+        Some((_, it)) => {
+            *it = it.checked_add(amount).ok_or_else(|| {
+                Error::basic_parse(format!("balance adjustment of `{account_id}` overflows"))
+            })?;
+        }
+        None => adjustments.push((account_id, amount)),
+    }
+
+    Ok(())
+}
+
+fn negate(amount: i64) -> crate::Result<i64> {
+    amount
+        .checked_neg()
+        .ok_or_else(|| Error::basic_parse(format!("transfer amount `{amount}` overflows")))
+}
+
+impl Default for TransferPlanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransferPlanner {
+    /// Create a new `TransferPlanner` without any movements, using the network's default limits.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            movements: Vec::new(),
+            max_hbar_transfers: DEFAULT_MAX_TRANSFERS,
+            max_token_transfers: DEFAULT_MAX_TRANSFERS,
+            max_nft_transfers: DEFAULT_MAX_TRANSFERS,
+            max_airdrop_transfers: DEFAULT_MAX_TRANSFERS,
+            batch_size: None,
+            signers: Vec::new(),
+        }
+    }
+
+    /// Adds a movement of `amount` hbar from `from` to `to`.
+    pub fn hbar_transfer(&mut self, from: AccountId, to: AccountId, amount: Hbar) -> &mut Self {
+        self.movements.push(Movement::Hbar { from, to, amount: amount.to_tinybars() });
+
+        self
+    }
+
+    /// Adds a movement of `amount` of a fungible token from `from` to `to`.
+    ///
+    /// `amount` is in the lowest denomination for the token.
+    pub fn token_transfer(
+        &mut self,
+        token_id: TokenId,
+        from: AccountId,
+        to: AccountId,
+        amount: i64,
+    ) -> &mut Self {
+        self.movements.push(Movement::Token { token_id, from, to, amount, decimals: None });
+
+        self
+    }
+
+    /// Adds a movement of `amount` of a fungible token from `from` to `to`,
+    /// ensuring that the token has `amount`'s decimals.
+    pub fn token_amount_transfer(
+        &mut self,
+        token_id: TokenId,
+        from: AccountId,
+        to: AccountId,
+        amount: TokenAmount,
+    ) -> &mut Self {
+        self.movements.push(Movement::Token {
+            token_id,
+            from,
+            to,
+            amount: amount.get_value(),
+            decimals: Some(amount.get_decimals()),
+        });
+
+        self
+    }
+
+    /// Adds a movement of an NFT from `from` to `to`.
+    pub fn nft_transfer(
+        &mut self,
+        nft_id: impl Into<NftId>,
+        from: AccountId,
+        to: AccountId,
+    ) -> &mut Self {
+        self.movements.push(Movement::Nft { nft_id: nft_id.into(), from, to });
+
+        self
+    }
+
+    /// Returns the maximum number of hbar adjustments in a single [`TransferTransaction`].
+    #[must_use]
+    pub fn get_max_hbar_transfers(&self) -> usize {
+        self.max_hbar_transfers
+    }
+
+    /// Sets the maximum number of hbar adjustments in a single [`TransferTransaction`].
+    ///
+    /// The default is 10, same as the network.
+    pub fn max_hbar_transfers(&mut self, max_hbar_transfers: usize) -> &mut Self {
+        self.max_hbar_transfers = max_hbar_transfers;
+
+        self
+    }
+
+    /// Returns the maximum number of fungible token adjustments, across all tokens, in a single [`TransferTransaction`].
+    #[must_use]
+    pub fn get_max_token_transfers(&self) -> usize {
+        self.max_token_transfers
+    }
+
+    /// Sets the maximum number of fungible token adjustments, across all tokens, in a single [`TransferTransaction`].
+    ///
+    /// The default is 10, same as the network.
+    pub fn max_token_transfers(&mut self, max_token_transfers: usize) -> &mut Self {
+        self.max_token_transfers = max_token_transfers;
+
+        self
+    }
+
+    /// Returns the maximum number of NFT transfers in a single [`TransferTransaction`].
+    #[must_use]
+    pub fn get_max_nft_transfers(&self) -> usize {
+        self.max_nft_transfers
+    }
+
+    /// Sets the maximum number of NFT transfers in a single [`TransferTransaction`].
+    ///
+    /// The default is 10, same as the network.
+    pub fn max_nft_transfers(&mut self, max_nft_transfers: usize) -> &mut Self {
+        self.max_nft_transfers = max_nft_transfers;
+
+        self
+    }
+
+    /// Returns the maximum number of token adjustments and NFT transfers combined in a single [`TokenAirdropTransaction`].
+    #[must_use]
+    pub fn get_max_airdrop_transfers(&self) -> usize {
+        self.max_airdrop_transfers
+    }
+
+    /// Sets the maximum number of token adjustments and NFT transfers combined in a single [`TokenAirdropTransaction`].
+    ///
+    /// The default is 10, same as the network.
+    pub fn max_airdrop_transfers(&mut self, max_airdrop_transfers: usize) -> &mut Self {
+        self.max_airdrop_transfers = max_airdrop_transfers;
+
+        self
+    }
+
+    /// Returns the number of planned transactions wrapped in each [`BatchTransaction`], if batching.
+    #[must_use]
+    pub fn get_batch_size(&self) -> Option<usize> {
+        self.batch_size
+    }
+
+    /// Wraps up to `batch_size` planned transactions in each [`BatchTransaction`] when executing.
+    ///
+    /// The transactions in a batch succeed or fail together.
+    /// The client's operator key is used as the batch key.
+    ///
+    /// # Panics
+    /// Panics if `batch_size` is zero or greater than 25.
+    pub fn batch_size(&mut self, batch_size: usize) -> &mut Self {
+        assert!(
+            (1..=DEFAULT_MAX_BATCH_SIZE).contains(&batch_size),
+            "batch size must be between 1 and {DEFAULT_MAX_BATCH_SIZE}"
+        );
+
+        self.batch_size = Some(batch_size);
+
+        self
+    }
+
+    /// Adds a key to sign every planned transaction with, such as the key of a sender.
+    pub fn sign(&mut self, key: PrivateKey) -> &mut Self {
+        self.signers.push(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Adds a signer to sign every planned transaction with, such as the signer of a sender.
+    pub fn sign_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.signers.push(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    pub(crate) fn sign_signer(&mut self, signer: AnySigner) -> &mut Self {
+        self.signers.push(signer);
+
+        self
+    }
+
+    // `movements`, grouped by `Movement::group` in the order each group first appears.
+    fn grouped_movements(&self) -> Vec<&Movement> {
+        let mut groups = HashMap::new();
+
+        for movement in &self.movements {
+            let next = groups.len();
+            groups.entry(movement.group()).or_insert(next);
+        }
+
+        let mut movements: Vec<_> = self.movements.iter().collect();
+
+        // stable, so movements within a group keep their order.
+        movements.sort_by_key(|it| groups[&it.group()]);
+
+        movements
+    }
+
+    fn plan(&self, is_airdrop: bool) -> crate::Result<Vec<Part>> {
+        let mut parts = Vec::new();
+        let mut part = Part::default();
+
+        for movement in self.grouped_movements() {
+            if is_airdrop && matches!(movement, Movement::Hbar { .. }) {
+                return Err(Error::basic_parse("airdrops can't transfer hbar"));
+            }
+
+            if !part.fits(self, movement, is_airdrop) {
+                parts.push(std::mem::take(&mut part));
+
+                if !part.fits(self, movement, is_airdrop) {
+                    return Err(Error::basic_parse(format!(
+                        "{movement:?} doesn't fit in a single transaction"
+                    )));
+                }
+            }
+
+            part.push(movement)?;
+        }
+
+        if !self.movements.is_empty() {
+            parts.push(part);
+        }
+
+        Ok(parts)
+    }
+
+    /// Returns the movements split into [`TransferTransaction`]s.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if a single movement exceeds the limits, for example if they're less than 2.
+    /// - [`Error::BasicParse`] if an account's balance adjustment in a transaction overflows an `i64`.
+    pub fn plan_transfers(&self) -> crate::Result<Vec<TransferTransaction>> {
+        Ok(self.plan(false)?.iter().map(Part::to_transfer_transaction).collect())
+    }
+
+    /// Returns the movements split into [`TokenAirdropTransaction`]s.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if there are hbar movements, since airdrops only transfer tokens.
+    /// - [`Error::BasicParse`] if a single movement exceeds the limits, for example if they're less than 2.
+    /// - [`Error::BasicParse`] if an account's balance adjustment in a transaction overflows an `i64`.
+    pub fn plan_airdrops(&self) -> crate::Result<Vec<TokenAirdropTransaction>> {
+        Ok(self.plan(true)?.iter().map(Part::to_airdrop_transaction).collect())
+    }
+
+    /// Executes the planned [`TransferTransaction`]s, returning the receipt or error of each.
+    ///
+    /// A failed transaction doesn't stop the rest of the plan from executing.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if planning fails, see [`plan_transfers`](Self::plan_transfers).
+    pub async fn execute_transfers(&self, client: &Client) -> crate::Result<TransferPlanReceipts> {
+        let transactions = self.plan_transfers()?;
+
+        Ok(self.execute_transactions(client, transactions).await)
+    }
+
+    /// Executes the planned [`TokenAirdropTransaction`]s, returning the receipt or error of each.
+    ///
+    /// A failed transaction doesn't stop the rest of the plan from executing.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if planning fails, see [`plan_airdrops`](Self::plan_airdrops).
+    pub async fn execute_airdrops(&self, client: &Client) -> crate::Result<TransferPlanReceipts> {
+        let transactions = self.plan_airdrops()?;
+
+        Ok(self.execute_transactions(client, transactions).await)
+    }
+
+    async fn execute_transactions<D>(
+        &self,
+        client: &Client,
+        transactions: Vec<Transaction<D>>,
+    ) -> TransferPlanReceipts
+    where
+        D: TransactionExecute,
+        Transaction<D>: Into<AnyTransaction>,
+    {
+        let mut receipts = TransferPlanReceipts::default();
+
+        let Some(batch_size) = self.batch_size else {
+            for (index, mut tx) in transactions.into_iter().enumerate() {
+                for signer in &self.signers {
+                    tx.sign_signer(signer.clone());
+                }
+
+                match execute_and_get_receipt(&mut tx, client).await {
+                    Ok(receipt) => receipts.receipts.push((index, receipt)),
+                    Err(error) => receipts.errors.push((Vec::from([index]), error)),
+                }
+            }
+
+            return receipts;
+        };
+
+        let mut transactions = transactions.into_iter().enumerate().peekable();
+
+        while transactions.peek().is_some() {
+            let batch: Vec<_> = transactions.by_ref().take(batch_size).collect();
+            let indices: Vec<_> = batch.iter().map(|(index, _)| *index).collect();
+
+            match self.execute_batch(client, batch).await {
+                Ok(batch_receipts) => {
+                    for (index, receipt) in batch_receipts {
+                        match receipt {
+                            Ok(receipt) => receipts.receipts.push((index, receipt)),
+                            Err(error) => receipts.errors.push((Vec::from([index]), error)),
+                        }
+                    }
+                }
+
+                Err(error) => receipts.errors.push((indices, error)),
+            }
+        }
+
+        receipts
+    }
+
+    /// Executes `transactions` in a single [`BatchTransaction`], returning the receipt of each.
+    async fn execute_batch<D>(
+        &self,
+        client: &Client,
+        transactions: Vec<(usize, Transaction<D>)>,
+    ) -> crate::Result<Vec<(usize, crate::Result<TransactionReceipt>)>>
+    where
+        D: TransactionExecute,
+        Transaction<D>: Into<AnyTransaction>,
+    {
+        let batch_key = client.get_operator_public_key().ok_or_else(|| {
+            Error::basic_parse("client must have an operator to execute transfers in batches")
+        })?;
+
+        let mut batch = BatchTransaction::new();
+        let mut inner = Vec::with_capacity(transactions.len());
+
+        for (index, mut tx) in transactions {
+            tx.batchify(client, batch_key.into())?;
+
+            for signer in &self.signers {
+                tx.sign_signer(signer.clone());
+            }
+
+            let transaction_id = tx
+                .get_transaction_id()
+                .expect("batchified transactions are frozen with a transaction ID");
+
+            inner.push((index, transaction_id));
+            batch.add_inner_transaction(tx.into())?;
+        }
+
+        batch.execute(client).await?.get_receipt(client).await?;
+
+        let mut receipts = Vec::with_capacity(inner.len());
+
+        for (index, transaction_id) in inner {
+            let receipt = TransactionReceiptQuery::new()
+                .transaction_id(transaction_id)
+                .validate_status(true)
+                .execute(client)
+                .await;
+
+            receipts.push((index, receipt));
+        }
+
+        Ok(receipts)
+    }
+}
+
+async fn execute_and_get_receipt<D: TransactionExecute>(
+    tx: &mut Transaction<D>,
+    client: &Client,
+) -> crate::Result<TransactionReceipt> {
+    tx.execute(client).await?.get_receipt(client).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        AccountId,
+        Hbar,
+        NftId,
+        TokenAmount,
+        TokenId,
+        TransferPlanner,
+    };
+
+    const TREASURY: AccountId = AccountId::new(0, 0, 1000);
+    const TOKEN: TokenId = TokenId::new(0, 0, 2000);
+    const NFT: TokenId = TokenId::new(0, 0, 3000);
+
+    fn holder(num: u64) -> AccountId {
+        AccountId::new(0, 0, 10_000 + num)
+    }
+
+    #[test]
+    fn airdrop_to_many_holders() {
+        let mut planner = TransferPlanner::new();
+
+        for num in 0..50 {
+            planner.token_transfer(TOKEN, TREASURY, holder(num), 100);
+        }
+
+        let airdrops = planner.plan_airdrops().unwrap();
+
+        // the treasury and 9 holders in each airdrop.
+        assert_eq!(airdrops.len(), 6);
+
+        for airdrop in &airdrops {
+            let transfers = &airdrop.get_token_transfers()[&TOKEN];
+
+            assert!(transfers.len() <= 10);
+            assert_eq!(transfers.values().sum::<i64>(), 0);
+        }
+
+        assert_eq!(airdrops[0].get_token_transfers()[&TOKEN][&TREASURY], -900);
+        assert_eq!(airdrops[5].get_token_transfers()[&TOKEN][&TREASURY], -500);
+    }
+
+    #[test]
+    fn transfers_limits_are_independent() {
+        let mut planner = TransferPlanner::new();
+
+        for num in 0..9 {
+            planner
+                .hbar_transfer(TREASURY, holder(num), Hbar::from_tinybars(1))
+                .token_transfer(TOKEN, TREASURY, holder(num), 1)
+                .nft_transfer(NftId::from((NFT, num + 1)), TREASURY, holder(num));
+        }
+
+        assert_eq!(planner.plan_transfers().unwrap().len(), 1);
+
+        planner.nft_transfer(NftId::from((NFT, 10)), TREASURY, holder(9));
+        assert_eq!(planner.plan_transfers().unwrap().len(), 1);
+
+        planner.nft_transfer(NftId::from((NFT, 11)), TREASURY, holder(10));
+
+        let transfers = planner.plan_transfers().unwrap();
+        assert_eq!(transfers.len(), 2);
+        assert_eq!(transfers[1].get_nft_transfers()[&NFT].len(), 1);
+        assert_eq!(
+            transfers[0].get_hbar_transfers().values().map(|it| it.to_tinybars()).sum::<i64>(),
+            0
+        );
+    }
+
+    #[test]
+    fn airdrop_limit_counts_tokens_and_nfts() {
+        let mut planner = TransferPlanner::new();
+
+        for num in 0..4 {
+            planner.token_transfer(TOKEN, TREASURY, holder(num), 1);
+        }
+
+        for num in 0..6 {
+            planner.nft_transfer(NftId::from((NFT, num + 1)), TREASURY, holder(num));
+        }
+
+        // 5 token adjustments and 6 NFT transfers.
+        assert_eq!(planner.plan_airdrops().unwrap().len(), 2);
+
+        planner.hbar_transfer(TREASURY, holder(0), Hbar::from_tinybars(1));
+        assert!(planner.plan_airdrops().is_err());
+    }
+
+    #[test]
+    fn keeps_decimals() {
+        let mut planner = TransferPlanner::new();
+        planner.token_amount_transfer(TOKEN, TREASURY, holder(0), TokenAmount::new(150, 2));
+
+        let transfers = planner.plan_transfers().unwrap();
+
+        assert_eq!(transfers[0].get_token_decimals(), HashMap::from([(TOKEN, 2)]));
+    }
+
+    #[test]
+    fn groups_by_token_and_sender() {
+        const OTHER_TOKEN: TokenId = TokenId::new(0, 0, 2001);
+        const OTHER_TREASURY: AccountId = AccountId::new(0, 0, 1001);
+
+        let mut planner = TransferPlanner::new();
+
+        for num in 0..18 {
+            planner.token_transfer(TOKEN, TREASURY, holder(num), 1).token_transfer(
+                OTHER_TOKEN,
+                OTHER_TREASURY,
+                holder(num),
+                2,
+            );
+        }
+
+        // in input order, every transaction would need both treasuries: 4 holders of each token per transaction, 5 in total.
+        let transfers = planner.plan_transfers().unwrap();
+        assert_eq!(transfers.len(), 4);
+
+        for (tx, token) in transfers.iter().zip([TOKEN, TOKEN, OTHER_TOKEN, OTHER_TOKEN]) {
+            let token_transfers = tx.get_token_transfers();
+
+            assert_eq!(token_transfers.len(), 1);
+            assert_eq!(token_transfers[&token].len(), 10);
+            assert_eq!(token_transfers[&token].values().sum::<i64>(), 0);
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        let mut planner = TransferPlanner::new();
+        planner.token_transfer(TOKEN, TREASURY, holder(0), i64::MAX);
+
+        assert!(planner.plan_transfers().is_ok());
+
+        // the treasury's adjustment would be `-2 * i64::MAX`.
+        planner.token_transfer(TOKEN, TREASURY, holder(1), i64::MAX);
+        assert!(planner.plan_transfers().is_err());
+
+        let mut planner = TransferPlanner::new();
+        planner.hbar_transfer(TREASURY, holder(0), Hbar::from_tinybars(i64::MIN));
+
+        assert!(planner.plan_transfers().is_err());
+    }
+
+    #[test]
+    fn movement_too_large() {
+        let mut planner = TransferPlanner::new();
+        planner.max_token_transfers(1).token_transfer(TOKEN, TREASURY, holder(0), 1);
+
+        assert!(planner.plan_transfers().is_err());
+        assert!(TransferPlanner::new().plan_transfers().unwrap().is_empty());
+    }
+}
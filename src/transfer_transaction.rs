@@ -30,6 +30,7 @@ use crate::{
     Hbar,
     NftId,
     ToProtobuf,
+    TokenAmount,
     TokenId,
     TokenNftTransfer,
     Transaction,
@@ -55,6 +56,16 @@ pub struct TransferTransactionData {
     token_transfers: Vec<TokenTransfer>,
 }
 
+impl TransferTransactionData {
+    pub(crate) fn hbar_transfers(&self) -> &[Transfer] {
+        &self.transfers
+    }
+
+    pub(crate) fn token_transfers(&self) -> &[TokenTransfer] {
+        &self.token_transfers
+    }
+}
+
 #[derive(Debug, Clone)]
 #[cfg_attr(test, derive(Eq, PartialEq))]
 pub(crate) struct Transfer {
@@ -214,6 +225,36 @@ impl TransferTransaction {
         self._token_transfer(token_id, account_id, amount, true, Some(expected_decimals), None)
     }
 
+    /// Add a non-approved token transfer of `amount`, ensuring that the token has `amount`'s decimals.
+    pub fn token_amount_transfer(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: TokenAmount,
+    ) -> &mut Self {
+        self.token_transfer_with_decimals(
+            token_id,
+            account_id,
+            amount.get_value(),
+            amount.get_decimals(),
+        )
+    }
+
+    /// Add an approved token transfer of `amount`, ensuring that the token has `amount`'s decimals.
+    pub fn approved_token_amount_transfer(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: TokenAmount,
+    ) -> &mut Self {
+        self.approved_token_transfer_with_decimals(
+            token_id,
+            account_id,
+            amount.get_value(),
+            amount.get_decimals(),
+        )
+    }
+
     /// Returns all the token transfers associated associated with this transaction.
     pub fn get_token_transfers(&self) -> HashMap<TokenId, HashMap<AccountId, i64>> {
         use std::collections::hash_map::Entry;
@@ -585,6 +626,7 @@ mod tests {
         AccountId,
         AnyTransaction,
         Hbar,
+        TokenAmount,
         TokenId,
         TransferTransaction,
     };
@@ -1010,6 +1052,23 @@ mod tests {
         assert_eq!(tx.get_token_decimals().get(&TOKEN), Some(&5));
     }
 
+    #[test]
+    fn token_amount_transfer() {
+        let mut tx = TransferTransaction::new();
+        const TOKEN: TokenId = TokenId::new(0, 0, 5);
+
+        let amount = TokenAmount::parse("1.5", 8).unwrap();
+
+        tx.token_amount_transfer(TOKEN, AccountId::new(0, 0, 7), amount.checked_neg().unwrap())
+            .token_amount_transfer(TOKEN, AccountId::new(0, 0, 8), amount);
+
+        assert_eq!(tx.get_token_decimals().get(&TOKEN), Some(&8));
+        assert_eq!(
+            tx.get_token_transfers()[&TOKEN].get(&AccountId::new(0, 0, 8)),
+            Some(&150_000_000)
+        );
+    }
+
     #[test]
     fn token_transfer_aggregation_same_token_and_account() {
         // Test that multiple transfers for the same token + account aggregate amounts
@@ -0,0 +1,514 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+use std::str::FromStr;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use hiero_sdk_proto::services;
+use prost::Message;
+use serde_json::json;
+
+use crate::transaction::TransactionExecute;
+use crate::{
+    AccountId,
+    AnyTransaction,
+    Error,
+    LedgerId,
+    PublicKey,
+    Transaction,
+    TransactionHash,
+    TransactionId,
+    TransactionResponse,
+};
+
+/// A JSON-RPC method defined by HIP-820 for Hedera WalletConnect integrations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WalletConnectMethod {
+    /// `hedera_signMessage`
+    SignMessage,
+
+    /// `hedera_signTransaction`
+    SignTransaction,
+
+    /// `hedera_signAndExecuteTransaction`
+    SignAndExecuteTransaction,
+}
+
+impl WalletConnectMethod {
+    /// Returns the JSON-RPC method name, for example `hedera_signMessage`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::SignMessage => "hedera_signMessage",
+            Self::SignTransaction => "hedera_signTransaction",
+            Self::SignAndExecuteTransaction => "hedera_signAndExecuteTransaction",
+        }
+    }
+}
+
+impl Display for WalletConnectMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl FromStr for WalletConnectMethod {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hedera_signMessage" => Ok(Self::SignMessage),
+            "hedera_signTransaction" => Ok(Self::SignTransaction),
+            "hedera_signAndExecuteTransaction" => Ok(Self::SignAndExecuteTransaction),
+            _ => Err(Error::basic_parse(format!("unsupported WalletConnect method `{s}`"))),
+        }
+    }
+}
+
+/// A CAIP-10 account identifier as used by HIP-820, for example `hedera:testnet:0.0.12345`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalletConnectAccountId {
+    /// The ledger the account lives on.
+    pub ledger_id: LedgerId,
+
+    /// The account itself.
+    pub account_id: AccountId,
+}
+
+impl WalletConnectAccountId {
+    /// Create a new `WalletConnectAccountId` for `account_id` on the ledger `ledger_id`.
+    #[must_use]
+    pub fn new(ledger_id: LedgerId, account_id: AccountId) -> Self {
+        Self { ledger_id, account_id }
+    }
+}
+
+impl Display for WalletConnectAccountId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "hedera:{}:{}", self.ledger_id, self.account_id)
+    }
+}
+
+impl FromStr for WalletConnectAccountId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(3, ':');
+
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some("hedera"), Some(ledger_id), Some(account_id)) => {
+                Ok(Self { ledger_id: ledger_id.parse()?, account_id: account_id.parse()? })
+            }
+            _ => Err(Error::basic_parse(format!(
+                "expecting a CAIP-10 account id of the form `hedera:<network>:<account>`, got `{s}`"
+            ))),
+        }
+    }
+}
+
+/// The parameters of a HIP-820 WalletConnect request.
+///
+/// These are the objects a dApp sends as the `params` of a JSON-RPC request, and that a wallet receives.
+#[derive(Debug, Clone)]
+pub enum WalletConnectRequest {
+    /// `hedera_signMessage`: sign `message` using the Hedera signed-message standard.
+    SignMessage {
+        /// The account that is asked to sign.
+        signer_account_id: WalletConnectAccountId,
+
+        /// The message to sign.
+        message: String,
+    },
+
+    /// `hedera_signTransaction`: sign a transaction body without submitting it.
+    SignTransaction {
+        /// The account that is asked to sign.
+        signer_account_id: WalletConnectAccountId,
+
+        /// The protobuf encoded `TransactionBody` to sign.
+        transaction_body: Vec<u8>,
+    },
+
+    /// `hedera_signAndExecuteTransaction`: sign a transaction and submit it to the network.
+    SignAndExecuteTransaction {
+        /// The account that is asked to sign.
+        signer_account_id: WalletConnectAccountId,
+
+        /// The protobuf encoded `TransactionList`, as returned by [`Transaction::to_bytes`].
+        transaction_list: Vec<u8>,
+    },
+}
+
+impl WalletConnectRequest {
+    /// Create a `hedera_signMessage` request.
+    #[must_use]
+    pub fn sign_message(
+        signer_account_id: WalletConnectAccountId,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::SignMessage { signer_account_id, message: message.into() }
+    }
+
+    /// Create a `hedera_signTransaction` request for the body of a frozen `transaction`.
+    ///
+    /// Only the body for the first node (and first chunk) is sent to the wallet.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `transaction` is not frozen.
+    pub fn sign_transaction<D: TransactionExecute>(
+        signer_account_id: WalletConnectAccountId,
+        transaction: &Transaction<D>,
+    ) -> crate::Result<Self> {
+        let transaction_body = first_body_bytes(transaction)?;
+
+        Ok(Self::SignTransaction { signer_account_id, transaction_body })
+    }
+
+    /// Create a `hedera_signAndExecuteTransaction` request for a frozen `transaction`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `transaction` is not frozen.
+    /// - Any error that can occur during [`Transaction::to_bytes`].
+    pub fn sign_and_execute_transaction<D: TransactionExecute>(
+        signer_account_id: WalletConnectAccountId,
+        transaction: &Transaction<D>,
+    ) -> crate::Result<Self> {
+        if !transaction.is_frozen() {
+            return Err(Error::basic_parse("transaction must be frozen to be sent to a wallet"));
+        }
+
+        Ok(Self::SignAndExecuteTransaction {
+            signer_account_id,
+            transaction_list: transaction.to_bytes()?,
+        })
+    }
+
+    /// Returns the JSON-RPC method of this request.
+    #[must_use]
+    pub fn method(&self) -> WalletConnectMethod {
+        match self {
+            Self::SignMessage { .. } => WalletConnectMethod::SignMessage,
+            Self::SignTransaction { .. } => WalletConnectMethod::SignTransaction,
+            Self::SignAndExecuteTransaction { .. } => {
+                WalletConnectMethod::SignAndExecuteTransaction
+            }
+        }
+    }
+
+    /// Returns the account that is asked to sign.
+    #[must_use]
+    pub fn signer_account_id(&self) -> &WalletConnectAccountId {
+        match self {
+            Self::SignMessage { signer_account_id, .. }
+            | Self::SignTransaction { signer_account_id, .. }
+            | Self::SignAndExecuteTransaction { signer_account_id, .. } => signer_account_id,
+        }
+    }
+
+    /// Decodes the transaction carried by this request.
+    ///
+    /// Returns `None` for `hedera_signMessage` requests.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if the transaction cannot be decoded.
+    pub fn transaction(&self) -> crate::Result<Option<AnyTransaction>> {
+        match self {
+            Self::SignMessage { .. } => Ok(None),
+            Self::SignTransaction { transaction_body, .. } => {
+                let signed_transaction_bytes = services::SignedTransaction {
+                    body_bytes: transaction_body.clone(),
+                    sig_map: None,
+                    use_serialized_tx_message_hash_algorithm: false,
+                }
+                .encode_to_vec();
+
+                let transaction_list = hiero_sdk_proto::sdk::TransactionList {
+                    transaction_list: vec![services::Transaction {
+                        signed_transaction_bytes,
+                        ..services::Transaction::default()
+                    }],
+                };
+
+                AnyTransaction::from_bytes(&transaction_list.encode_to_vec()).map(Some)
+            }
+            Self::SignAndExecuteTransaction { transaction_list, .. } => {
+                AnyTransaction::from_bytes(transaction_list).map(Some)
+            }
+        }
+    }
+
+    /// Encodes the `params` object of this request as JSON.
+    #[must_use]
+    pub fn to_params_json(&self) -> String {
+        let value = match self {
+            Self::SignMessage { signer_account_id, message } => json!({
+                "signerAccountId": signer_account_id.to_string(),
+                "message": message,
+            }),
+            Self::SignTransaction { signer_account_id, transaction_body } => json!({
+                "signerAccountId": signer_account_id.to_string(),
+                "transactionBody": BASE64.encode(transaction_body),
+            }),
+            Self::SignAndExecuteTransaction { signer_account_id, transaction_list } => json!({
+                "signerAccountId": signer_account_id.to_string(),
+                "transactionList": BASE64.encode(transaction_list),
+            }),
+        };
+
+        value.to_string()
+    }
+
+    /// Decodes the JSON `params` object of a request for `method`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `params` is not valid JSON, or is missing a required field.
+    /// - [`Error::BasicParse`] if a field has an invalid value.
+    pub fn from_params_json(method: WalletConnectMethod, params: &str) -> crate::Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(params).map_err(|e| {
+            Error::basic_parse(format!("failed to parse WalletConnect params JSON: {e}"))
+        })?;
+
+        let signer_account_id = get_str(&value, "signerAccountId")?.parse()?;
+
+        match method {
+            WalletConnectMethod::SignMessage => Ok(Self::SignMessage {
+                signer_account_id,
+                message: get_str(&value, "message")?.to_owned(),
+            }),
+            WalletConnectMethod::SignTransaction => Ok(Self::SignTransaction {
+                signer_account_id,
+                transaction_body: get_base64(&value, "transactionBody")?,
+            }),
+            WalletConnectMethod::SignAndExecuteTransaction => Ok(Self::SignAndExecuteTransaction {
+                signer_account_id,
+                transaction_list: get_base64(&value, "transactionList")?,
+            }),
+        }
+    }
+}
+
+/// A single signature returned by a wallet in a HIP-820 `signatureMap`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalletConnectSignature {
+    /// The public key of the signer.
+    pub public_key: PublicKey,
+
+    /// The signature itself.
+    pub signature: Vec<u8>,
+}
+
+impl WalletConnectSignature {
+    /// Verifies this signature against a `hedera_signMessage` `message`.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is invalid for `message`.
+    pub fn verify_message(&self, message: &[u8]) -> crate::Result<()> {
+        self.public_key.verify_message(message, &self.signature)
+    }
+
+    /// Encodes the result of a `hedera_signMessage` or `hedera_signTransaction` request as JSON.
+    #[must_use]
+    pub fn to_result_json(signatures: &[Self]) -> String {
+        let sig_pair = signatures
+            .iter()
+            .map(|it| {
+                let signature = if it.public_key.is_ed25519() {
+                    services::signature_pair::Signature::Ed25519(it.signature.clone())
+                } else {
+                    services::signature_pair::Signature::EcdsaSecp256k1(it.signature.clone())
+                };
+
+                services::SignaturePair {
+                    pub_key_prefix: it.public_key.to_bytes_raw(),
+                    signature: Some(signature),
+                }
+            })
+            .collect();
+
+        let signature_map = services::SignatureMap { sig_pair }.encode_to_vec();
+
+        json!({ "signatureMap": BASE64.encode(signature_map) }).to_string()
+    }
+
+    /// Decodes the result of a `hedera_signMessage` or `hedera_signTransaction` request.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `result` is not valid JSON, or has no `signatureMap`.
+    /// - [`Error::FromProtobuf`] if the `signatureMap` cannot be decoded.
+    /// - [`Error::KeyParse`] if a signature pair doesn't contain a full public key.
+    pub fn from_result_json(result: &str) -> crate::Result<Vec<Self>> {
+        let value: serde_json::Value = serde_json::from_str(result).map_err(|e| {
+            Error::basic_parse(format!("failed to parse WalletConnect result JSON: {e}"))
+        })?;
+
+        let signature_map =
+            services::SignatureMap::decode(get_base64(&value, "signatureMap")?.as_slice())
+                .map_err(Error::from_protobuf)?;
+
+        signature_map
+            .sig_pair
+            .into_iter()
+            .map(|pair| {
+                use services::signature_pair::Signature;
+
+                match pair.signature {
+                    Some(Signature::Ed25519(signature)) => Ok(Self {
+                        public_key: PublicKey::from_bytes_ed25519(&pair.pub_key_prefix)?,
+                        signature,
+                    }),
+                    Some(Signature::EcdsaSecp256k1(signature)) => Ok(Self {
+                        public_key: PublicKey::from_bytes_ecdsa(&pair.pub_key_prefix)?,
+                        signature,
+                    }),
+                    _ => Err(Error::from_protobuf("unsupported signature type in signature map")),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Encodes a [`TransactionResponse`] as the result of a `hedera_signAndExecuteTransaction` request.
+#[must_use]
+pub fn wallet_connect_execute_result_json(response: &TransactionResponse) -> String {
+    json!({
+        "nodeId": response.node_account_id.to_string(),
+        "transactionHash": response.transaction_hash.to_string(),
+        "transactionId": response.transaction_id.to_string(),
+    })
+    .to_string()
+}
+
+/// Decodes the result of a `hedera_signAndExecuteTransaction` request into a [`TransactionResponse`].
+///
+/// # Errors
+/// - [`Error::BasicParse`] if `result` is not valid JSON, or a field is missing or invalid.
+pub fn wallet_connect_execute_result_from_json(result: &str) -> crate::Result<TransactionResponse> {
+    let value: serde_json::Value = serde_json::from_str(result).map_err(|e| {
+        Error::basic_parse(format!("failed to parse WalletConnect result JSON: {e}"))
+    })?;
+
+    let node_account_id = get_str(&value, "nodeId")?.parse()?;
+    let transaction_id = TransactionId::from_str(get_str(&value, "transactionId")?)?;
+
+    let transaction_hash = hex::decode(get_str(&value, "transactionHash")?)
+        .map_err(Error::basic_parse)?
+        .try_into()
+        .map(TransactionHash)
+        .map_err(|_| Error::basic_parse("`transactionHash` must be 48 bytes"))?;
+
+    Ok(TransactionResponse {
+        node_account_id,
+        transaction_id,
+        transaction_hash,
+        validate_status: true,
+    })
+}
+
+fn first_body_bytes<D: TransactionExecute>(transaction: &Transaction<D>) -> crate::Result<Vec<u8>> {
+    if !transaction.is_frozen() {
+        return Err(Error::basic_parse("transaction must be frozen to be sent to a wallet"));
+    }
+
+    let sources = transaction.make_sources()?;
+
+    sources
+        .signed_transactions()
+        .first()
+        .map(|it| it.body_bytes.clone())
+        .ok_or_else(|| Error::basic_parse("no transactions found"))
+}
+
+fn get_str<'a>(value: &'a serde_json::Value, field: &str) -> crate::Result<&'a str> {
+    value
+        .get(field)
+        .and_then(serde_json::Value::as_str)
+        .ok_or_else(|| Error::basic_parse(format!("missing or invalid `{field}`")))
+}
+
+fn get_base64(value: &serde_json::Value, field: &str) -> crate::Result<Vec<u8>> {
+    BASE64.decode(get_str(value, field)?).map_err(Error::basic_parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+
+    #[test]
+    fn account_id_round_trip() {
+        let id: WalletConnectAccountId = "hedera:testnet:0.0.12345".parse().unwrap();
+
+        assert_eq!(id.ledger_id, LedgerId::testnet());
+        assert_eq!(id.account_id, AccountId::new(0, 0, 12345));
+        assert_eq!(id.to_string(), "hedera:testnet:0.0.12345");
+    }
+
+    #[test]
+    fn account_id_rejects_other_namespaces() {
+        assert!("eip155:1:0xab16a96d359ec26a11e2c2b3d8f8b8942d5bfcdb"
+            .parse::<WalletConnectAccountId>()
+            .is_err());
+    }
+
+    #[test]
+    fn sign_message_params_round_trip() {
+        let request = WalletConnectRequest::sign_message(
+            WalletConnectAccountId::new(LedgerId::mainnet(), AccountId::new(0, 0, 3)),
+            "log in to example.com",
+        );
+
+        let json = request.to_params_json();
+        let decoded =
+            WalletConnectRequest::from_params_json(WalletConnectMethod::SignMessage, &json)
+                .unwrap();
+
+        assert_eq!(decoded.method(), WalletConnectMethod::SignMessage);
+        assert_eq!(decoded.signer_account_id().to_string(), "hedera:mainnet:0.0.3");
+        assert!(decoded.transaction().unwrap().is_none());
+        assert!(matches!(
+            decoded,
+            WalletConnectRequest::SignMessage { message, .. } if message == "log in to example.com"
+        ));
+    }
+
+    #[test]
+    fn signature_map_result_round_trip() {
+        let ed25519 = PrivateKey::generate_ed25519();
+        let ecdsa = PrivateKey::generate_ecdsa();
+
+        let signatures: Vec<_> = [&ed25519, &ecdsa]
+            .into_iter()
+            .map(|key| WalletConnectSignature {
+                public_key: key.public_key(),
+                signature: key.sign_message(b"hello"),
+            })
+            .collect();
+
+        let json = WalletConnectSignature::to_result_json(&signatures);
+        let decoded = WalletConnectSignature::from_result_json(&json).unwrap();
+
+        assert_eq!(decoded, signatures);
+
+        for signature in &decoded {
+            signature.verify_message(b"hello").unwrap();
+        }
+    }
+
+    #[test]
+    fn method_from_str() {
+        for method in [
+            WalletConnectMethod::SignMessage,
+            WalletConnectMethod::SignTransaction,
+            WalletConnectMethod::SignAndExecuteTransaction,
+        ] {
+            assert_eq!(method.as_str().parse::<WalletConnectMethod>().unwrap(), method);
+        }
+
+        assert!("hedera_getNodeAddresses".parse::<WalletConnectMethod>().is_err());
+    }
+}
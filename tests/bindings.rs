@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: Apache-2.0
+
+// Compiles the checked-in output of `ContractBindings` (kept up to date by its `generate` test)
+// the way a crate using the bindings would.
+
+// not every generated item is used here.
+#[allow(dead_code)]
+mod token {
+    include!("../src/contract/abi/snapshots/bindings/generate.txt");
+}
+
+use hiero_sdk::{
+    ContractFunctionParameters,
+    ContractId,
+    ContractLogInfo,
+    EvmAddress,
+};
+use num_bigint::BigUint;
+use token::{
+    DeployedEvent,
+    Token,
+};
+
+const ADDRESS: &str = "0x000000000000000000000000000000000000abcd";
+
+#[test]
+fn constructor_parameters() {
+    let data = Token::constructor_parameters(BigUint::from(1000_u32), "token".to_owned()).unwrap();
+
+    let expected = ContractFunctionParameters::new()
+        .add_uint256(BigUint::from(1000_u32))
+        .add_string("token")
+        .to_bytes(None);
+
+    assert_eq!(data, expected);
+}
+
+#[test]
+fn call() {
+    let token = Token::new(ContractId::new(0, 0, 5));
+
+    let tx = token.transfer(ADDRESS.parse().unwrap(), 100).unwrap();
+
+    let expected = ContractFunctionParameters::new()
+        .add_address(ADDRESS)
+        .add_uint64(100)
+        .to_bytes(Some("transfer"));
+
+    assert_eq!(tx.get_function_parameters(), expected);
+
+    // `new`, `data` and `request` are renamed so they don't shadow the generated code.
+    let tx = token.new_1(vec![1, 2], [3; 32]).unwrap();
+
+    let expected = ContractFunctionParameters::new()
+        .add_bytes(&[1, 2])
+        .add_bytes32(&[3; 32])
+        .to_bytes(Some("new"));
+
+    assert_eq!(tx.get_function_parameters(), expected);
+}
+
+#[test]
+fn event() {
+    let address: EvmAddress = ADDRESS.parse().unwrap();
+
+    let mut log = ContractLogInfo {
+        contract_id: ContractId::new(0, 0, 5),
+        bloom: Vec::new(),
+        topics: vec![DeployedEvent::abi().topic().to_vec()],
+        data: [[0; 12].as_slice(), &address.to_bytes()].concat(),
+    };
+
+    let event = DeployedEvent::decode(&log).unwrap();
+
+    assert_eq!(event.contract_id, ContractId::new(0, 0, 5));
+    assert_eq!(event.contract_id_1, address);
+
+    log.data.truncate(16);
+
+    assert!(DeployedEvent::decode(&log).is_err());
+}
@@ -139,7 +139,10 @@ async fn invalid_account_id_fails() -> anyhow::Result<()> {
 
     assert_matches!(
         res,
-        Err(hiero_sdk::Error::QueryNoPaymentPreCheckStatus { status: Status::InvalidAccountId })
+        Err(hiero_sdk::Error::QueryNoPaymentPreCheckStatus {
+            status: Status::InvalidAccountId,
+            ..
+        })
     );
 
     Ok(())
@@ -44,7 +44,7 @@ async fn create_then_delete() -> anyhow::Result<()> {
 
     assert_matches!(
         res,
-        Err(hiero_sdk::Error::QueryNoPaymentPreCheckStatus { status: Status::AccountDeleted })
+        Err(hiero_sdk::Error::QueryNoPaymentPreCheckStatus { status: Status::AccountDeleted, .. })
     );
 
     Ok(())
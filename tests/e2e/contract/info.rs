@@ -86,7 +86,10 @@ async fn missing_contract_id_fails() -> anyhow::Result<()> {
 
     assert_matches!(
         res,
-        Err(hiero_sdk::Error::QueryNoPaymentPreCheckStatus { status: Status::InvalidContractId })
+        Err(hiero_sdk::Error::QueryNoPaymentPreCheckStatus {
+            status: Status::InvalidContractId,
+            ..
+        })
     );
 
     Ok(())
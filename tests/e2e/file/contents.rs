@@ -91,7 +91,7 @@ async fn missing_file_id_fails() -> anyhow::Result<()> {
 
     assert_matches!(
         res,
-        Err(hiero_sdk::Error::QueryNoPaymentPreCheckStatus { status: Status::InvalidFileId })
+        Err(hiero_sdk::Error::QueryNoPaymentPreCheckStatus { status: Status::InvalidFileId, .. })
     );
 
     Ok(())
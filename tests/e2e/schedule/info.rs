@@ -105,7 +105,10 @@ async fn missing_schedule_id_fails() -> anyhow::Result<()> {
 
     assert_matches!(
         res,
-        Err(hiero_sdk::Error::QueryNoPaymentPreCheckStatus { status: Status::InvalidScheduleId })
+        Err(hiero_sdk::Error::QueryNoPaymentPreCheckStatus {
+            status: Status::InvalidScheduleId,
+            ..
+        })
     );
 
     Ok(())
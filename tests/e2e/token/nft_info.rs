@@ -54,7 +54,7 @@ async fn invalid_nft_id_fails() -> anyhow::Result<()> {
 
     assert_matches!(
         res,
-        Err(hiero_sdk::Error::QueryNoPaymentPreCheckStatus { status: Status::InvalidNftId })
+        Err(hiero_sdk::Error::QueryNoPaymentPreCheckStatus { status: Status::InvalidNftId, .. })
     );
 
     Ok(())
@@ -74,7 +74,8 @@ async fn invalid_serial_number_fails() -> anyhow::Result<()> {
     assert_matches!(
         res,
         Err(hiero_sdk::Error::QueryNoPaymentPreCheckStatus {
-            status: Status::InvalidTokenNftSerialNumber
+            status: Status::InvalidTokenNftSerialNumber,
+            ..
         })
     );
 